@@ -1,7 +1,8 @@
 // Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
 
-use crc_fast::checksum;
 use crc_fast::CrcAlgorithm;
+use crc_fast::PerformanceTier;
+use crc_fast::{checksum, checksum_with_backend, Calculator, Chunker, CrcParams, RollingCrc};
 use criterion::*;
 use rand::{rng, RngCore};
 use std::hint::black_box;
@@ -30,6 +31,17 @@ pub const SIZES: &[(&str, i32)] = &[
     //("1 bytes", 1),
 ];
 
+// short inputs, such as keys and headers, which take the small-input path
+pub const SMALL_SIZES: &[usize] = &[1, 4, 8, 12, 16, 24, 32, 40, 48, 56, 63, 64];
+
+// long enough for the software backend to fold with Chorba rather than use slice-by-16 alone
+pub const SOFTWARE_SIZES: &[(&str, usize)] = &[
+    ("1 MiB", 1024 * 1024),
+    ("64 KiB", 64 * 1024),
+    ("16 KiB", 16 * 1024),
+    ("4 KiB", 4 * 1024),
+];
+
 // these are the most important algorithms in popular use, with forward/reflected coverage
 pub const CRC32_ALGORITHMS: &[CrcAlgorithm] = &[
     // benchmark both CRC-32/ISCSI and CRC-32/ISO-HDLC since they're special flowers with lots of
@@ -174,6 +186,217 @@ fn bench_crc64(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, bench_crc32, bench_crc64);
+// custom parameters have no static tables, so they always take the SIMD engine, which makes an
+// equivalent custom set a same-run baseline for the small-input path
+fn small_input_pairs() -> Vec<(CrcAlgorithm, CrcParams)> {
+    vec![
+        (
+            CrcAlgorithm::Crc16T10Dif,
+            CrcParams::new("CRC-16/T10-DIF", 16, 0x8bb7, 0x0, false, 0x0, 0xd0db),
+        ),
+        (
+            CrcAlgorithm::Crc32IsoHdlc,
+            CrcParams::new(
+                "CRC-32/ISO-HDLC",
+                32,
+                0x04c11db7,
+                0xffffffff,
+                true,
+                0xffffffff,
+                0xcbf43926,
+            ),
+        ),
+        (
+            CrcAlgorithm::Crc32Bzip2,
+            CrcParams::new(
+                "CRC-32/BZIP2",
+                32,
+                0x04c11db7,
+                0xffffffff,
+                false,
+                0xffffffff,
+                0xfc891918,
+            ),
+        ),
+        (
+            CrcAlgorithm::Crc64Nvme,
+            CrcParams::new(
+                "CRC-64/NVME",
+                64,
+                0xad93d23594c93659,
+                0xffffffffffffffff,
+                true,
+                0xffffffffffffffff,
+                0xae8b14860a799888,
+            ),
+        ),
+    ]
+}
+
+#[inline(always)]
+fn bench_small(c: &mut Criterion) {
+    println!(
+        "Acceleration target: {}",
+        crc_fast::get_calculator_target(CrcAlgorithm::Crc64Nvme)
+    );
+
+    let mut group = c.benchmark_group("Small");
+    let pairs = small_input_pairs();
+
+    for size in SMALL_SIZES {
+        let buf = create_aligned_data(&random_data(*size as i32));
+
+        for (algorithm, params) in &pairs {
+            group.throughput(Throughput::Bytes(*size as u64));
+            group.sample_size(100);
+            group.measurement_time(Duration::from_secs(2));
+
+            // resolved up front, so neither side pays for the algorithm or key lookup
+            let predefined = Calculator::new(*algorithm);
+            let custom = Calculator::new_with_params(*params);
+
+            let bench_name = [algorithm.to_string(), "(tables)".to_string()].join(" ");
+
+            group.bench_function(BenchmarkId::new(bench_name, size), |b| {
+                b.iter(|| black_box(predefined.checksum(black_box(&buf))))
+            });
+
+            let bench_name = [algorithm.to_string(), "(keys)".to_string()].join(" ");
+
+            group.bench_function(BenchmarkId::new(bench_name, size), |b| {
+                b.iter(|| black_box(custom.checksum(black_box(&buf))))
+            });
+        }
+    }
+}
+
+// the crc crate's slice-by-16 is the software backend's implementation for inputs which don't
+// fold, which makes it a same-run baseline for the Chorba fold
+type SoftwareBaseline = Box<dyn Fn(&[u8]) -> u64>;
+
+fn software_pairs() -> Vec<(CrcAlgorithm, SoftwareBaseline)> {
+    const T10_DIF: crc::Crc<u16, crc::Table<16>> =
+        crc::Crc::<u16, crc::Table<16>>::new(&crc::CRC_16_T10_DIF);
+    const ISCSI: crc::Crc<u32, crc::Table<16>> =
+        crc::Crc::<u32, crc::Table<16>>::new(&crc::CRC_32_ISCSI);
+    const ISO_HDLC: crc::Crc<u32, crc::Table<16>> =
+        crc::Crc::<u32, crc::Table<16>>::new(&crc::CRC_32_ISO_HDLC);
+    const BZIP2: crc::Crc<u32, crc::Table<16>> =
+        crc::Crc::<u32, crc::Table<16>>::new(&crc::CRC_32_BZIP2);
+    const GO_ISO: crc::Crc<u64, crc::Table<16>> =
+        crc::Crc::<u64, crc::Table<16>>::new(&crc::CRC_64_GO_ISO);
+
+    vec![
+        (
+            CrcAlgorithm::Crc16T10Dif,
+            Box::new(|buf| T10_DIF.checksum(buf) as u64),
+        ),
+        (
+            CrcAlgorithm::Crc32Iscsi,
+            Box::new(|buf| ISCSI.checksum(buf) as u64),
+        ),
+        (
+            CrcAlgorithm::Crc32IsoHdlc,
+            Box::new(|buf| ISO_HDLC.checksum(buf) as u64),
+        ),
+        (
+            CrcAlgorithm::Crc32Bzip2,
+            Box::new(|buf| BZIP2.checksum(buf) as u64),
+        ),
+        (
+            CrcAlgorithm::Crc64GoIso,
+            Box::new(|buf| GO_ISO.checksum(buf)),
+        ),
+    ]
+}
+
+#[inline(always)]
+fn bench_software(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Software");
+    let pairs = software_pairs();
+
+    for (size_name, size) in SOFTWARE_SIZES {
+        let buf = create_aligned_data(&random_data(*size as i32));
+
+        for (algorithm, baseline) in &pairs {
+            group.throughput(Throughput::Bytes(*size as u64));
+            group.sample_size(100);
+            group.measurement_time(Duration::from_secs(5));
+
+            let bench_name = [algorithm.to_string(), "(software)".to_string()].join(" ");
+
+            group.bench_function(BenchmarkId::new(bench_name, size_name), |b| {
+                b.iter(|| {
+                    black_box(checksum_with_backend(
+                        *algorithm,
+                        PerformanceTier::SoftwareTable,
+                        black_box(&buf),
+                    ))
+                })
+            });
+
+            let bench_name = [algorithm.to_string(), "(slice-by-16)".to_string()].join(" ");
+
+            group.bench_function(BenchmarkId::new(bench_name, size_name), |b| {
+                b.iter(|| black_box(baseline(black_box(&buf))))
+            });
+        }
+    }
+}
+
+// a typical content-defined chunking window, and mask for ~8 KiB average chunks
+const ROLLING_WINDOW: usize = 48;
+const CHUNK_MASK: u64 = 0x1fff;
+
+#[inline(always)]
+fn bench_rolling(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Rolling");
+    let size = 1024 * 1024;
+    let buf = create_aligned_data(&random_data(size));
+
+    for algorithm in [CrcAlgorithm::Crc32Iscsi, CrcAlgorithm::Crc64Nvme] {
+        let params = *Calculator::new(algorithm).params();
+
+        group.throughput(Throughput::Bytes(size as u64));
+        group.sample_size(50);
+        group.measurement_time(Duration::from_secs(5));
+
+        let bench_name = [algorithm.to_string(), "(roll_slice)".to_string()].join(" ");
+
+        group.bench_function(BenchmarkId::new(bench_name, "1 MiB"), |b| {
+            let mut rolling = RollingCrc::new(params, ROLLING_WINDOW);
+            b.iter(|| {
+                black_box(rolling.roll_slice(
+                    black_box(&buf[..buf.len() - ROLLING_WINDOW]),
+                    black_box(&buf[ROLLING_WINDOW..]),
+                ))
+            })
+        });
+
+        let bench_name = [algorithm.to_string(), "(chunker)".to_string()].join(" ");
+
+        group.bench_function(BenchmarkId::new(bench_name, "1 MiB"), |b| {
+            let mut chunker = Chunker::new(params, ROLLING_WINDOW, CHUNK_MASK, 0);
+            b.iter(|| {
+                let mut rest = black_box(&buf[..]);
+                let mut chunks = 0;
+                while let Some(len) = chunker.next_boundary(rest) {
+                    rest = &rest[len..];
+                    chunks += 1;
+                }
+                black_box(chunks)
+            })
+        });
+    }
+}
+
+criterion_group!(
+    benches,
+    bench_crc32,
+    bench_crc64,
+    bench_small,
+    bench_software,
+    bench_rolling
+);
 
 criterion_main!(benches);
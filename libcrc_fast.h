@@ -9,6 +9,11 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+/**
+ * The number of entries each cache holds unless changed with [set_capacity].
+ */
+#define DEFAULT_CAPACITY 256
+
 /**
  * Error codes for FFI operations
  */
@@ -45,6 +50,15 @@ typedef enum CrcFastError {
    * Internal string conversion error
    */
   StringConversionError = 7,
+  /**
+   * The algorithm's family (crc16, crc32 or crc64) isn't compiled into this library
+   */
+  UnsupportedAlgorithm = 8,
+  /**
+   * A masked range is outside the data, or the ranges aren't in ascending order without
+   * overlapping
+   */
+  InvalidRange = 9,
 } CrcFastError;
 
 /**
@@ -106,6 +120,51 @@ typedef enum CrcFastAlgorithm {
   Crc64Xz,
 } CrcFastAlgorithm;
 
+/**
+ * Strategy used to read files
+ */
+typedef enum CrcFastFileReadMode {
+  /**
+   * Regular buffered reads
+   */
+  Buffered,
+  /**
+   * Memory-mapped reads (requires the library to be built with the mmap feature)
+   */
+  Mmap,
+  /**
+   * O_DIRECT reads which bypass the page cache (requires the library to be built with the
+   * direct-io feature, Linux and Android only)
+   */
+  Direct,
+} CrcFastFileReadMode;
+
+/**
+ * A CRC calculator resolved once for an algorithm or set of custom parameters.
+ *
+ * Creating a `Calculator` performs the algorithm lookup and CPU tier dispatch ahead of time, so
+ * [checksum](Calculator::checksum), [update](Calculator::update) and any [Digest] created by
+ * [digest](Calculator::digest) call straight into the specialised routine. Results are identical
+ * to [crate::checksum] and [Digest::new].
+ *
+ * # Examples
+ *
+ * ```rust
+ * use crc_fast::{Calculator, CrcAlgorithm::Crc32IsoHdlc};
+ *
+ * let calculator = Calculator::new(Crc32IsoHdlc);
+ *
+ * assert_eq!(calculator.checksum(b"123456789"), 0xcbf43926);
+ *
+ * let mut digest = calculator.digest();
+ * digest.update(b"1234");
+ * digest.update(b"56789");
+ *
+ * assert_eq!(digest.finalize(), 0xcbf43926);
+ * ```
+ */
+typedef struct Calculator Calculator;
+
 /**
  * Represents a CRC Digest, which is used to compute CRC checksums.
  *
@@ -138,6 +197,125 @@ typedef struct CrcFastParams {
   const uint64_t *keys;
 } CrcFastParams;
 
+/**
+ * A byte range [start, end) of the data, treated as zeros by the masked checksum functions
+ */
+typedef struct CrcFastRange {
+  uintptr_t start;
+  uintptr_t end;
+} CrcFastRange;
+
+/**
+ * A handle to the Calculator object
+ */
+typedef struct CrcFastCalculatorHandle {
+  struct Calculator *_0;
+} CrcFastCalculatorHandle;
+
+/**
+ * Options for checksumming a file, zero-initialize for the defaults (whole file, buffered)
+ */
+typedef struct CrcFastFileOptions {
+  /**
+   * Read size in bytes, or 0 for the default
+   */
+  uintptr_t chunk_size;
+  /**
+   * Byte offset to start reading from
+   */
+  uint64_t offset;
+  /**
+   * Number of bytes to read, only used when has_len is true
+   */
+  uint64_t len;
+  /**
+   * Whether len limits the range, otherwise it extends to the end of the file
+   */
+  bool has_len;
+  /**
+   * Strategy used to read the file
+   */
+  enum CrcFastFileReadMode mode;
+} CrcFastFileOptions;
+
+/**
+ * Summary of a runtime self-test
+ */
+typedef struct CrcFastSelfTestReport {
+  /**
+   * Whether every checksum matched its expected value
+   */
+  bool passed;
+  /**
+   * Number of backends (performance tiers) which were tested
+   */
+  uint32_t backends;
+  /**
+   * Number of checksums which were computed and compared
+   */
+  uint32_t checks;
+  /**
+   * Number of checksums which didn't match
+   */
+  uint32_t failures;
+} CrcFastSelfTestReport;
+
+/**
+ * The predefined algorithm families compiled into this library
+ */
+typedef struct CrcFastFamilies {
+  /**
+   * Whether the CRC-16 algorithms are available
+   */
+  bool crc16;
+  /**
+   * Whether the CRC-32 algorithms are available
+   */
+  bool crc32;
+  /**
+   * Whether the CRC-64 algorithms are available
+   */
+  bool crc64;
+} CrcFastFamilies;
+
+/**
+ * Counters and sizes of the custom parameter caches
+ */
+typedef struct CrcFastCacheStats {
+  /**
+   * Lookups served from a cache
+   */
+  uint64_t hits;
+  /**
+   * Lookups that had to generate keys or a table
+   */
+  uint64_t misses;
+  /**
+   * Entries removed to stay within the capacity
+   */
+  uint64_t evictions;
+  /**
+   * Sets of folding keys currently cached
+   */
+  uintptr_t key_entries;
+  /**
+   * Software lookup tables currently cached, across all widths
+   */
+  uintptr_t table_entries;
+  /**
+   * Software fold plans currently cached, including searches which found no plan
+   */
+  uintptr_t plan_entries;
+  /**
+   * The maximum number of entries in each cache
+   */
+  uintptr_t capacity;
+  /**
+   * Whether caching is enabled
+   */
+  bool enabled;
+} CrcFastCacheStats;
+
 #ifdef __cplusplus
 extern "C" {
 #endif // __cplusplus
@@ -161,11 +339,13 @@ const char *crc_fast_error_message(enum CrcFastError error);
 
 /**
  * Creates a new Digest to compute CRC checksums using algorithm
+ * Returns NULL if the algorithm's family isn't compiled into this library
  */
 struct CrcFastDigestHandle *crc_fast_digest_new(enum CrcFastAlgorithm algorithm);
 
 /**
  * Creates a new Digest with a custom initial state
+ * Returns NULL if the algorithm's family isn't compiled into this library
  */
 struct CrcFastDigestHandle *crc_fast_digest_new_with_init_state(enum CrcFastAlgorithm algorithm,
                                                                 uint64_t init_state);
@@ -182,6 +362,20 @@ struct CrcFastDigestHandle *crc_fast_digest_new_with_params(struct CrcFastParams
  */
 void crc_fast_digest_update(struct CrcFastDigestHandle *handle, const char *data, uintptr_t len);
 
+/**
+ * Updates the Digest with data, treating the bytes in each of the masked ranges as zeros
+ */
+void crc_fast_digest_update_with_masked_ranges(struct CrcFastDigestHandle *handle,
+                                               const char *data,
+                                               uintptr_t len,
+                                               const struct CrcFastRange *ranges,
+                                               uintptr_t range_count);
+
+/**
+ * Updates the Digest as if len zero bytes were written
+ */
+void crc_fast_digest_update_zeros(struct CrcFastDigestHandle *handle, uint64_t len);
+
 /**
  * Calculates the CRC checksum for data that's been written to the Digest
  * Returns 0 on error (e.g. null handle)
@@ -222,12 +416,65 @@ uint64_t crc_fast_digest_get_amount(struct CrcFastDigestHandle *handle);
  */
 uint64_t crc_fast_digest_get_state(struct CrcFastDigestHandle *handle);
 
+/**
+ * Creates a new Calculator, resolved once for the algorithm and the detected CPU
+ * Returns NULL if the algorithm's family isn't compiled into this library
+ */
+struct CrcFastCalculatorHandle *crc_fast_calculator_new(enum CrcFastAlgorithm algorithm);
+
+/**
+ * Creates a new Calculator, resolved once for custom parameters and the detected CPU
+ * Returns NULL if parameters are invalid (invalid key count or null pointer)
+ * Call crc_fast_get_last_error() to get the specific error code
+ */
+struct CrcFastCalculatorHandle *crc_fast_calculator_new_with_params(struct CrcFastParams params);
+
+/**
+ * Calculates a CRC checksum for data using the Calculator
+ * Returns 0 on error (e.g. null handle or data pointer)
+ */
+uint64_t crc_fast_calculator_checksum(const struct CrcFastCalculatorHandle *handle,
+                                      const char *data,
+                                      uintptr_t len);
+
+/**
+ * Updates a non-finalized CRC state with data using the Calculator, returning the new state
+ * Returns 0 on error (e.g. null handle or data pointer)
+ */
+uint64_t crc_fast_calculator_update(const struct CrcFastCalculatorHandle *handle,
+                                    uint64_t state,
+                                    const char *data,
+                                    uintptr_t len);
+
+/**
+ * Creates a new Digest which uses the Calculator's resolved routine
+ * Returns NULL on error (e.g. null handle)
+ */
+struct CrcFastDigestHandle *crc_fast_calculator_digest(const struct CrcFastCalculatorHandle *handle);
+
+/**
+ * Free the Calculator resources
+ */
+void crc_fast_calculator_free(struct CrcFastCalculatorHandle *handle);
+
 /**
  * Helper method to calculate a CRC checksum directly for a string using algorithm
- * Returns 0 on error (e.g. null data pointer)
+ * Returns 0 on error (e.g. null data pointer, or the algorithm's family isn't compiled in)
  */
 uint64_t crc_fast_checksum(enum CrcFastAlgorithm algorithm, const char *data, uintptr_t len);
 
+/**
+ * Helper method to calculate a CRC checksum directly for data using algorithm, treating the bytes
+ * in each of the masked ranges as zeros, such as an embedded CRC field
+ * Returns 0 on error (e.g. null pointer, invalid ranges, or the algorithm's family isn't compiled
+ * in)
+ */
+uint64_t crc_fast_checksum_with_masked_ranges(enum CrcFastAlgorithm algorithm,
+                                              const char *data,
+                                              uintptr_t len,
+                                              const struct CrcFastRange *ranges,
+                                              uintptr_t range_count);
+
 /**
  * Helper method to calculate a CRC checksum directly for data using custom parameters
  * Returns 0 if parameters are invalid or data is null
@@ -239,7 +486,7 @@ uint64_t crc_fast_checksum_with_params(struct CrcFastParams params,
 
 /**
  * Helper method to just calculate a CRC checksum directly for a file using algorithm
- * Returns 0 if path is null or file I/O fails
+ * Returns 0 if path is null, file I/O fails, or the algorithm's family isn't compiled in
  * Call crc_fast_get_last_error() to get the specific error code
  */
 uint64_t crc_fast_checksum_file(enum CrcFastAlgorithm algorithm,
@@ -255,8 +502,31 @@ uint64_t crc_fast_checksum_file_with_params(struct CrcFastParams params,
                                             const uint8_t *path_ptr,
                                             uintptr_t path_len);
 
+/**
+ * Calculates a CRC checksum for a file, or a byte range of it, using algorithm
+ * Returns 0 if path is null, file I/O fails (including ranges past the end of the file), or the
+ * algorithm's family isn't compiled in
+ * Call crc_fast_get_last_error() to get the specific error code
+ */
+uint64_t crc_fast_checksum_file_with_options(enum CrcFastAlgorithm algorithm,
+                                             const uint8_t *path_ptr,
+                                             uintptr_t path_len,
+                                             struct CrcFastFileOptions options);
+
+/**
+ * Calculates a CRC checksum for a file, or a byte range of it, using custom parameters
+ * Returns 0 if parameters are invalid, path is null, or file I/O fails (including ranges past
+ * the end of the file)
+ * Call crc_fast_get_last_error() to get the specific error code
+ */
+uint64_t crc_fast_checksum_file_with_params_and_options(struct CrcFastParams params,
+                                                        const uint8_t *path_ptr,
+                                                        uintptr_t path_len,
+                                                        struct CrcFastFileOptions options);
+
 /**
  * Combine two CRC checksums using algorithm
+ * Returns 0 if the algorithm's family isn't compiled into this library
  */
 uint64_t crc_fast_checksum_combine(enum CrcFastAlgorithm algorithm,
                                    uint64_t checksum1,
@@ -287,11 +557,52 @@ struct CrcFastParams crc_fast_get_custom_params(const char *name_ptr,
 
 /**
  * Gets the target build properties (CPU architecture and fine-tuning parameters) for this algorithm
- * Returns NULL if string conversion fails
+ * Returns NULL if string conversion fails or the algorithm's family isn't compiled in
  * Call crc_fast_get_last_error() to get the specific error code
  */
 const char *crc_fast_get_calculator_target(enum CrcFastAlgorithm algorithm);
 
+/**
+ * Runs a self-test of every algorithm across every backend this CPU supports, including the
+ * fusion paths and the software tables, against check values and known-answer vectors
+ */
+struct CrcFastSelfTestReport crc_fast_self_test(void);
+
+/**
+ * Gets the predefined algorithm families compiled into this library. Custom parameters work
+ * with every width regardless.
+ */
+struct CrcFastFamilies crc_fast_get_families(void);
+
+/**
+ * Gets the counters and sizes of the custom parameter caches
+ */
+struct CrcFastCacheStats crc_fast_cache_stats(void);
+
+/**
+ * Sets the maximum number of entries in each custom parameter cache, evicting the least
+ * recently used entries beyond it
+ */
+void crc_fast_cache_set_capacity(uintptr_t capacity);
+
+/**
+ * Enables or disables the custom parameter caches, clearing them when disabled
+ */
+void crc_fast_cache_set_enabled(bool enabled);
+
+/**
+ * Caches the keys and, when the software backend is active, the lookup table for custom
+ * parameters ahead of first use
+ * Returns false if the parameters are invalid
+ * Call crc_fast_get_last_error() to get the specific error code
+ */
+bool crc_fast_cache_prewarm(struct CrcFastParams params);
+
+/**
+ * Removes every cached set of keys and lookup table for custom parameters
+ */
+void crc_fast_cache_clear(void);
+
 /**
  * Gets the version of this library
  * Returns a pointer to "unknown" if version string is invalid
@@ -304,7 +615,7 @@ const char *crc_fast_get_version(void);
  *
  * https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-iscsi
  *
- * Returns 0 on error (e.g. null data pointer)
+ * Returns 0 on error (e.g. null data pointer, or the crc32 family isn't compiled in)
  */
 uint32_t crc_fast_crc32_iscsi(const char *data, uintptr_t len);
 
@@ -314,7 +625,7 @@ uint32_t crc_fast_crc32_iscsi(const char *data, uintptr_t len);
  *
  * https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-iso-hdlc
  *
- * Returns 0 on error (e.g. null data pointer)
+ * Returns 0 on error (e.g. null data pointer, or the crc32 family isn't compiled in)
  */
 uint32_t crc_fast_crc32_iso_hdlc(const char *data, uintptr_t len);
 
@@ -323,7 +634,7 @@ uint32_t crc_fast_crc32_iso_hdlc(const char *data, uintptr_t len);
  *
  * https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-64-nvme
  *
- * Returns 0 on error (e.g. null data pointer)
+ * Returns 0 on error (e.g. null data pointer, or the crc64 family isn't compiled in)
  */
 uint64_t crc_fast_crc64_nvme(const char *data, uintptr_t len);
 
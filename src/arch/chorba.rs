@@ -0,0 +1,563 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides a multiplication-free fold for long inputs on the software path, in the
+//! style of Chorba (Sam Russell, "Chorba: A novel CRC32 implementation", 2024).
+//!
+//! If `G(x) = x^n + ... + x^t + ... + 1` is a sparse multiple of the CRC polynomial `P(x)`, then
+//! so is `G(x^64)`. Reading the message as 64-bit words, a word `n` words from the end can
+//! therefore be cancelled by XORing it into the words `n - t` positions further along, for every
+//! other term `x^t` of `G`, without changing the remainder modulo `P`. Folding the whole message
+//! forward this way leaves only its final `n` words, which the 16-lane lookup tables then
+//! finish.
+//!
+//! The fold only needs loads, XORs and a small history of recent words, and it has no serial
+//! dependency between neighbouring words, so it outruns slice-by-16 once the input is long
+//! enough to amortise the table-driven tail. It works for any width and either bit order, since
+//! it never looks inside a word.
+//!
+//! Plans for the polynomials of the predefined algorithms were found ahead of time. Dense CRC-64
+//! polynomials (and CRC-32/AUTOSAR) have no usable sparse multiple, so they stay on slice-by-16.
+//! Custom polynomials are searched once and cached, when a cache is available, alongside the
+//! other custom parameter caches managed by [crate::cache].
+
+use crate::CrcParams;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "alloc")]
+#[cfg(any(feature = "std", feature = "cache", test))]
+use alloc::vec::Vec;
+
+/// The number of folded words kept for lookback. Plans must span fewer words than this.
+const HISTORY_WORDS: usize = 512;
+
+/// The most terms, other than the leading one, a plan can have.
+const MAX_TAPS: usize = 5;
+
+/// Inputs shorter than this are left to slice-by-16, which is faster until the fold covers
+/// enough of the input to pay for clearing the history and for the table-driven tail.
+const MIN_INPUT_LEN: usize = 4096;
+
+/// A sparse multiple of a CRC polynomial, stored as the distances each folded word travels.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ChorbaPlan {
+    /// The degree `n` of the multiple, which is the number of words left for the tail.
+    span: u16,
+
+    /// `n - t` for each lower term `x^t` of the multiple.
+    taps: [u16; MAX_TAPS],
+
+    /// The number of entries in `taps` which are in use.
+    tap_count: u8,
+}
+
+impl ChorbaPlan {
+    /// Builds a plan from the ascending exponents of a multiple, starting with 0.
+    const fn from_exponents(exponents: &[u16]) -> Self {
+        assert!(exponents.len() >= 3 && exponents.len() <= MAX_TAPS + 1);
+
+        let span = exponents[exponents.len() - 1];
+        assert!((span as usize) < HISTORY_WORDS);
+
+        let mut taps = [0; MAX_TAPS];
+        let mut i = 0;
+        while i < exponents.len() - 1 {
+            taps[i] = span - exponents[i];
+            i += 1;
+        }
+
+        Self {
+            span,
+            taps,
+            tap_count: (exponents.len() - 1) as u8,
+        }
+    }
+
+    /// The shortest input, in bytes, for which the fold is faster than slice-by-16.
+    #[inline(always)]
+    fn threshold(&self) -> usize {
+        // folding has to cover several spans' worth of words to make up for the tail
+        MIN_INPUT_LEN.max(self.span as usize * 8 * 8)
+    }
+}
+
+/// Returns the plan to use for an input of `len` bytes with `params`, or `None` if slice-by-16
+/// is the better choice.
+#[inline(always)]
+pub(crate) fn plan(params: &CrcParams, len: usize) -> Option<ChorbaPlan> {
+    if len < MIN_INPUT_LEN {
+        return None;
+    }
+
+    predefined_plan(params.width, params.poly)
+        .or_else(|| searched_plan(params.width, params.poly))
+        .filter(|plan| len >= plan.threshold())
+}
+
+/// Returns the plan found ahead of time for the polynomials of the predefined algorithms.
+///
+/// Matching on the polynomial rather than the algorithm lets custom parameters which share one
+/// of these polynomials skip the search.
+fn predefined_plan(width: u8, poly: u64) -> Option<ChorbaPlan> {
+    let exponents: &[u16] = match (width, poly) {
+        (16, 0x0589) => &[0, 2, 127, 129],
+        (16, 0x080b) => &[0, 3, 5, 124],
+        (16, 0x1021) => &[0, 5, 12, 16],
+        (16, 0x1dcf) => &[0, 31, 89, 120],
+        (16, 0x3d65) => &[0, 1, 4, 6, 16, 22],
+        (16, 0x5935) => &[0, 13, 27, 41, 54],
+        (16, 0x6f63) => &[0, 14, 17, 22, 30],
+        (16, 0x755b) => &[0, 15, 78, 126],
+        (16, 0x8005) => &[0, 1, 14, 29],
+        (16, 0x8bb7) => &[0, 31, 369],
+        (16, 0xa097) => &[0, 6, 15, 83],
+        (16, 0xc867) => &[0, 43, 377],
+        (32, 0x000000af) => &[0, 75, 185, 296, 357],
+        (32, 0x04c11db7) => &[0, 89, 117, 155, 300],
+        (32, 0x1edc6f41) => &[0, 12, 41, 66, 185, 210],
+        (32, 0x741b8cd7) => &[0, 4, 19, 87, 138, 222],
+        (32, 0x8001801b) => &[0, 6, 30, 62],
+        (32, 0x814141ab) => &[0, 3, 40, 72, 220, 227],
+        (32, 0xa833982b) => &[0, 3, 16, 97, 110, 113],
+        (64, 0x000000000000001b) => &[0, 1, 3, 4, 64],
+        _ => return None,
+    };
+
+    Some(ChorbaPlan::from_exponents(exponents))
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "cache")))]
+use crate::cache::Lru;
+
+#[cfg(all(feature = "alloc", feature = "std"))]
+use std::sync::{Mutex, OnceLock};
+
+#[cfg(all(feature = "alloc", not(feature = "std"), feature = "cache"))]
+use spin::{Mutex, Once};
+
+/// Cache key: (width, poly)
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "cache")))]
+type PlanKey = (u8, u64);
+
+/// Search results for custom polynomials, including the ones which have no plan
+#[cfg(all(feature = "alloc", feature = "std"))]
+static CUSTOM_PLAN_CACHE: OnceLock<Mutex<Lru<PlanKey, Option<ChorbaPlan>>>> = OnceLock::new();
+
+/// Search results for custom polynomials, including the ones which have no plan
+#[cfg(all(feature = "alloc", not(feature = "std"), feature = "cache"))]
+static CUSTOM_PLAN_CACHE: Once<Mutex<Lru<PlanKey, Option<ChorbaPlan>>>> = Once::new();
+
+/// Runs `f` with exclusive access to the plan cache, recovering from lock poisoning.
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "cache")))]
+fn with_plans<R>(f: impl FnOnce(&mut Lru<PlanKey, Option<ChorbaPlan>>) -> R) -> R {
+    #[cfg(feature = "std")]
+    let mut plans = CUSTOM_PLAN_CACHE
+        .get_or_init(|| Mutex::new(Lru::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+
+    #[cfg(not(feature = "std"))]
+    let mut plans = CUSTOM_PLAN_CACHE
+        .call_once(|| Mutex::new(Lru::new()))
+        .lock();
+
+    f(&mut plans)
+}
+
+/// Returns the cached plan for a custom polynomial, searching for one on a miss, or `None`
+/// while caching is disabled or has no capacity.
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "cache")))]
+fn searched_plan(width: u8, poly: u64) -> Option<ChorbaPlan> {
+    if !crate::cache::is_enabled() {
+        return None;
+    }
+
+    let key = (width, poly);
+
+    if let Some(plan) = with_plans(|plans| plans.get(&key)) {
+        return plan;
+    }
+
+    // a search which won't be kept costs more than the fold saves
+    if crate::cache::capacity() == 0 {
+        return None;
+    }
+
+    // search outside the lock, since it takes up to tens of milliseconds
+    let plan = search(width, poly);
+    with_plans(|plans| plans.insert(key, plan, crate::cache::capacity()));
+
+    plan
+}
+
+/// Evicts the least recently used plans until at most `capacity` remain.
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "cache")))]
+pub(crate) fn shrink_plan_cache(capacity: usize) {
+    with_plans(|plans| plans.shrink_to(capacity));
+}
+
+/// Removes every cached plan.
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "cache")))]
+pub(crate) fn clear_plan_cache() {
+    with_plans(|plans| plans.clear());
+}
+
+/// Counts the cached plans, including the searches which found none.
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "cache")))]
+pub(crate) fn plan_cache_len() -> usize {
+    with_plans(|plans| plans.len())
+}
+
+/// Without a cache the search would run on every update, which costs more than it saves.
+#[cfg(not(all(feature = "alloc", any(feature = "std", feature = "cache"))))]
+fn searched_plan(_width: u8, _poly: u64) -> Option<ChorbaPlan> {
+    None
+}
+
+/// Searches for the multiple of `poly` with the fewest terms, then the smallest degree, up to
+/// five terms.
+///
+/// Each candidate degree `n` has the residues `x^i mod P` for `i < n` computed, and a multiple
+/// is a set of exponents, including 0, whose residues XOR to zero. Five-term multiples match
+/// pairs of residues against other pairs, which takes tens of milliseconds at the largest
+/// degree. Six-term multiples cost far more to find, so the predefined plans which need them
+/// were found offline.
+#[cfg(feature = "alloc")]
+#[cfg(any(feature = "std", feature = "cache"))]
+fn search(width: u8, poly: u64) -> Option<ChorbaPlan> {
+    let residues = residues(width, poly, HISTORY_WORDS);
+
+    for terms in 3..=5 {
+        let mut n = 32;
+
+        while n <= HISTORY_WORDS {
+            let r = &residues[..n];
+
+            let mut singles: Vec<(u64, u16)> = (1..n).map(|i| (r[i], i as u16)).collect();
+            singles.sort_unstable();
+
+            let find = |value: u64| {
+                singles
+                    .binary_search_by(|single| single.0.cmp(&value))
+                    .ok()
+                    .map(|k| singles[k].1 as usize)
+            };
+
+            let found: Option<Vec<usize>> = match terms {
+                3 => (1..n).find_map(|c| {
+                    find(1 ^ r[c])
+                        .filter(|&b| b < c)
+                        .map(|b| [0, b, c].to_vec())
+                }),
+                4 => (1..n).find_map(|c| {
+                    (c + 1..n).find_map(|d| {
+                        find(1 ^ r[c] ^ r[d])
+                            .filter(|&b| b < c)
+                            .map(|b| [0, b, c, d].to_vec())
+                    })
+                }),
+                _ => {
+                    let mut pairs: Vec<(u64, u16, u16)> = Vec::new();
+                    for b in 1..n {
+                        for c in b + 1..n {
+                            pairs.push((r[b] ^ r[c], b as u16, c as u16));
+                        }
+                    }
+                    pairs.sort_unstable();
+
+                    pairs.iter().find_map(|&(value, b, c)| {
+                        let start = pairs.partition_point(|pair| pair.0 < value ^ 1);
+
+                        pairs[start..]
+                            .iter()
+                            .take_while(|pair| pair.0 == value ^ 1)
+                            .find(|pair| pair.1 > c)
+                            .map(|&(_, d, e)| [0, b, c, d, e].map(usize::from).to_vec())
+                    })
+                }
+            };
+
+            if let Some(exponents) = found {
+                let exponents: Vec<u16> = exponents.iter().map(|&e| e as u16).collect();
+                return Some(ChorbaPlan::from_exponents(&exponents));
+            }
+
+            n *= 2;
+        }
+    }
+
+    None
+}
+
+/// Computes `x^i mod P` for `i < count`, in the normal (non-reflected) bit order.
+#[cfg(feature = "alloc")]
+#[cfg(any(feature = "std", feature = "cache", test))]
+fn residues(width: u8, poly: u64, count: usize) -> Vec<u64> {
+    let mask = u64::MAX >> (64 - width as u32);
+    let top = 1u64 << (width - 1);
+
+    let mut value = 1u64;
+    (0..count)
+        .map(|_| {
+            let residue = value;
+            let carry = value & top != 0;
+            value = (value << 1) & mask;
+            if carry {
+                value ^= poly & mask;
+            }
+            residue
+        })
+        .collect()
+}
+
+/// Updates the CRC state for a long input by folding it down to `plan.span` words, which `tail`
+/// then processes with the 16-lane lookup tables.
+///
+/// `tail` updates a CRC state of the same width and bit order as `state`. The state is merged
+/// into the leading bytes of the input, so the tail starts from zero.
+#[inline(always)]
+pub(crate) fn update(
+    state: u64,
+    bytes: &[u8],
+    width: u8,
+    reflected: bool,
+    plan: &ChorbaPlan,
+    tail: impl Fn(u64, &[u8]) -> u64,
+) -> u64 {
+    // the state covers the first width / 8 bytes of the input, in stream order
+    let state = if reflected {
+        state
+    } else {
+        (state << (64 - width as u32)).swap_bytes()
+    };
+
+    let taps = &plan.taps;
+    let span = plan.span as usize;
+
+    match plan.tap_count {
+        2 => fold::<2>(state, bytes, span, [taps[0], taps[1]], tail),
+        3 => fold::<3>(state, bytes, span, [taps[0], taps[1], taps[2]], tail),
+        4 => fold::<4>(
+            state,
+            bytes,
+            span,
+            [taps[0], taps[1], taps[2], taps[3]],
+            tail,
+        ),
+        _ => fold::<5>(state, bytes, span, *taps, tail),
+    }
+}
+
+#[inline(always)]
+fn fold<const TAPS: usize>(
+    state: u64,
+    bytes: &[u8],
+    span: usize,
+    taps: [u16; TAPS],
+    tail: impl Fn(u64, &[u8]) -> u64,
+) -> u64 {
+    const MASK: usize = HISTORY_WORDS - 1;
+
+    let taps = taps.map(usize::from);
+    let words = bytes.len() / 8;
+    let folded = words.saturating_sub(span);
+
+    // slots not yet written stand in for the words before the input, which are zero
+    let mut history = [0u64; HISTORY_WORDS];
+    let mut inject = state;
+
+    // each word takes on the words `taps` positions behind it, after they were folded themselves
+    for (i, chunk) in bytes[..folded * 8].chunks_exact(8).enumerate() {
+        let mut word = u64::from_le_bytes(chunk.try_into().unwrap()) ^ inject;
+        for d in taps {
+            word ^= history[i.wrapping_sub(d) & MASK];
+        }
+        history[i & MASK] = word;
+        inject = 0;
+    }
+
+    // the remaining words only take on folded words, and are buffered so the tables can
+    // process 16 bytes at a time
+    let mut crc = 0;
+    let mut buffer = [0u8; 128];
+    let mut buffered = 0;
+
+    for (i, chunk) in bytes[folded * 8..words * 8].chunks_exact(8).enumerate() {
+        let i = folded + i;
+        let mut word = u64::from_le_bytes(chunk.try_into().unwrap()) ^ inject;
+        for d in taps {
+            if i >= d && i - d < folded {
+                word ^= history[(i - d) & MASK];
+            }
+        }
+        inject = 0;
+
+        buffer[buffered..buffered + 8].copy_from_slice(&word.to_le_bytes());
+        buffered += 8;
+
+        if buffered == buffer.len() {
+            crc = tail(crc, &buffer);
+            buffered = 0;
+        }
+    }
+
+    crc = tail(crc, &buffer[..buffered]);
+
+    tail(crc, &bytes[words * 8..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arch::software::{
+        crc16_table, crc32_table, crc64_table, native_update_u16, native_update_u32,
+        native_update_u64,
+    };
+    use crate::test::consts::TEST_ALL_CONFIGS;
+
+    fn test_data(len: usize) -> Vec<u8> {
+        (0..len).map(|i| ((i * 2654435761) >> 13) as u8).collect()
+    }
+
+    /// Checks that the exponents behind `plan` describe a multiple of `poly`.
+    fn assert_multiple(width: u8, poly: u64, plan: &ChorbaPlan) {
+        let r = residues(width, poly, HISTORY_WORDS);
+        let span = plan.span as usize;
+
+        let sum = plan.taps[..plan.tap_count as usize]
+            .iter()
+            .fold(r[span], |sum, &d| sum ^ r[span - d as usize]);
+
+        assert_eq!(sum, 0, "{plan:?} isn't a multiple of {poly:#x}");
+    }
+
+    #[test]
+    fn test_predefined_plans_are_multiples() {
+        for config in TEST_ALL_CONFIGS {
+            let params = config.get_params();
+
+            if let Some(plan) = predefined_plan(params.width, params.poly) {
+                assert_multiple(params.width, params.poly, &plan);
+            } else {
+                // only polynomials without a practical sparse multiple are left out
+                assert!(
+                    params.width == 64 || params.poly == 0xf4acfb13,
+                    "{} has no plan",
+                    config.get_name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "cache"))]
+    fn test_search_finds_multiples() {
+        // CRC-32/ISO-HDLC, CRC-16/ARC, CRC-64/GO-ISO and CRC-32/CD-ROM-EDC
+        for (width, poly) in [(32, 0x04c11db7), (16, 0x8005), (64, 0x1b), (32, 0x8001801b)] {
+            let plan = search(width, poly).expect("a multiple within reach");
+            assert_multiple(width, poly, &plan);
+        }
+
+        // CRC-64/XZ has no sparse multiple of a usable degree
+        assert_eq!(search(64, 0x42f0e1eba9ea3693), None);
+    }
+
+    #[test]
+    fn test_fold_matches_tables() {
+        let data = test_data(24 * 1024 + 13);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = config.get_params();
+            let Some(plan) = predefined_plan(params.width, params.poly) else {
+                continue;
+            };
+
+            let lens = [
+                plan.threshold(),
+                plan.threshold() + 1,
+                plan.threshold() + 7,
+                plan.threshold() + 129,
+                8 * 1024 + 3,
+                data.len(),
+            ];
+
+            for len in lens {
+                // a non-trivial starting state, as when continuing a digest
+                let state = config.get_init_algorithm() ^ 0x5a5a_a5a5_1234_5678;
+
+                let (expected, actual) = match params.width {
+                    16 => {
+                        let (table, refin) = crc16_table(params.algorithm).unwrap();
+                        let tail = |crc: u64, bytes: &[u8]| {
+                            native_update_u16(crc as u16, table, refin, bytes) as u64
+                        };
+
+                        (
+                            tail(state & 0xffff, &data[..len]),
+                            update(state & 0xffff, &data[..len], 16, refin, &plan, tail),
+                        )
+                    }
+                    32 => {
+                        let (table, refin) = crc32_table(params.algorithm).unwrap();
+                        let tail = |crc: u64, bytes: &[u8]| {
+                            native_update_u32(crc as u32, table, refin, bytes) as u64
+                        };
+
+                        (
+                            tail(state & 0xffffffff, &data[..len]),
+                            update(state & 0xffffffff, &data[..len], 32, refin, &plan, tail),
+                        )
+                    }
+                    _ => {
+                        let (table, refin) = crc64_table(params.algorithm).unwrap();
+                        let tail =
+                            |crc: u64, bytes: &[u8]| native_update_u64(crc, table, refin, bytes);
+
+                        (
+                            tail(state, &data[..len]),
+                            update(state, &data[..len], 64, refin, &plan, tail),
+                        )
+                    }
+                };
+
+                assert_eq!(
+                    actual,
+                    expected,
+                    "{} mismatch at {len} bytes",
+                    config.get_name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_software_path_matches_reference() {
+        let data = test_data(16 * 1024 + 5);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = config.get_params();
+
+            for len in [MIN_INPUT_LEN, 4096, 10000, data.len()] {
+                let state = crate::arch::software::update(
+                    config.get_init_algorithm(),
+                    &data[..len],
+                    params,
+                );
+
+                assert_eq!(
+                    state ^ config.get_xorout(),
+                    config.checksum_with_reference(&data[..len]),
+                    "{} mismatch at {len} bytes",
+                    config.get_name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_short_inputs_have_no_plan() {
+        for config in TEST_ALL_CONFIGS {
+            assert_eq!(plan(config.get_params(), MIN_INPUT_LEN - 1), None);
+        }
+    }
+}
@@ -4,10 +4,16 @@
 //!
 //! It dispatches to the appropriate architecture-specific implementation
 
-#[cfg(all(target_arch = "aarch64", feature = "std"))]
-use std::arch::is_aarch64_feature_detected;
-
-use crate::CrcParams;
+use crate::feature_detection::PerformanceTier;
+use crate::{CalculatorFn, CrcParams};
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
+use crate::feature_detection::{get_arch_ops, ArchOpsInstance};
 
 #[cfg(target_arch = "aarch64")]
 use crate::arch::aarch64::aes::Aarch64AesOps;
@@ -15,174 +21,389 @@ use crate::arch::aarch64::aes::Aarch64AesOps;
 #[cfg(target_arch = "aarch64")]
 use crate::arch::aarch64::aes_sha3::Aarch64AesSha3Ops;
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+#[cfg(target_arch = "riscv64")]
+use crate::arch::riscv64::{zbc::Riscv64ZbcOps, zvbc::Riscv64ZvbcOps};
+
+#[cfg(target_arch = "riscv64")]
+use crate::traits::ArchOps;
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
 use crate::{
     algorithm,
     structs::{Width16, Width32, Width64},
 };
 
 pub mod aarch64;
+pub(crate) mod chorba;
+pub mod riscv64;
+mod small;
 pub mod software;
 pub mod x86;
 pub mod x86_64;
 
+pub(crate) use small::{SmallTable, SMALL_INPUT_THRESHOLD};
+
 /// Main entry point that dispatches to the appropriate architecture
 ///
 /// # Safety
 /// May use native CPU features
 #[inline(always)]
-#[cfg(target_arch = "aarch64")]
 pub(crate) unsafe fn update(state: u64, bytes: &[u8], params: &CrcParams) -> u64 {
-    use crate::feature_detection::{get_arch_ops, ArchOpsInstance};
-
-    match get_arch_ops() {
-        ArchOpsInstance::Aarch64AesSha3(ops) => update_aarch64_aes_sha3(state, bytes, params, *ops),
-        ArchOpsInstance::Aarch64Aes(ops) => update_aarch64_aes(state, bytes, params, *ops),
-        ArchOpsInstance::SoftwareFallback => {
-            #[cfg(feature = "std")]
-            let has_features =
-                is_aarch64_feature_detected!("aes") && is_aarch64_feature_detected!("neon");
-            #[cfg(not(feature = "std"))]
-            let has_features = cfg!(target_feature = "aes") && cfg!(target_feature = "neon");
-
-            if !has_features {
-                #[cfg(any(not(target_feature = "aes"), not(target_feature = "neon")))]
-                {
-                    // Use software implementation when no SIMD support is available
-                    return crate::arch::software::update(state, bytes, params);
-                }
-            }
+    if let Some(state) = small::update(state, bytes, params) {
+        return state;
+    }
 
-            // This should likely never happen, but just in case
-            panic!("aarch64 features missing (NEON and/or AES)");
-        }
+    resolve(params)(state, bytes, params)
+}
+
+/// Resolves the update routine specialised for the active tier and the width of `params`.
+///
+/// The routine doesn't include the table-driven small-input path, which callers holding a
+/// resolved routine pair with a [SmallTable] resolved at the same time. The accelerated tiers do
+/// handle short inputs without the SIMD engine, with [small::update_with_keys].
+#[inline(always)]
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
+pub(crate) fn resolve(params: &CrcParams) -> CalculatorFn {
+    resolve_ops(get_arch_ops(), params)
+}
+
+/// Resolves the update routine for a specific tier, or returns `None` if this CPU doesn't
+/// support it.
+pub(crate) fn resolve_tier(tier: PerformanceTier, params: &CrcParams) -> Option<CalculatorFn> {
+    if !tier.is_available() {
+        return None;
+    }
+
+    #[cfg(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    ))]
+    {
+        Some(resolve_ops(
+            crate::feature_detection::create_arch_ops_from_tier(tier),
+            params,
+        ))
+    }
+
+    #[cfg(not(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )))]
+    {
+        Some(resolve(params))
     }
 }
 
+#[inline(always)]
+#[cfg(target_arch = "aarch64")]
+fn resolve_ops(ops: ArchOpsInstance, params: &CrcParams) -> CalculatorFn {
+    match (ops, params.width) {
+        (ArchOpsInstance::Aarch64AesSha3(_), 64) => resolved_aarch64_aes_sha3::<64>,
+        (ArchOpsInstance::Aarch64AesSha3(_), 32) => resolved_aarch64_aes_sha3::<32>,
+        (ArchOpsInstance::Aarch64AesSha3(_), 16) => resolved_aarch64_aes_sha3::<16>,
+        (ArchOpsInstance::Aarch64Aes(_), 64) => resolved_aarch64_aes::<64>,
+        (ArchOpsInstance::Aarch64Aes(_), 32) => resolved_aarch64_aes::<32>,
+        (ArchOpsInstance::Aarch64Aes(_), 16) => resolved_aarch64_aes::<16>,
+        (ArchOpsInstance::SoftwareFallback, _) => resolved_software,
+        (_, width) => panic!("Unsupported CRC width: {}", width),
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+fn resolved_aarch64_aes_sha3<const WIDTH: u8>(state: u64, bytes: &[u8], params: &CrcParams) -> u64 {
+    // SAFETY: only resolved after AES and SHA3 support was detected
+    unsafe { update_aarch64_aes_sha3::<WIDTH>(state, bytes, params) }
+}
+
+#[cfg(target_arch = "aarch64")]
+fn resolved_aarch64_aes<const WIDTH: u8>(state: u64, bytes: &[u8], params: &CrcParams) -> u64 {
+    // SAFETY: only resolved after AES support was detected
+    unsafe { update_aarch64_aes::<WIDTH>(state, bytes, params) }
+}
+
 #[inline]
 #[cfg(target_arch = "aarch64")]
 #[target_feature(enable = "aes")]
-unsafe fn update_aarch64_aes(
-    state: u64,
-    bytes: &[u8],
-    params: &CrcParams,
-    ops: Aarch64AesOps,
-) -> u64 {
-    match params.width {
+unsafe fn update_aarch64_aes<const WIDTH: u8>(state: u64, bytes: &[u8], params: &CrcParams) -> u64 {
+    let ops = Aarch64AesOps;
+
+    if bytes.len() < small::keys_threshold(WIDTH) {
+        return small::update_with_keys::<_, WIDTH>(state, bytes, params, &ops);
+    }
+
+    match WIDTH {
         64 => algorithm::update::<_, Width64>(state, bytes, params, &ops),
         32 => algorithm::update::<_, Width32>(state as u32, bytes, params, &ops) as u64,
         16 => algorithm::update::<_, Width16>(state as u16, bytes, params, &ops) as u64,
-        _ => panic!("Unsupported CRC width: {}", params.width),
+        _ => panic!("Unsupported CRC width: {}", WIDTH),
     }
 }
 
 #[inline]
 #[cfg(target_arch = "aarch64")]
 #[target_feature(enable = "aes,sha3")]
-unsafe fn update_aarch64_aes_sha3(
+unsafe fn update_aarch64_aes_sha3<const WIDTH: u8>(
     state: u64,
     bytes: &[u8],
     params: &CrcParams,
-    ops: Aarch64AesSha3Ops,
 ) -> u64 {
-    match params.width {
+    let ops = Aarch64AesSha3Ops::new();
+
+    if bytes.len() < small::keys_threshold(WIDTH) {
+        return small::update_with_keys::<_, WIDTH>(state, bytes, params, &ops);
+    }
+
+    match WIDTH {
         64 => algorithm::update::<_, Width64>(state, bytes, params, &ops),
         32 => algorithm::update::<_, Width32>(state as u32, bytes, params, &ops) as u64,
         16 => algorithm::update::<_, Width16>(state as u16, bytes, params, &ops) as u64,
-        _ => panic!("Unsupported CRC width: {}", params.width),
+        _ => panic!("Unsupported CRC width: {}", WIDTH),
     }
 }
 
-/// Main entry point for x86/x86_64
-///
-/// # Safety
-/// May use native CPU features
 #[inline(always)]
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-pub(crate) unsafe fn update(state: u64, bytes: &[u8], params: &CrcParams) -> u64 {
-    use crate::feature_detection::{get_arch_ops, ArchOpsInstance};
-
-    match get_arch_ops() {
+fn resolve_ops(ops: ArchOpsInstance, params: &CrcParams) -> CalculatorFn {
+    match (ops, params.width) {
         #[cfg(target_arch = "x86_64")]
-        ArchOpsInstance::X86_64Avx512Vpclmulqdq(ops) => {
-            update_x86_64_avx512_vpclmulqdq(state, bytes, params, *ops)
-        }
+        (ArchOpsInstance::X86_64Avx512Vpclmulqdq(_), 64) => resolved_x86_64_avx512_vpclmulqdq::<64>,
         #[cfg(target_arch = "x86_64")]
-        ArchOpsInstance::X86_64Avx512Pclmulqdq(ops) => {
-            update_x86_64_avx512_pclmulqdq(state, bytes, params, *ops)
-        }
-        ArchOpsInstance::X86SsePclmulqdq(ops) => {
-            update_x86_sse_pclmulqdq(state, bytes, params, *ops)
-        }
-        ArchOpsInstance::SoftwareFallback => crate::arch::software::update(state, bytes, params),
+        (ArchOpsInstance::X86_64Avx512Vpclmulqdq(_), 32) => resolved_x86_64_avx512_vpclmulqdq::<32>,
+        #[cfg(target_arch = "x86_64")]
+        (ArchOpsInstance::X86_64Avx512Vpclmulqdq(_), 16) => resolved_x86_64_avx512_vpclmulqdq::<16>,
+        #[cfg(target_arch = "x86_64")]
+        (ArchOpsInstance::X86_64Avx2Vpclmulqdq(_), 64) => resolved_x86_64_avx2_vpclmulqdq::<64>,
+        #[cfg(target_arch = "x86_64")]
+        (ArchOpsInstance::X86_64Avx2Vpclmulqdq(_), 32) => resolved_x86_64_avx2_vpclmulqdq::<32>,
+        #[cfg(target_arch = "x86_64")]
+        (ArchOpsInstance::X86_64Avx2Vpclmulqdq(_), 16) => resolved_x86_64_avx2_vpclmulqdq::<16>,
+        #[cfg(target_arch = "x86_64")]
+        (ArchOpsInstance::X86_64Avx512Pclmulqdq(_), 64) => resolved_x86_64_avx512_pclmulqdq::<64>,
+        #[cfg(target_arch = "x86_64")]
+        (ArchOpsInstance::X86_64Avx512Pclmulqdq(_), 32) => resolved_x86_64_avx512_pclmulqdq::<32>,
+        #[cfg(target_arch = "x86_64")]
+        (ArchOpsInstance::X86_64Avx512Pclmulqdq(_), 16) => resolved_x86_64_avx512_pclmulqdq::<16>,
+        (ArchOpsInstance::X86SsePclmulqdq(_), 64) => resolved_x86_sse_pclmulqdq::<64>,
+        (ArchOpsInstance::X86SsePclmulqdq(_), 32) => resolved_x86_sse_pclmulqdq::<32>,
+        (ArchOpsInstance::X86SsePclmulqdq(_), 16) => resolved_x86_sse_pclmulqdq::<16>,
+        (ArchOpsInstance::SoftwareFallback, _) => resolved_software,
+        (_, width) => panic!("Unsupported CRC width: {}", width),
     }
 }
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn resolved_x86_sse_pclmulqdq<const WIDTH: u8>(
+    state: u64,
+    bytes: &[u8],
+    params: &CrcParams,
+) -> u64 {
+    // SAFETY: only resolved after SSE4.1 and PCLMULQDQ support was detected
+    unsafe { update_x86_sse_pclmulqdq::<WIDTH>(state, bytes, params) }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn resolved_x86_64_avx512_pclmulqdq<const WIDTH: u8>(
+    state: u64,
+    bytes: &[u8],
+    params: &CrcParams,
+) -> u64 {
+    // SAFETY: only resolved after AVX512VL and PCLMULQDQ support was detected
+    unsafe { update_x86_64_avx512_pclmulqdq::<WIDTH>(state, bytes, params) }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn resolved_x86_64_avx512_vpclmulqdq<const WIDTH: u8>(
+    state: u64,
+    bytes: &[u8],
+    params: &CrcParams,
+) -> u64 {
+    // SAFETY: only resolved after AVX512VL and VPCLMULQDQ support was detected
+    unsafe { update_x86_64_avx512_vpclmulqdq::<WIDTH>(state, bytes, params) }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn resolved_x86_64_avx2_vpclmulqdq<const WIDTH: u8>(
+    state: u64,
+    bytes: &[u8],
+    params: &CrcParams,
+) -> u64 {
+    // SAFETY: only resolved after AVX2 and VPCLMULQDQ support was detected
+    unsafe { update_x86_64_avx2_vpclmulqdq::<WIDTH>(state, bytes, params) }
+}
+
 #[inline]
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[target_feature(enable = "sse4.1,pclmulqdq")]
-unsafe fn update_x86_sse_pclmulqdq(
+unsafe fn update_x86_sse_pclmulqdq<const WIDTH: u8>(
     state: u64,
     bytes: &[u8],
     params: &CrcParams,
-    ops: crate::arch::x86::sse::X86SsePclmulqdqOps,
 ) -> u64 {
-    match params.width {
+    let ops = crate::arch::x86::sse::X86SsePclmulqdqOps;
+
+    if bytes.len() < small::keys_threshold(WIDTH) {
+        return small::update_with_keys::<_, WIDTH>(state, bytes, params, &ops);
+    }
+
+    match WIDTH {
         64 => algorithm::update::<_, Width64>(state, bytes, params, &ops),
         32 => algorithm::update::<_, Width32>(state as u32, bytes, params, &ops) as u64,
         16 => algorithm::update::<_, Width16>(state as u16, bytes, params, &ops) as u64,
-        _ => panic!("Unsupported CRC width: {}", params.width),
+        _ => panic!("Unsupported CRC width: {}", WIDTH),
     }
 }
 
 #[inline]
 #[cfg(target_arch = "x86_64")]
 #[target_feature(enable = "avx512vl,pclmulqdq")]
-unsafe fn update_x86_64_avx512_pclmulqdq(
+unsafe fn update_x86_64_avx512_pclmulqdq<const WIDTH: u8>(
     state: u64,
     bytes: &[u8],
     params: &CrcParams,
-    ops: crate::arch::x86_64::avx512::X86_64Avx512PclmulqdqOps,
 ) -> u64 {
-    match params.width {
+    let ops = crate::arch::x86_64::avx512::X86_64Avx512PclmulqdqOps::new();
+
+    if bytes.len() < small::keys_threshold(WIDTH) {
+        return small::update_with_keys::<_, WIDTH>(state, bytes, params, &ops);
+    }
+
+    match WIDTH {
         64 => algorithm::update::<_, Width64>(state, bytes, params, &ops),
         32 => algorithm::update::<_, Width32>(state as u32, bytes, params, &ops) as u64,
         16 => algorithm::update::<_, Width16>(state as u16, bytes, params, &ops) as u64,
-        _ => panic!("Unsupported CRC width: {}", params.width),
+        _ => panic!("Unsupported CRC width: {}", WIDTH),
     }
 }
 
 #[inline]
 #[cfg(target_arch = "x86_64")]
 #[target_feature(enable = "avx512vl,vpclmulqdq")]
-unsafe fn update_x86_64_avx512_vpclmulqdq(
+unsafe fn update_x86_64_avx512_vpclmulqdq<const WIDTH: u8>(
     state: u64,
     bytes: &[u8],
     params: &CrcParams,
-    ops: crate::arch::x86_64::avx512_vpclmulqdq::X86_64Avx512VpclmulqdqOps,
 ) -> u64 {
-    match params.width {
+    let ops = crate::arch::x86_64::avx512_vpclmulqdq::X86_64Avx512VpclmulqdqOps::new();
+
+    if bytes.len() < small::keys_threshold(WIDTH) {
+        return small::update_with_keys::<_, WIDTH>(state, bytes, params, &ops);
+    }
+
+    match WIDTH {
         64 => algorithm::update::<_, Width64>(state, bytes, params, &ops),
         32 => algorithm::update::<_, Width32>(state as u32, bytes, params, &ops) as u64,
         16 => algorithm::update::<_, Width16>(state as u16, bytes, params, &ops) as u64,
-        _ => panic!("Unsupported CRC width: {}", params.width),
+        _ => panic!("Unsupported CRC width: {}", WIDTH),
+    }
+}
+
+#[inline]
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2,vpclmulqdq")]
+unsafe fn update_x86_64_avx2_vpclmulqdq<const WIDTH: u8>(
+    state: u64,
+    bytes: &[u8],
+    params: &CrcParams,
+) -> u64 {
+    let ops = crate::arch::x86_64::avx2_vpclmulqdq::X86_64Avx2VpclmulqdqOps::new();
+
+    if bytes.len() < small::keys_threshold(WIDTH) {
+        return small::update_with_keys::<_, WIDTH>(state, bytes, params, &ops);
+    }
+
+    match WIDTH {
+        64 => algorithm::update::<_, Width64>(state, bytes, params, &ops),
+        32 => algorithm::update::<_, Width32>(state as u32, bytes, params, &ops) as u64,
+        16 => algorithm::update::<_, Width16>(state as u16, bytes, params, &ops) as u64,
+        _ => panic!("Unsupported CRC width: {}", WIDTH),
     }
 }
 
+#[inline(always)]
+#[cfg(target_arch = "riscv64")]
+fn resolve_ops(ops: ArchOpsInstance, params: &CrcParams) -> CalculatorFn {
+    match (ops, params.width) {
+        (ArchOpsInstance::Riscv64Zvbc(_), 64) => resolved_riscv64_zvbc::<64>,
+        (ArchOpsInstance::Riscv64Zvbc(_), 32) => resolved_riscv64_zvbc::<32>,
+        (ArchOpsInstance::Riscv64Zvbc(_), 16) => resolved_riscv64_zvbc::<16>,
+        (ArchOpsInstance::Riscv64Zbc(_), 64) => resolved_riscv64_zbc::<64>,
+        (ArchOpsInstance::Riscv64Zbc(_), 32) => resolved_riscv64_zbc::<32>,
+        (ArchOpsInstance::Riscv64Zbc(_), 16) => resolved_riscv64_zbc::<16>,
+        (ArchOpsInstance::SoftwareFallback, _) => resolved_software,
+        (_, width) => panic!("Unsupported CRC width: {}", width),
+    }
+}
+
+#[cfg(target_arch = "riscv64")]
+fn resolved_riscv64_zvbc<const WIDTH: u8>(state: u64, bytes: &[u8], params: &CrcParams) -> u64 {
+    // SAFETY: only resolved after Zbc, V and Zvbc support was detected
+    unsafe { update_riscv64::<_, WIDTH>(state, bytes, params, &Riscv64ZvbcOps::new()) }
+}
+
+#[cfg(target_arch = "riscv64")]
+fn resolved_riscv64_zbc<const WIDTH: u8>(state: u64, bytes: &[u8], params: &CrcParams) -> u64 {
+    // SAFETY: only resolved after Zbc support was detected
+    unsafe { update_riscv64::<_, WIDTH>(state, bytes, params, &Riscv64ZbcOps) }
+}
+
+/// The extensions are enabled inside the inline assembly rather than with `target_feature`, so
+/// both RISC-V tiers share this routine.
+#[inline]
+#[cfg(target_arch = "riscv64")]
+unsafe fn update_riscv64<T: ArchOps<Vector = u128>, const WIDTH: u8>(
+    state: u64,
+    bytes: &[u8],
+    params: &CrcParams,
+    ops: &T,
+) -> u64 {
+    if bytes.len() < small::keys_threshold(WIDTH) {
+        return small::update_with_keys::<_, WIDTH>(state, bytes, params, ops);
+    }
+
+    match WIDTH {
+        64 => algorithm::update::<_, Width64>(state, bytes, params, ops),
+        32 => algorithm::update::<_, Width32>(state as u32, bytes, params, ops) as u64,
+        16 => algorithm::update::<_, Width16>(state as u16, bytes, params, ops) as u64,
+        _ => panic!("Unsupported CRC width: {}", WIDTH),
+    }
+}
+
+/// Resolves the software implementation, which is the only option on this architecture.
 #[inline(always)]
 #[cfg(all(
     not(target_arch = "x86"),
     not(target_arch = "x86_64"),
-    not(target_arch = "aarch64")
+    not(target_arch = "aarch64"),
+    not(target_arch = "riscv64")
 ))]
-pub(crate) unsafe fn update(state: u64, bytes: &[u8], params: &CrcParams) -> u64 {
-    crate::arch::software::update(state, bytes, params)
+pub(crate) fn resolve(_params: &CrcParams) -> CalculatorFn {
+    resolved_software
+}
+
+fn resolved_software(state: u64, bytes: &[u8], params: &CrcParams) -> u64 {
+    software::update(state, bytes, params)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "crc32")]
     use crate::crc32::consts::CRC32_BZIP2;
+    #[cfg(feature = "crc64")]
     use crate::crc64::consts::CRC64_NVME;
     use crate::test::consts::{TEST_256_BYTES_STRING, TEST_ALL_CONFIGS, TEST_CHECK_STRING};
     use crate::test::create_aligned_data;
@@ -285,6 +506,7 @@ mod tests {
     // CRC-64/NVME is a special flower in that Rust's crc library doesn't support it yet, so we have
     // tested values to check against.
     #[test]
+    #[cfg(feature = "crc64")]
     fn test_crc64_nvme_standard_vectors() {
         static CASES: &[(&[u8], u64)] = &[
             // from our own internal tests, since the Check value in the NVM Express® NVM Command
@@ -333,6 +555,7 @@ mod tests {
     ///
     /// https://www.php.net/manual/en/function.hash-file.php#104836
     #[test]
+    #[cfg(feature = "crc32")]
     fn test_crc32_php_standard_vectors() {
         static CASES: &[(&[u8], u64)] = &[
             (b"123456789", 0x181989fc),
@@ -359,6 +582,7 @@ mod tests {
 
     /// Test CRC-16/IBM-SDLC check value (reflected variant)
     #[test]
+    #[cfg(feature = "crc16")]
     fn test_crc16_ibm_sdlc_check_value() {
         use crate::crc16::consts::CRC16_IBM_SDLC;
 
@@ -375,6 +599,7 @@ mod tests {
 
     /// Test CRC-16/T10-DIF check value (forward/non-reflected variant)
     #[test]
+    #[cfg(feature = "crc16")]
     fn test_crc16_t10_dif_check_value() {
         use crate::crc16::consts::CRC16_T10_DIF;
 
@@ -403,6 +628,77 @@ mod tests {
         }
     }
 
+    /// The small-input path handles these lengths for predefined algorithms, so run the same
+    /// parameters as a custom algorithm to keep the keys-based path of each tier covered.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_small_lengths_without_tables() {
+        for config in TEST_ALL_CONFIGS {
+            let params = CrcParams {
+                algorithm: crate::CrcAlgorithm::CrcCustom,
+                ..*config.get_params()
+            };
+
+            for len in 0..small::SMALL_INPUT_THRESHOLD {
+                let mut data = vec![0u8; len];
+                rng().fill(&mut data[..]);
+
+                let actual = unsafe {
+                    update(config.get_init_algorithm(), &data, &params) ^ config.get_xorout()
+                };
+
+                assert_eq!(
+                    actual,
+                    config.checksum_with_reference(&data),
+                    "Mismatch CRC, {}, length {}",
+                    config.get_name(),
+                    len
+                );
+            }
+        }
+    }
+
+    /// Short inputs no longer reach the SIMD engine through any tier, so call it directly to keep
+    /// its handling of partial blocks verified.
+    #[test]
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "std"))]
+    #[cfg_attr(miri, ignore)]
+    fn test_small_lengths_engine() {
+        if !is_x86_feature_detected!("sse4.1") || !is_x86_feature_detected!("pclmulqdq") {
+            return;
+        }
+
+        let ops = crate::arch::x86::sse::X86SsePclmulqdqOps;
+
+        for config in TEST_ALL_CONFIGS {
+            let params = config.get_params();
+            let state = config.get_init_algorithm();
+
+            for len in 0..small::SMALL_INPUT_THRESHOLD {
+                let mut data = vec![0u8; len];
+                rng().fill(&mut data[..]);
+
+                let actual = unsafe {
+                    match params.width {
+                        64 => algorithm::update::<_, Width64>(state, &data, params, &ops),
+                        32 => algorithm::update::<_, Width32>(state as u32, &data, params, &ops)
+                            as u64,
+                        _ => algorithm::update::<_, Width16>(state as u16, &data, params, &ops)
+                            as u64,
+                    }
+                };
+
+                assert_eq!(
+                    actual ^ config.get_xorout(),
+                    config.checksum_with_reference(&data),
+                    "Mismatch CRC, {}, length {}",
+                    config.get_name(),
+                    len
+                );
+            }
+        }
+    }
+
     /// Skipping for Miri runs due to time constraints, underlying code already covered by other
     /// tests.
     #[test]
@@ -431,6 +727,46 @@ mod tests {
         }
     }
 
+    /// The AVX2 tier is only selected on CPUs without AVX-512, so call it directly wherever AVX2
+    /// and VPCLMULQDQ are available (including AVX-512 hardware) to keep it verified.
+    #[test]
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    #[cfg_attr(miri, ignore)]
+    fn test_forced_avx2_vpclmulqdq() {
+        if !is_x86_feature_detected!("avx2") || !is_x86_feature_detected!("vpclmulqdq") {
+            return;
+        }
+
+        let mut data = vec![0u8; 4096];
+        rng().fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = config.get_params();
+            let forced = match params.width {
+                64 => resolved_x86_64_avx2_vpclmulqdq::<64>,
+                32 => resolved_x86_64_avx2_vpclmulqdq::<32>,
+                _ => resolved_x86_64_avx2_vpclmulqdq::<16>,
+            };
+
+            // every length through 1 KiB, covering each number of 128-byte blocks and remainders,
+            // and a few unaligned starts
+            for (offset, len) in (0..=1024).map(|len| (0, len)).chain([(1, 4095), (7, 4000)]) {
+                let data = &data[offset..offset + len];
+                let actual =
+                    forced(config.get_init_algorithm(), data, params) ^ config.get_xorout();
+
+                assert_eq!(
+                    actual,
+                    config.checksum_with_reference(data),
+                    "Mismatch CRC, {}, length {}, offset {}",
+                    config.get_name(),
+                    len,
+                    offset
+                );
+            }
+        }
+    }
+
     fn test_length(length: usize, config: &AnyCrcTestConfig) {
         let mut data = vec![0u8; length];
         rng().fill(&mut data[..]);
@@ -0,0 +1,8 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides RISC-V 64-bit implementations of the ArchOps trait.
+
+#![cfg(target_arch = "riscv64")]
+
+pub mod zbc;
+pub mod zvbc;
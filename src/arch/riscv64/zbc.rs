@@ -0,0 +1,252 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides RISC-V 64-bit implementations of the ArchOps trait for CPUs with the Zbc
+//! (scalar carry-less multiplication) extension.
+//!
+//! There are no 128-bit vector registers in the base ISA, so each vector is a `u128` held in a
+//! pair of general purpose registers, laid out like an SSE register (lane 0 is the low 64 bits),
+//! and the carry-less multiplications use the `clmul` and `clmulh` instructions.
+
+#![cfg(target_arch = "riscv64")]
+
+use crate::traits::ArchOps;
+use core::arch::asm;
+
+/// Base RISC-V Zbc implementation
+#[derive(Debug, Copy, Clone)]
+pub struct Riscv64ZbcOps;
+
+impl ArchOps for Riscv64ZbcOps {
+    type Vector = u128;
+
+    #[inline]
+    unsafe fn create_vector_from_u64_pair(
+        &self,
+        high: u64,
+        low: u64,
+        reflected: bool,
+    ) -> Self::Vector {
+        // Same lane order as x86
+        if reflected {
+            from_u64s(low, high)
+        } else {
+            from_u64s(high, low)
+        }
+    }
+
+    #[inline]
+    unsafe fn create_vector_from_u64_pair_non_reflected(
+        &self,
+        high: u64,
+        low: u64,
+    ) -> Self::Vector {
+        from_u64s(high, low)
+    }
+
+    #[inline]
+    unsafe fn create_vector_from_u64(&self, value: u64, high: bool) -> Self::Vector {
+        if high {
+            from_u64s(value, 0)
+        } else {
+            from_u64s(0, value)
+        }
+    }
+
+    #[inline]
+    unsafe fn extract_u64s(&self, vector: Self::Vector) -> [u64; 2] {
+        [vector as u64, (vector >> 64) as u64]
+    }
+
+    #[inline]
+    unsafe fn extract_poly64s(&self, vector: Self::Vector) -> [u64; 2] {
+        self.extract_u64s(vector)
+    }
+
+    #[inline]
+    unsafe fn xor_vectors(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        a ^ b
+    }
+
+    #[inline]
+    unsafe fn load_bytes(&self, ptr: *const u8) -> Self::Vector {
+        u128::from_le_bytes(core::ptr::read_unaligned(ptr as *const [u8; 16]))
+    }
+
+    #[inline]
+    unsafe fn load_aligned(&self, ptr: *const [u64; 2]) -> Self::Vector {
+        let [low, high] = core::ptr::read_unaligned(ptr);
+
+        from_u64s(high, low)
+    }
+
+    #[inline]
+    unsafe fn shuffle_bytes(&self, data: Self::Vector, mask: Self::Vector) -> Self::Vector {
+        // PSHUFB semantics: a set high bit in the mask byte zeroes the output byte
+        let data = data.to_le_bytes();
+        let mask = mask.to_le_bytes();
+
+        u128::from_le_bytes(core::array::from_fn(|i| {
+            if mask[i] & 0x80 != 0 {
+                0
+            } else {
+                data[(mask[i] & 0x0f) as usize]
+            }
+        }))
+    }
+
+    #[inline]
+    unsafe fn blend_vectors(
+        &self,
+        a: Self::Vector,
+        b: Self::Vector,
+        mask: Self::Vector,
+    ) -> Self::Vector {
+        // Expand the high bit of each mask byte to the whole byte, then select
+        let select = ((mask >> 7) & u128::from_le_bytes([1; 16])) * 0xff;
+
+        (a & !select) | (b & select)
+    }
+
+    #[inline]
+    unsafe fn shift_left_8(&self, vector: Self::Vector) -> Self::Vector {
+        vector << 64
+    }
+
+    #[inline]
+    unsafe fn set_all_bytes(&self, value: u8) -> Self::Vector {
+        u128::from_le_bytes([value; 16])
+    }
+
+    #[inline(always)]
+    unsafe fn create_compare_mask(&self, vector: Self::Vector) -> Self::Vector {
+        // blend_vectors uses the high bit of each byte, like x86
+        vector
+    }
+
+    #[inline]
+    unsafe fn and_vectors(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        a & b
+    }
+
+    #[inline]
+    unsafe fn shift_right_32(&self, vector: Self::Vector) -> Self::Vector {
+        vector >> 32
+    }
+
+    #[inline]
+    unsafe fn shift_left_32(&self, vector: Self::Vector) -> Self::Vector {
+        vector << 32
+    }
+
+    #[inline]
+    unsafe fn create_vector_from_u32(&self, value: u32, high: bool) -> Self::Vector {
+        if high {
+            (value as u128) << 96
+        } else {
+            value as u128
+        }
+    }
+
+    #[inline]
+    unsafe fn shift_left_4(&self, vector: Self::Vector) -> Self::Vector {
+        vector << 32
+    }
+
+    #[inline]
+    unsafe fn shift_right_4(&self, vector: Self::Vector) -> Self::Vector {
+        vector >> 32
+    }
+
+    #[inline]
+    unsafe fn shift_right_8(&self, vector: Self::Vector) -> Self::Vector {
+        vector >> 64
+    }
+
+    #[inline]
+    unsafe fn shift_right_5(&self, vector: Self::Vector) -> Self::Vector {
+        vector >> 40
+    }
+
+    #[inline]
+    unsafe fn shift_right_6(&self, vector: Self::Vector) -> Self::Vector {
+        vector >> 48
+    }
+
+    #[inline]
+    unsafe fn shift_right_7(&self, vector: Self::Vector) -> Self::Vector {
+        vector >> 56
+    }
+
+    #[inline]
+    unsafe fn shift_right_12(&self, vector: Self::Vector) -> Self::Vector {
+        vector >> 96
+    }
+
+    #[inline]
+    unsafe fn shift_left_12(&self, vector: Self::Vector) -> Self::Vector {
+        vector << 96
+    }
+
+    #[inline]
+    unsafe fn carryless_mul_00(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        clmul64(a as u64, b as u64)
+    }
+
+    #[inline]
+    unsafe fn carryless_mul_01(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        // Same operand selection as PCLMULQDQ with an immediate of 0x01
+        clmul64((a >> 64) as u64, b as u64)
+    }
+
+    #[inline]
+    unsafe fn carryless_mul_10(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        // Same operand selection as PCLMULQDQ with an immediate of 0x10
+        clmul64(a as u64, (b >> 64) as u64)
+    }
+
+    #[inline]
+    unsafe fn carryless_mul_11(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        clmul64((a >> 64) as u64, (b >> 64) as u64)
+    }
+
+    #[inline]
+    unsafe fn xor3_vectors(
+        &self,
+        a: Self::Vector,
+        b: Self::Vector,
+        c: Self::Vector,
+    ) -> Self::Vector {
+        a ^ b ^ c
+    }
+}
+
+#[inline(always)]
+fn from_u64s(high: u64, low: u64) -> u128 {
+    ((high as u128) << 64) | low as u128
+}
+
+/// Carry-less multiplies two 64-bit values into a 128-bit product
+///
+/// # Safety
+/// Requires the Zbc extension
+#[inline(always)]
+unsafe fn clmul64(a: u64, b: u64) -> u128 {
+    let low: u64;
+    let high: u64;
+
+    // The extension is enabled for just these instructions, since it's detected at runtime
+    asm!(
+        ".option push",
+        ".option arch, +zbc",
+        "clmul {low}, {a}, {b}",
+        "clmulh {high}, {a}, {b}",
+        ".option pop",
+        a = in(reg) a,
+        b = in(reg) b,
+        low = out(reg) low,
+        high = lateout(reg) high,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+
+    from_u64s(high, low)
+}
@@ -0,0 +1,364 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides RISC-V 64-bit implementations of the ArchOps trait for CPUs with the
+//! vector (V) and Zvbc (vector carry-less multiplication) extensions.
+//!
+//! It folds all eight 128-bit lanes of each 128-byte block at once, holding the low and high
+//! halves of the lanes in separate LMUL=4 register groups of eight 64-bit elements, which fits
+//! the 128-bit minimum VLEN of the V extension.
+
+#![cfg(target_arch = "riscv64")]
+
+use crate::arch::riscv64::zbc::Riscv64ZbcOps;
+use crate::enums::Reflector;
+use crate::structs::CrcState;
+use crate::traits::{ArchOps, EnhancedCrcWidth};
+use core::arch::asm;
+
+/// Byte indices which reverse each 64-bit element of a 64-byte register group
+static BYTE_SWAP_64_INDICES: [u8; 64] = {
+    let mut indices = [0u8; 64];
+    let mut i = 0;
+    while i < 64 {
+        indices[i] = ((i & !7) | (7 - (i & 7))) as u8;
+        i += 1;
+    }
+    indices
+};
+
+/// Implements the ArchOps trait using the V and Zvbc vector instructions.
+/// Delegates to Riscv64ZbcOps for standard 128-bit operations
+#[derive(Debug, Copy, Clone)]
+pub struct Riscv64ZvbcOps(Riscv64ZbcOps);
+
+impl Default for Riscv64ZvbcOps {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Riscv64ZvbcOps {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self(Riscv64ZbcOps)
+    }
+
+    /// Fold each 128-byte block of `rest` into the eight 128-bit lanes of `x`, multiplying the
+    /// low half of each lane by `k_low` and the high half by `k_high`
+    ///
+    /// # Safety
+    /// Requires the V and Zvbc extensions, and `rest` must not be empty
+    #[inline]
+    unsafe fn fold_blocks(
+        &self,
+        x: &mut [u128; 8],
+        rest: &[[u128; 8]],
+        k_low: u64,
+        k_high: u64,
+        reflected: bool,
+    ) {
+        // v8/v12 hold the low/high halves of x, and v16/v20 those of each block. Forward CRCs
+        // reverse the bytes of each 16-byte lane, which swaps its halves and reverses each one.
+        asm!(
+            ".option push",
+            ".option arch, +v, +zvbc",
+            "vsetvli zero, {bytes}, e8, m4, ta, ma",
+            "vle8.v v4, ({indices})",
+            "vsetivli zero, 8, e64, m4, ta, ma",
+            "vlseg2e64.v v8, ({x})",
+            "2:",
+            "vlseg2e64.v v16, ({data})",
+            "beqz {forward}, 3f",
+            "vsetvli zero, {bytes}, e8, m4, ta, ma",
+            "vrgather.vv v24, v20, v4",
+            "vrgather.vv v28, v16, v4",
+            "vsetivli zero, 8, e64, m4, ta, ma",
+            "vmv4r.v v16, v24",
+            "vmv4r.v v20, v28",
+            "3:",
+            "vclmul.vx v24, v8, {k_low}",
+            "vclmulh.vx v28, v8, {k_low}",
+            "vxor.vv v16, v16, v24",
+            "vxor.vv v20, v20, v28",
+            "vclmul.vx v24, v12, {k_high}",
+            "vclmulh.vx v28, v12, {k_high}",
+            "vxor.vv v8, v16, v24",
+            "vxor.vv v12, v20, v28",
+            "addi {data}, {data}, 128",
+            "addi {blocks}, {blocks}, -1",
+            "bnez {blocks}, 2b",
+            "vsseg2e64.v v8, ({x})",
+            ".option pop",
+            x = in(reg) x.as_mut_ptr(),
+            data = inout(reg) rest.as_ptr() => _,
+            blocks = inout(reg) rest.len() => _,
+            indices = in(reg) BYTE_SWAP_64_INDICES.as_ptr(),
+            bytes = in(reg) BYTE_SWAP_64_INDICES.len(),
+            forward = in(reg) !reflected as usize,
+            k_low = in(reg) k_low,
+            k_high = in(reg) k_high,
+            out("v4") _, out("v5") _, out("v6") _, out("v7") _,
+            out("v8") _, out("v9") _, out("v10") _, out("v11") _,
+            out("v12") _, out("v13") _, out("v14") _, out("v15") _,
+            out("v16") _, out("v17") _, out("v18") _, out("v19") _,
+            out("v20") _, out("v21") _, out("v22") _, out("v23") _,
+            out("v24") _, out("v25") _, out("v26") _, out("v27") _,
+            out("v28") _, out("v29") _, out("v30") _, out("v31") _,
+            options(nostack),
+        );
+    }
+
+    /// Process aligned blocks using Zvbc, returning the reduced CRC value
+    #[inline]
+    unsafe fn process_blocks<W: EnhancedCrcWidth>(
+        &self,
+        state: &CrcState<u128>,
+        first: &[u128; 8],
+        rest: &[[u128; 8]],
+        reflector: &Reflector<u128>,
+        keys: &[u64; 23],
+    ) -> W::Value {
+        let reflected = state.reflected;
+
+        let mut x = first.map(|chunk| match reflector {
+            Reflector::NoReflector => chunk,
+            Reflector::ForwardReflector { smask } => self.shuffle_bytes(chunk, *smask),
+        });
+
+        x[0] ^= state.value;
+
+        // Same multipliers as the 128-byte coefficient on every other tier
+        let (k_low, k_high) = if reflected {
+            (keys[4], keys[3])
+        } else {
+            (keys[3], keys[4])
+        };
+
+        self.fold_blocks(&mut x, rest, k_low, k_high, reflected);
+
+        // Fold the 8 lanes to 1 with the scalar Zbc operations
+        let fold_coefficients = [
+            W::create_coefficient(keys[10], keys[9], reflected, self), // 112 bytes
+            W::create_coefficient(keys[12], keys[11], reflected, self), // 96 bytes
+            W::create_coefficient(keys[14], keys[13], reflected, self), // 80 bytes
+            W::create_coefficient(keys[16], keys[15], reflected, self), // 64 bytes
+            W::create_coefficient(keys[18], keys[17], reflected, self), // 48 bytes
+            W::create_coefficient(keys[20], keys[19], reflected, self), // 32 bytes
+            W::create_coefficient(keys[2], keys[1], reflected, self),  // 16 bytes
+        ];
+
+        let mut res = x[7];
+
+        for (i, &coeff) in fold_coefficients.iter().enumerate() {
+            let mut temp_state = CrcState {
+                value: x[i],
+                reflected,
+            };
+            W::fold_16(&mut temp_state, coeff, res, self);
+
+            res = temp_state.value
+        }
+
+        W::perform_final_reduction(res, reflected, keys, self)
+    }
+}
+
+// Delegate all ArchOps methods to the inner Riscv64ZbcOps instance
+impl ArchOps for Riscv64ZvbcOps {
+    type Vector = u128;
+
+    #[inline(always)]
+    unsafe fn process_enhanced_simd_blocks<W: EnhancedCrcWidth>(
+        &self,
+        state: &mut CrcState<Self::Vector>,
+        first: &[Self::Vector; 8],
+        rest: &[[Self::Vector; 8]],
+        reflector: &Reflector<Self::Vector>,
+        keys: &[u64; 23],
+    ) -> bool
+    where
+        Self::Vector: Copy,
+    {
+        if rest.is_empty() {
+            return false;
+        }
+
+        *state = W::create_state(
+            self.process_blocks::<W>(state, first, rest, reflector, keys),
+            state.reflected,
+            self,
+        );
+
+        true
+    }
+
+    // Delegate all other methods to Riscv64ZbcOps
+    #[inline(always)]
+    unsafe fn create_vector_from_u64_pair(
+        &self,
+        high: u64,
+        low: u64,
+        reflected: bool,
+    ) -> Self::Vector {
+        self.0.create_vector_from_u64_pair(high, low, reflected)
+    }
+
+    #[inline(always)]
+    unsafe fn create_vector_from_u64_pair_non_reflected(
+        &self,
+        high: u64,
+        low: u64,
+    ) -> Self::Vector {
+        self.0.create_vector_from_u64_pair_non_reflected(high, low)
+    }
+
+    #[inline(always)]
+    unsafe fn create_vector_from_u64(&self, value: u64, high: bool) -> Self::Vector {
+        self.0.create_vector_from_u64(value, high)
+    }
+
+    #[inline(always)]
+    unsafe fn extract_u64s(&self, vector: Self::Vector) -> [u64; 2] {
+        self.0.extract_u64s(vector)
+    }
+
+    #[inline(always)]
+    unsafe fn extract_poly64s(&self, vector: Self::Vector) -> [u64; 2] {
+        self.0.extract_poly64s(vector)
+    }
+
+    #[inline(always)]
+    unsafe fn xor_vectors(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        self.0.xor_vectors(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn load_bytes(&self, ptr: *const u8) -> Self::Vector {
+        self.0.load_bytes(ptr)
+    }
+
+    #[inline(always)]
+    unsafe fn load_aligned(&self, ptr: *const [u64; 2]) -> Self::Vector {
+        self.0.load_aligned(ptr)
+    }
+
+    #[inline(always)]
+    unsafe fn shuffle_bytes(&self, data: Self::Vector, mask: Self::Vector) -> Self::Vector {
+        self.0.shuffle_bytes(data, mask)
+    }
+
+    #[inline(always)]
+    unsafe fn blend_vectors(
+        &self,
+        a: Self::Vector,
+        b: Self::Vector,
+        mask: Self::Vector,
+    ) -> Self::Vector {
+        self.0.blend_vectors(a, b, mask)
+    }
+
+    #[inline(always)]
+    unsafe fn shift_left_8(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_left_8(vector)
+    }
+
+    #[inline(always)]
+    unsafe fn set_all_bytes(&self, value: u8) -> Self::Vector {
+        self.0.set_all_bytes(value)
+    }
+
+    #[inline(always)]
+    unsafe fn create_compare_mask(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.create_compare_mask(vector)
+    }
+
+    #[inline(always)]
+    unsafe fn and_vectors(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        self.0.and_vectors(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn shift_right_32(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_right_32(vector)
+    }
+
+    #[inline(always)]
+    unsafe fn shift_left_32(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_left_32(vector)
+    }
+
+    #[inline(always)]
+    unsafe fn create_vector_from_u32(&self, value: u32, high: bool) -> Self::Vector {
+        self.0.create_vector_from_u32(value, high)
+    }
+
+    #[inline(always)]
+    unsafe fn shift_left_4(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_left_4(vector)
+    }
+
+    #[inline(always)]
+    unsafe fn shift_right_4(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_right_4(vector)
+    }
+
+    #[inline(always)]
+    unsafe fn shift_right_8(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_right_8(vector)
+    }
+
+    #[inline(always)]
+    unsafe fn shift_right_5(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_right_5(vector)
+    }
+
+    #[inline(always)]
+    unsafe fn shift_right_6(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_right_6(vector)
+    }
+
+    #[inline(always)]
+    unsafe fn shift_right_7(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_right_7(vector)
+    }
+
+    #[inline(always)]
+    unsafe fn shift_right_12(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_right_12(vector)
+    }
+
+    #[inline(always)]
+    unsafe fn shift_left_12(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_left_12(vector)
+    }
+
+    #[inline(always)]
+    unsafe fn carryless_mul_00(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        self.0.carryless_mul_00(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn carryless_mul_01(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        self.0.carryless_mul_01(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn carryless_mul_10(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        self.0.carryless_mul_10(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn carryless_mul_11(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        self.0.carryless_mul_11(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn xor3_vectors(
+        &self,
+        a: Self::Vector,
+        b: Self::Vector,
+        c: Self::Vector,
+    ) -> Self::Vector {
+        self.0.xor3_vectors(a, b, c)
+    }
+}
@@ -0,0 +1,493 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides dedicated strategies for short inputs.
+//!
+//! Below [SMALL_INPUT_THRESHOLD] bytes the SIMD engine spends more time loading keys and
+//! shuffle masks than it does folding, so predefined algorithms are instead computed up to 16
+//! bytes at a time using their static lookup tables. Custom parameters have no static tables, and
+//! builds which shrink the tables with a `table-*` feature have too few lanes, so the accelerated
+//! tiers handle both with [update_with_keys], a Barrett reduction built from the resolved keys,
+//! below [keys_threshold] bytes.
+
+use crate::arch::software::{crc16_table, crc32_table, crc64_table, TableWord};
+use crate::CrcParams;
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
+use crate::traits::ArchOps;
+
+/// Inputs shorter than this are handled by the table-driven small-input path.
+pub(crate) const SMALL_INPUT_THRESHOLD: usize = 64;
+
+/// Inputs shorter than this skip the SIMD engine for [update_with_keys] on the accelerated tiers.
+///
+/// CRC-16 and CRC-32 fold each word once more before reducing it, so the engine catches up with
+/// them sooner than with CRC-64.
+#[inline(always)]
+pub(crate) const fn keys_threshold(width: u8) -> usize {
+    match width {
+        64 => SMALL_INPUT_THRESHOLD,
+        _ => 48,
+    }
+}
+
+/// Updates the CRC state for a short input, or returns `None` if the input is too long or
+/// `params` has no static 16-lane tables.
+///
+/// This looks up the table on every call, so [crate::Calculator] resolves a [SmallTable] once
+/// instead. The state uses the same representation as [crate::arch::software::update], so the
+/// result is interchangeable with the SIMD and software paths.
+#[inline(always)]
+pub(crate) fn update(state: u64, bytes: &[u8], params: &CrcParams) -> Option<u64> {
+    if bytes.len() >= SMALL_INPUT_THRESHOLD {
+        return None;
+    }
+
+    SmallTable::resolve(params).map(|table| table.update(state, bytes))
+}
+
+/// The static 16-lane table and input reflection of a predefined algorithm, for short inputs.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum SmallTable {
+    Crc16(&'static [[u16; 256]; 16], bool),
+    Crc32(&'static [[u32; 256]; 16], bool),
+    Crc64(&'static [[u64; 256]; 16], bool),
+}
+
+impl SmallTable {
+    /// Looks up the table for `params`, or returns `None` if it has no static 16-lane tables.
+    pub(crate) fn resolve(params: &CrcParams) -> Option<Self> {
+        match params.width {
+            16 => crc16_table(params.algorithm)
+                .and_then(|(table, refin)| Some(Self::Crc16(table.lanes16()?, refin))),
+            32 => crc32_table(params.algorithm)
+                .and_then(|(table, refin)| Some(Self::Crc32(table.lanes16()?, refin))),
+            64 => crc64_table(params.algorithm)
+                .and_then(|(table, refin)| Some(Self::Crc64(table.lanes16()?, refin))),
+            _ => None,
+        }
+    }
+
+    /// Updates the CRC state for an input shorter than [SMALL_INPUT_THRESHOLD].
+    #[inline(always)]
+    pub(crate) fn update(self, state: u64, bytes: &[u8]) -> u64 {
+        match self {
+            Self::Crc16(table, refin) => dispatch(state, table, refin, bytes),
+            Self::Crc32(table, refin) => dispatch(state, table, refin, bytes),
+            Self::Crc64(table, refin) => dispatch(state, table, refin, bytes),
+        }
+    }
+}
+
+#[inline(always)]
+fn dispatch<T: TableWord>(state: u64, table: &[[T; 256]; 16], refin: bool, bytes: &[u8]) -> u64 {
+    if refin {
+        update_by_words::<T, true>(state, table, bytes)
+    } else {
+        update_by_words::<T, false>(state, table, bytes)
+    }
+}
+
+/// Processes 16, 8 and then 4 bytes per step, finishing any remainder one byte at a time.
+///
+/// Lane `n` of the table holds the CRC of a byte followed by `n` zero bytes, so each word (with
+/// the state folded into its leading bytes) reduces to independent lookups.
+#[inline(always)]
+fn update_by_words<T: TableWord, const REFLECTED: bool>(
+    state: u64,
+    table: &[[T; 256]; 16],
+    bytes: &[u8],
+) -> u64 {
+    let mut crc = state;
+    let mut chunks = bytes.chunks_exact(16);
+
+    for chunk in &mut chunks {
+        let (first, second) = chunk.split_at(8);
+
+        crc = (fold_word::<T, REFLECTED>(load::<T, REFLECTED>(first, crc), table, 8)
+            ^ fold_word::<T, REFLECTED>(load::<T, REFLECTED>(second, 0), table, 0))
+        .widen();
+    }
+
+    let mut chunks = chunks.remainder().chunks_exact(8);
+
+    for chunk in &mut chunks {
+        crc = fold_word::<T, REFLECTED>(load::<T, REFLECTED>(chunk, crc), table, 0).widen();
+    }
+
+    let mut chunks = chunks.remainder().chunks_exact(4);
+
+    for chunk in &mut chunks {
+        crc = update_word4::<T, REFLECTED>(crc, table, chunk);
+    }
+
+    for &byte in chunks.remainder() {
+        crc = if REFLECTED {
+            table[0][((crc ^ byte as u64) & 0xFF) as usize].widen() ^ (crc >> 8)
+        } else {
+            let top = (crc >> (T::BITS - 8)) ^ byte as u64;
+            let shifted = (crc << 8) & (u64::MAX >> (64 - T::BITS));
+            table[0][(top & 0xFF) as usize].widen() ^ shifted
+        };
+    }
+
+    crc
+}
+
+/// Processes a 4-byte word, carrying over any state bytes which extend past it.
+#[inline(always)]
+fn update_word4<T: TableWord, const REFLECTED: bool>(
+    crc: u64,
+    table: &[[T; 256]; 16],
+    chunk: &[u8],
+) -> u64 {
+    let chunk: [u8; 4] = chunk.try_into().unwrap();
+
+    if REFLECTED {
+        let word = u32::from_le_bytes(chunk) as u64 ^ crc;
+        let folded = table[3][(word & 0xFF) as usize]
+            ^ table[2][((word >> 8) & 0xFF) as usize]
+            ^ table[1][((word >> 16) & 0xFF) as usize]
+            ^ table[0][((word >> 24) & 0xFF) as usize];
+
+        // only CRC-64 has state bytes beyond the word
+        folded.widen() ^ (crc >> 32)
+    } else {
+        let aligned = crc << (64 - T::BITS);
+        let word = (u32::from_be_bytes(chunk) as u64) ^ (aligned >> 32);
+        let folded = table[3][((word >> 24) & 0xFF) as usize]
+            ^ table[2][((word >> 16) & 0xFF) as usize]
+            ^ table[1][((word >> 8) & 0xFF) as usize]
+            ^ table[0][(word & 0xFF) as usize];
+
+        // only CRC-64 has state bytes beyond the word, which move to the top
+        let carried = (aligned << 32) >> (64 - T::BITS);
+        folded.widen() ^ carried
+    }
+}
+
+/// Loads 8 bytes as a word in stream order with the state placed over its leading bytes.
+#[inline(always)]
+fn load<T: TableWord, const REFLECTED: bool>(chunk: &[u8], crc: u64) -> u64 {
+    let chunk: [u8; 8] = chunk.try_into().unwrap();
+
+    if REFLECTED {
+        u64::from_le_bytes(chunk) ^ crc
+    } else {
+        u64::from_be_bytes(chunk) ^ (crc << (64 - T::BITS))
+    }
+}
+
+/// Looks up each byte of `word` in the lane matching the number of bytes which follow it.
+#[inline(always)]
+fn fold_word<T: TableWord, const REFLECTED: bool>(
+    word: u64,
+    table: &[[T; 256]; 16],
+    trailing: usize,
+) -> T {
+    let byte = |i: u32| {
+        let shift = if REFLECTED { 8 * i } else { 56 - 8 * i };
+        ((word >> shift) & 0xFF) as usize
+    };
+
+    table[trailing + 7][byte(0)]
+        ^ table[trailing + 6][byte(1)]
+        ^ table[trailing + 5][byte(2)]
+        ^ table[trailing + 4][byte(3)]
+        ^ table[trailing + 3][byte(4)]
+        ^ table[trailing + 2][byte(5)]
+        ^ table[trailing + 1][byte(6)]
+        ^ table[trailing][byte(7)]
+}
+
+/// Updates the CRC state for a short input 8 bytes at a time with Barrett reductions, using the
+/// constants among the resolved keys, so no lookup table is needed.
+///
+/// CRC-64 reduces each word directly. CRC-16 and CRC-32, whose keys are scaled into 32-bit space
+/// the way the SIMD engine handles CRC-16, first fold the word's leading half with x^64 mod P and
+/// then reduce the remaining 32 bits.
+///
+/// # Safety
+/// May use native CPU features
+#[inline(always)]
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
+pub(crate) unsafe fn update_with_keys<T: ArchOps, const WIDTH: u8>(
+    state: u64,
+    bytes: &[u8],
+    params: &CrcParams,
+    ops: &T,
+) -> u64 {
+    const LOW: u64 = 0xFFFF_FFFF;
+
+    let fold = params.get_key(6);
+    let mu = params.get_key(7);
+    let poly = params.get_key(8);
+
+    match (WIDTH, params.refin) {
+        (64, true) => reduce_reflected(state, bytes, |word| {
+            let q = clmul(word, mu, ops)[0];
+            clmul(q, poly, ops)[1] ^ q
+        }),
+        (64, false) => reduce_forward(state, bytes, |word| {
+            let q = clmul(word, mu, ops)[1] ^ word;
+            clmul(q, poly, ops)[0]
+        }),
+        (_, true) => reduce_reflected(state, bytes, |word| {
+            let folded = clmul(word & LOW, fold, ops)[0] ^ (word >> 32);
+            let q = clmul(folded & LOW, mu, ops)[0] & LOW;
+            (clmul(q, poly, ops)[0] >> 32) ^ (folded >> 32)
+        }),
+        (_, false) => {
+            // the state sits at the top of the word, as CRC-16 does in 32-bit space
+            let shift = 64 - WIDTH as u32;
+            let fold = fold >> 32;
+            let state = reduce_forward(state << shift, bytes, |word| {
+                let folded = clmul(word >> 32, fold, ops)[0] ^ (word << 32);
+                let q = clmul(folded >> 32, mu, ops)[0] >> 32;
+                ((clmul(q, poly, ops)[0] & LOW) ^ (folded & LOW)) << 32
+            });
+
+            state >> shift
+        }
+    }
+}
+
+/// Feeds 8-byte words, then any remainder as one shorter word, through `reduce`, which multiplies
+/// a reflected word by x^width modulo the polynomial.
+#[inline(always)]
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
+fn reduce_reflected(state: u64, bytes: &[u8], reduce: impl Fn(u64) -> u64) -> u64 {
+    let mut crc = state;
+    let mut chunks = bytes.chunks_exact(8);
+
+    for chunk in &mut chunks {
+        crc = reduce(crc ^ load_le(chunk));
+    }
+
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let bits = 8 * tail.len() as u32;
+        let word = (crc ^ load_le(tail)) & ((1 << bits) - 1);
+
+        // the leading bytes are the highest powers, which belong at the top of a full word
+        crc = (crc >> bits) ^ reduce(word << (64 - bits));
+    }
+
+    crc
+}
+
+/// Feeds 8-byte words, then any remainder as one shorter word, through `reduce`, which multiplies
+/// a non-reflected word by x^width modulo the polynomial. The state and the result of `reduce`
+/// sit at the top of the word.
+#[inline(always)]
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
+fn reduce_forward(state: u64, bytes: &[u8], reduce: impl Fn(u64) -> u64) -> u64 {
+    let mut crc = state;
+    let mut chunks = bytes.chunks_exact(8);
+
+    for chunk in &mut chunks {
+        crc = reduce(crc ^ load_be(chunk));
+    }
+
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let bits = 8 * tail.len() as u32;
+
+        crc = (crc << bits) ^ reduce((crc >> (64 - bits)) ^ load_be(tail));
+    }
+
+    crc
+}
+
+/// Loads up to 8 bytes as a little-endian word.
+#[inline(always)]
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
+fn load_le(bytes: &[u8]) -> u64 {
+    let mut word = [0; 8];
+    word[..bytes.len()].copy_from_slice(bytes);
+
+    u64::from_le_bytes(word)
+}
+
+/// Loads up to 8 bytes as a big-endian word, right-aligned.
+#[inline(always)]
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
+fn load_be(bytes: &[u8]) -> u64 {
+    let mut word = [0; 8];
+    word[8 - bytes.len()..].copy_from_slice(bytes);
+
+    u64::from_be_bytes(word)
+}
+
+/// Multiplies two 64-bit polynomials, returning the low and high halves of the product.
+#[inline(always)]
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
+unsafe fn clmul<T: ArchOps>(a: u64, b: u64, ops: &T) -> [u64; 2] {
+    ops.extract_u64s(ops.carryless_mul_00(
+        ops.create_vector_from_u64(a, false),
+        ops.create_vector_from_u64(b, false),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arch::software::TABLE_LANES;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+
+    #[test]
+    fn test_small_inputs_match_reference() {
+        // reduced tables have no small path, which is covered below
+        if TABLE_LANES < 16 {
+            return;
+        }
+
+        let data: Vec<u8> = (0..SMALL_INPUT_THRESHOLD)
+            .map(|i| (i * 37 + 11) as u8)
+            .collect();
+
+        for config in TEST_ALL_CONFIGS {
+            let params = config.get_params();
+
+            for len in 0..SMALL_INPUT_THRESHOLD {
+                let state = update(config.get_init_algorithm(), &data[..len], params)
+                    .expect("predefined algorithms have static tables");
+
+                assert_eq!(
+                    state ^ config.get_xorout(),
+                    config.checksum_with_reference(&data[..len]),
+                    "{} mismatch at {len} bytes",
+                    config.get_name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_small_inputs_continue_state() {
+        if TABLE_LANES < 16 {
+            return;
+        }
+
+        let data: Vec<u8> = (1..SMALL_INPUT_THRESHOLD).map(|i| (i * 13) as u8).collect();
+
+        for config in TEST_ALL_CONFIGS {
+            let params = config.get_params();
+
+            for split in 0..data.len() {
+                let (a, b) = data.split_at(split);
+                let state = update(config.get_init_algorithm(), a, params).unwrap();
+                let state = update(state, b, params).unwrap();
+
+                assert_eq!(
+                    state ^ config.get_xorout(),
+                    config.checksum_with_reference(&data)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_long_inputs_have_no_small_path() {
+        let data = [0u8; SMALL_INPUT_THRESHOLD];
+
+        for config in TEST_ALL_CONFIGS {
+            assert_eq!(update(0, &data, config.get_params()), None);
+        }
+    }
+
+    #[test]
+    fn test_reduced_tables_have_no_small_path() {
+        for config in TEST_ALL_CONFIGS {
+            assert_eq!(
+                update(0, b"123456789", config.get_params()).is_some(),
+                TABLE_LANES == 16
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "std"))]
+    #[cfg_attr(miri, ignore)]
+    fn test_keys_match_reference() {
+        if !is_x86_feature_detected!("sse4.1") || !is_x86_feature_detected!("pclmulqdq") {
+            return;
+        }
+
+        let data: Vec<u8> = (0..SMALL_INPUT_THRESHOLD)
+            .map(|i| (i * 37 + 11) as u8)
+            .collect();
+        let ops = crate::arch::x86::sse::X86SsePclmulqdqOps;
+
+        for config in TEST_ALL_CONFIGS {
+            let params = config.get_params();
+            let keys = match params.width {
+                64 => update_with_keys::<_, 64>,
+                32 => update_with_keys::<_, 32>,
+                _ => update_with_keys::<_, 16>,
+            };
+
+            // beyond the thresholds, to cover each tail length after every number of whole words
+            for len in 0..SMALL_INPUT_THRESHOLD {
+                let state =
+                    unsafe { keys(config.get_init_algorithm(), &data[..len], params, &ops) };
+
+                assert_eq!(
+                    state ^ config.get_xorout(),
+                    config.checksum_with_reference(&data[..len]),
+                    "{} mismatch at {len} bytes",
+                    config.get_name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_params_have_no_small_path() {
+        let params = CrcParams::new(
+            "CRC-32/CUSTOM",
+            32,
+            0x1edc6f41,
+            0xffffffff,
+            true,
+            0xffffffff,
+            0,
+        );
+
+        assert_eq!(update(0, b"123456789", &params), None);
+    }
+}
@@ -0,0 +1,614 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Async I/O integration for the `tokio` and `futures-io` ecosystems.
+//!
+//! The reader and writer adapters are thin pass-through wrappers which feed every byte that
+//! crosses them into a [`Digest`], so a checksum falls out of an existing async pipeline without
+//! buffering. Both adapters implement the `tokio` and/or `futures-io` traits depending on which
+//! features are enabled.
+//!
+//! [`checksum_file_async`] needs a blocking pool, so it's only available with the `tokio`
+//! feature.
+
+use crate::Digest;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+#[cfg(feature = "tokio")]
+use crate::{CrcAlgorithm, CrcParams, DEFAULT_FILE_CHUNK_SIZE};
+#[cfg(feature = "tokio")]
+use std::fs::File;
+#[cfg(feature = "tokio")]
+use std::path::Path;
+#[cfg(feature = "tokio")]
+use std::sync::Arc;
+
+/// Wraps an async reader, updating a [`Digest`] with every byte read through it.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "tokio")]
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use crc_fast::{AsyncChecksumReader, Digest, CrcAlgorithm::Crc32IsoHdlc};
+/// use tokio::io::AsyncReadExt;
+///
+/// let mut reader = AsyncChecksumReader::new(&b"123456789"[..], Digest::new(Crc32IsoHdlc));
+///
+/// let mut contents = Vec::new();
+/// reader.read_to_end(&mut contents).await.unwrap();
+///
+/// assert_eq!(reader.finalize(), 0xcbf43926);
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct AsyncChecksumReader<R> {
+    inner: R,
+    digest: Digest,
+}
+
+impl<R> AsyncChecksumReader<R> {
+    /// Creates a new reader which checksums everything read from `inner` using `digest`.
+    #[inline]
+    pub fn new(inner: R, digest: Digest) -> Self {
+        Self { inner, digest }
+    }
+
+    /// Gets a reference to the [`Digest`] accumulating the data read so far.
+    #[inline]
+    pub fn digest(&self) -> &Digest {
+        &self.digest
+    }
+
+    /// Finalizes the CRC computation for the data read so far and returns the result.
+    #[inline]
+    pub fn finalize(&self) -> u64 {
+        self.digest.finalize()
+    }
+
+    /// Gets a reference to the underlying reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Reading directly from the underlying reader bypasses the [`Digest`].
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Consumes the adapter, returning the underlying reader and the [`Digest`].
+    #[inline]
+    pub fn into_parts(self) -> (R, Digest) {
+        (self.inner, self.digest)
+    }
+}
+
+/// Wraps an async writer, updating a [`Digest`] with every byte the inner writer accepts.
+///
+/// Only the bytes reported as written by the inner writer are checksummed, so partial writes are
+/// accounted for correctly.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "tokio")]
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use crc_fast::{AsyncChecksumWriter, Digest, CrcAlgorithm::Crc32IsoHdlc};
+/// use tokio::io::AsyncWriteExt;
+///
+/// let mut writer = AsyncChecksumWriter::new(Vec::new(), Digest::new(Crc32IsoHdlc));
+/// writer.write_all(b"123456789").await.unwrap();
+///
+/// assert_eq!(writer.finalize(), 0xcbf43926);
+/// assert_eq!(writer.get_ref(), b"123456789");
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct AsyncChecksumWriter<W> {
+    inner: W,
+    digest: Digest,
+}
+
+impl<W> AsyncChecksumWriter<W> {
+    /// Creates a new writer which checksums everything written to `inner` using `digest`.
+    #[inline]
+    pub fn new(inner: W, digest: Digest) -> Self {
+        Self { inner, digest }
+    }
+
+    /// Gets a reference to the [`Digest`] accumulating the data written so far.
+    #[inline]
+    pub fn digest(&self) -> &Digest {
+        &self.digest
+    }
+
+    /// Finalizes the CRC computation for the data written so far and returns the result.
+    #[inline]
+    pub fn finalize(&self) -> u64 {
+        self.digest.finalize()
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Writing directly to the underlying writer bypasses the [`Digest`].
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Consumes the adapter, returning the underlying writer and the [`Digest`].
+    #[inline]
+    pub fn into_parts(self) -> (W, Digest) {
+        (self.inner, self.digest)
+    }
+}
+
+/// Records the bytes a completed write accepted, passing the poll result through untouched.
+#[inline(always)]
+fn checksum_written(
+    digest: &mut Digest,
+    buf: &[u8],
+    poll: Poll<io::Result<usize>>,
+) -> Poll<io::Result<usize>> {
+    if let Poll::Ready(Ok(n)) = poll {
+        digest.update(&buf[..n]);
+    }
+
+    poll
+}
+
+#[cfg(feature = "tokio")]
+impl<R: tokio::io::AsyncRead + Unpin> tokio::io::AsyncRead for AsyncChecksumReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let filled = buf.filled().len();
+
+        let poll = Pin::new(&mut this.inner).poll_read(cx, buf);
+        if let Poll::Ready(Ok(())) = poll {
+            this.digest.update(&buf.filled()[filled..]);
+        }
+
+        poll
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<W: tokio::io::AsyncWrite + Unpin> tokio::io::AsyncWrite for AsyncChecksumWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let poll = Pin::new(&mut this.inner).poll_write(cx, buf);
+
+        checksum_written(&mut this.digest, buf, poll)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
+/// Lets a bare [`Digest`] act as an async sink, e.g. the target of `tokio::io::copy`.
+#[cfg(feature = "tokio")]
+impl tokio::io::AsyncWrite for Digest {
+    #[inline]
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.get_mut().update(buf);
+
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    #[inline]
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    #[inline]
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "futures-io")]
+impl<R: futures_io::AsyncRead + Unpin> futures_io::AsyncRead for AsyncChecksumReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let poll = Pin::new(&mut this.inner).poll_read(cx, buf);
+
+        checksum_written(&mut this.digest, buf, poll)
+    }
+}
+
+#[cfg(feature = "futures-io")]
+impl<W: futures_io::AsyncWrite + Unpin> futures_io::AsyncWrite for AsyncChecksumWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let poll = Pin::new(&mut this.inner).poll_write(cx, buf);
+
+        checksum_written(&mut this.digest, buf, poll)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
+
+/// Lets a bare [`Digest`] act as an async sink, e.g. the target of `futures::io::copy`.
+#[cfg(feature = "futures-io")]
+impl futures_io::AsyncWrite for Digest {
+    #[inline]
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.get_mut().update(buf);
+
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    #[inline]
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    #[inline]
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Computes the CRC checksum for the given file using the specified algorithm, without blocking
+/// the async executor.
+///
+/// Each chunk is read with a positional read and checksummed on tokio's blocking pool, so both
+/// the I/O and the CRC work stay off the executor threads while the task still yields between
+/// chunks. Uses the same default chunk size as [`checksum_file`](crate::checksum_file).
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be opened or read, or if the blocking
+/// task panics or is cancelled.
+///
+/// # Examples
+///
+/// ```rust
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use std::env;
+/// use crc_fast::{checksum_file_async, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// // for example/test purposes only, use your own file path
+/// let file_on_disk = env::current_dir().expect("missing working dir").join("crc-check.txt");
+///
+/// let checksum = checksum_file_async(Crc32IsoHdlc, file_on_disk, None).await;
+///
+/// assert_eq!(checksum.unwrap(), 0xcbf43926);
+/// # });
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn checksum_file_async(
+    algorithm: CrcAlgorithm,
+    path: impl AsRef<Path>,
+    chunk_size: Option<usize>,
+) -> io::Result<u64> {
+    checksum_file_with_digest_async(Digest::new(algorithm), path, chunk_size).await
+}
+
+/// Computes the CRC checksum for the given file using custom CRC parameters, without blocking
+/// the async executor.
+///
+/// See [`checksum_file_async`] for how the file is read.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be opened or read, or if the blocking
+/// task panics or is cancelled.
+#[cfg(feature = "tokio")]
+pub async fn checksum_file_with_params_async(
+    params: CrcParams,
+    path: impl AsRef<Path>,
+    chunk_size: Option<usize>,
+) -> io::Result<u64> {
+    checksum_file_with_digest_async(Digest::new_with_params(params), path, chunk_size).await
+}
+
+#[cfg(feature = "tokio")]
+async fn checksum_file_with_digest_async(
+    mut digest: Digest,
+    path: impl AsRef<Path>,
+    chunk_size: Option<usize>,
+) -> io::Result<u64> {
+    let path = path.as_ref().to_owned();
+    let file = Arc::new(spawn_blocking(move || File::open(path)).await?);
+
+    // a zero-sized buffer would never make progress
+    let chunk_size = chunk_size.unwrap_or(DEFAULT_FILE_CHUNK_SIZE).max(1);
+
+    // the buffer and digest are moved into each blocking task and handed back afterward, so the
+    // allocation is reused across chunks
+    let mut buf = vec![0; chunk_size];
+    let mut offset = 0u64;
+
+    loop {
+        let file = Arc::clone(&file);
+        let (returned_buf, returned_digest, n) = spawn_blocking(move || {
+            let n = read_at_retrying(&file, &mut buf, offset)?;
+            digest.update(&buf[..n]);
+
+            Ok::<_, io::Error>((buf, digest, n))
+        })
+        .await?;
+
+        if n == 0 {
+            return Ok(returned_digest.finalize());
+        }
+
+        buf = returned_buf;
+        digest = returned_digest;
+        offset += n as u64;
+    }
+}
+
+/// Runs blocking work on tokio's blocking pool, flattening join errors into `io::Error`.
+#[cfg(feature = "tokio")]
+async fn spawn_blocking<T, F>(f: F) -> io::Result<T>
+where
+    F: FnOnce() -> io::Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(io::Error::other)?
+}
+
+/// Reads from an absolute offset, retrying reads that were interrupted by a signal.
+#[cfg(feature = "tokio")]
+fn read_at_retrying(file: &File, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+    loop {
+        match read_at(file, buf, offset) {
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            result => return result,
+        }
+    }
+}
+
+#[cfg(all(feature = "tokio", unix))]
+#[inline(always)]
+fn read_at(file: &File, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+    std::os::unix::fs::FileExt::read_at(file, buf, offset)
+}
+
+#[cfg(all(feature = "tokio", windows))]
+#[inline(always)]
+fn read_at(file: &File, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+    std::os::windows::fs::FileExt::seek_read(file, buf, offset)
+}
+
+// no positional read primitive elsewhere, but each read is issued from a single task at a time so
+// seeking the shared handle is safe
+#[cfg(all(feature = "tokio", not(any(unix, windows))))]
+fn read_at(mut file: &File, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    file.seek(SeekFrom::Start(offset))?;
+    file.read(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    #[cfg(feature = "tokio")]
+    use crate::CrcAlgorithm::Crc32IsoHdlc;
+
+    fn test_data() -> Vec<u8> {
+        (0..(1024 * 1024 + 7)).map(|i| (i % 251) as u8).collect()
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_tokio_reader() {
+        use tokio::io::AsyncReadExt;
+
+        let data = test_data();
+        for config in TEST_ALL_CONFIGS {
+            let mut reader =
+                AsyncChecksumReader::new(&data[..], Digest::new(config.get_algorithm()));
+            let mut out = Vec::new();
+            reader.read_to_end(&mut out).await.unwrap();
+
+            assert_eq!(out, data);
+            assert_eq!(reader.finalize(), config.checksum_with_reference(&data));
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_tokio_writer_and_copy() {
+        use tokio::io::AsyncWriteExt;
+
+        let data = test_data();
+        for config in TEST_ALL_CONFIGS {
+            let expected = config.checksum_with_reference(&data);
+
+            let mut writer =
+                AsyncChecksumWriter::new(Vec::new(), Digest::new(config.get_algorithm()));
+            writer.write_all(&data).await.unwrap();
+            writer.shutdown().await.unwrap();
+            assert_eq!(writer.finalize(), expected);
+
+            let (inner, digest) = writer.into_parts();
+            assert_eq!(inner, data);
+            assert_eq!(digest.get_amount(), data.len() as u64);
+
+            let mut digest = Digest::new(config.get_algorithm());
+            tokio::io::copy(&mut &data[..], &mut digest).await.unwrap();
+            assert_eq!(digest.finalize(), expected);
+        }
+    }
+
+    /// A writer which only accepts a few bytes per call, to ensure partial writes are only
+    /// checksummed once.
+    #[cfg(feature = "tokio")]
+    struct TrickleWriter(Vec<u8>);
+
+    #[cfg(feature = "tokio")]
+    impl tokio::io::AsyncWrite for TrickleWriter {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            let n = buf.len().min(3);
+            self.get_mut().0.extend_from_slice(&buf[..n]);
+
+            Poll::Ready(Ok(n))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_tokio_writer_partial_writes() {
+        use tokio::io::AsyncWriteExt;
+
+        let mut writer =
+            AsyncChecksumWriter::new(TrickleWriter(Vec::new()), Digest::new(Crc32IsoHdlc));
+        writer.write_all(b"123456789").await.unwrap();
+
+        assert_eq!(writer.get_ref().0, b"123456789");
+        assert_eq!(writer.finalize(), 0xcbf43926);
+    }
+
+    /// Skipping for Miri runs due to isolation constraints, underlying code other than I/O
+    /// already covered by other tests.
+    #[cfg(feature = "tokio")]
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg_attr(miri, ignore)]
+    async fn test_checksum_file_async() {
+        let data = test_data();
+        let path = std::env::temp_dir().join(format!(
+            "crc_fast_test_checksum_file_async_{}.bin",
+            std::process::id()
+        ));
+        std::fs::write(&path, &data).unwrap();
+
+        for config in TEST_ALL_CONFIGS {
+            let expected = config.checksum_with_reference(&data);
+
+            let result = checksum_file_async(config.get_algorithm(), &path, None).await;
+            assert_eq!(result.unwrap(), expected);
+
+            // odd chunk sizes exercise the offset bookkeeping across short final reads
+            let result = checksum_file_async(config.get_algorithm(), &path, Some(4093)).await;
+            assert_eq!(result.unwrap(), expected);
+        }
+
+        let params = crate::CrcParams::new(
+            "CRC-32/CUSTOM",
+            32,
+            0x04c11db7,
+            0xffffffff,
+            true,
+            0xffffffff,
+            0xcbf43926,
+        );
+        let result = checksum_file_with_params_async(params, &path, None).await;
+        assert_eq!(result.unwrap(), crate::checksum(Crc32IsoHdlc, &data));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_checksum_file_async_zero_chunk_size() {
+        let result = checksum_file_async(Crc32IsoHdlc, "crc-check.txt", Some(0)).await;
+
+        assert_eq!(result.unwrap(), 0xcbf43926);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_checksum_file_async_missing_file() {
+        let result = checksum_file_async(Crc32IsoHdlc, "does/not/exist.bin", None).await;
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+
+    #[cfg(feature = "futures-io")]
+    #[test]
+    fn test_futures_reader_and_writer() {
+        use futures::executor::block_on;
+        use futures::io::{AsyncReadExt, AsyncWriteExt, Cursor};
+
+        let data = test_data();
+        for config in TEST_ALL_CONFIGS {
+            let expected = config.checksum_with_reference(&data);
+
+            let mut reader = AsyncChecksumReader::new(
+                Cursor::new(data.clone()),
+                Digest::new(config.get_algorithm()),
+            );
+            let mut out = Vec::new();
+            block_on(reader.read_to_end(&mut out)).unwrap();
+            assert_eq!(out, data);
+            assert_eq!(reader.finalize(), expected);
+
+            let mut writer =
+                AsyncChecksumWriter::new(Vec::new(), Digest::new(config.get_algorithm()));
+            block_on(writer.write_all(&data)).unwrap();
+            block_on(writer.close()).unwrap();
+            assert_eq!(writer.finalize(), expected);
+
+            let mut digest = Digest::new(config.get_algorithm());
+            block_on(futures::io::copy(Cursor::new(&data), &mut digest)).unwrap();
+            assert_eq!(digest.finalize(), expected);
+        }
+    }
+}
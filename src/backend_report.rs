@@ -0,0 +1,223 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module explains which backend the dispatching APIs use, and why the others weren't chosen.
+//!
+//! The detected CPU capabilities select the most preferred backend this CPU supports, unless one
+//! is forced. Each remaining backend either lost out to it or is missing a feature, such as
+//! `avx512vl`, or the OS support for one, such as the ZMM register state.
+
+use crate::feature_detection::{
+    get_detected_capabilities, get_detected_tier, get_force_tier_rejection,
+};
+use crate::{active_backend, get_fusion, ArchCapabilities, CrcAlgorithm, PerformanceTier};
+use core::fmt::{Display, Formatter};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Whether a backend is used by the dispatching APIs, and if not, why not.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackendDecision {
+    pub backend: PerformanceTier,
+    /// Whether this CPU (and the current build) supports the backend
+    pub available: bool,
+    /// Why the backend isn't active, or `None` for the active backend
+    pub reason: Option<String>,
+}
+
+impl Display for BackendDecision {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match (&self.reason, self.available) {
+            (None, _) => write!(f, "{}: active", self.backend),
+            (Some(reason), true) => write!(f, "{}: available, {reason}", self.backend),
+            (Some(reason), false) => write!(f, "{}: unavailable, {reason}", self.backend),
+        }
+    }
+}
+
+/// The backend a predefined algorithm is calculated with by the dispatching APIs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlgorithmBackend {
+    pub algorithm: CrcAlgorithm,
+    pub backend: PerformanceTier,
+    /// Whether the CRC-32 fusion path, which mixes native CRC-32 instructions into the backend's
+    /// carry-less multiplication, is used instead
+    pub fusion: bool,
+}
+
+/// The results of [backend_report].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackendReport {
+    pub capabilities: ArchCapabilities,
+    /// The backend the capabilities select
+    pub detected: PerformanceTier,
+    /// The backend used by the dispatching APIs, which is `detected` unless forced
+    pub active: PerformanceTier,
+    /// Whether the active backend was forced with
+    /// [set_backend_override](crate::set_backend_override) or [FORCE_TIER_ENV](crate::FORCE_TIER_ENV)
+    pub forced: bool,
+    /// Why the backend named by [FORCE_TIER_ENV](crate::FORCE_TIER_ENV) was ignored, such as
+    /// `unknown backend "avx512"`, or `None` if it wasn't set or was used
+    pub force_rejected: Option<String>,
+    /// Every backend for this architecture, in order of preference
+    pub backends: Vec<BackendDecision>,
+    /// Every predefined algorithm in this build
+    pub algorithms: Vec<AlgorithmBackend>,
+}
+
+impl Display for BackendReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "using {} ({})",
+            self.active,
+            if self.forced { "forced" } else { "detected" }
+        )?;
+
+        if let Some(reason) = &self.force_rejected {
+            write!(f, "\n  {} ignored: {reason}", crate::FORCE_TIER_ENV)?;
+        }
+
+        write!(f, "\n  features:")?;
+        for (name, _) in self.capabilities.features().iter().filter(|f| f.1) {
+            write!(f, " {name}")?;
+        }
+
+        for decision in &self.backends {
+            write!(f, "\n  {decision}")?;
+        }
+
+        let fusion: Vec<String> = self
+            .algorithms
+            .iter()
+            .filter(|a| a.fusion)
+            .map(|a| a.algorithm.to_string())
+            .collect();
+
+        if !fusion.is_empty() {
+            write!(f, "\n  crc32-fusion: {}", fusion.join(" "))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Explains which backend (performance tier) the dispatching APIs use on this CPU, and why each
+/// of the others wasn't chosen, such as `avx512vl missing` or `OS has not enabled ZMM state`.
+///
+/// Suitable for logging at startup, or reporting as part of a host inventory.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{active_backend, backend_report};
+///
+/// let report = backend_report();
+/// println!("{report}");
+///
+/// assert_eq!(report.active, active_backend());
+/// ```
+pub fn backend_report() -> BackendReport {
+    let active = active_backend();
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    ))]
+    let forced = crate::feature_detection::is_tier_forced();
+    #[cfg(not(any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )))]
+    let forced = false;
+
+    let backends = PerformanceTier::native()
+        .map(|backend| {
+            let reason = match backend.unavailable_reason() {
+                _ if backend == active => None,
+                Some(reason) => Some(reason.to_string()),
+                None if forced => Some(format!("{active} is forced")),
+                None => Some(format!("{active} is preferred")),
+            };
+
+            BackendDecision {
+                backend,
+                available: backend.is_available(),
+                reason,
+            }
+        })
+        .collect();
+
+    let algorithms = CrcAlgorithm::PREDEFINED
+        .iter()
+        .map(|&algorithm| AlgorithmBackend {
+            algorithm,
+            backend: active,
+            fusion: get_fusion(algorithm).is_some(),
+        })
+        .collect();
+
+    BackendReport {
+        capabilities: get_detected_capabilities(),
+        detected: get_detected_tier(),
+        active,
+        forced,
+        force_rejected: get_force_tier_rejection().map(String::from),
+        backends,
+        algorithms,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::available_backends;
+
+    #[test]
+    fn test_report_matches_detection() {
+        let report = backend_report();
+
+        assert_eq!(report.active, active_backend());
+        assert_eq!(report.algorithms.len(), CrcAlgorithm::PREDEFINED.len());
+        assert_eq!(
+            report.backends.last().map(|decision| decision.backend),
+            Some(PerformanceTier::SoftwareTable)
+        );
+
+        for decision in &report.backends {
+            assert_eq!(
+                decision.available,
+                available_backends().any(|backend| backend == decision.backend)
+            );
+            assert_eq!(decision.reason.is_none(), decision.backend == report.active);
+        }
+    }
+
+    #[test]
+    fn test_decisions_are_explained() {
+        let active = BackendDecision {
+            backend: PerformanceTier::SoftwareTable,
+            available: true,
+            reason: None,
+        };
+        let unavailable = BackendDecision {
+            backend: PerformanceTier::X86_64Avx512Vpclmulqdq,
+            available: false,
+            reason: Some("OS has not enabled ZMM state".to_string()),
+        };
+
+        assert_eq!(active.to_string(), "software-fallback-tables: active");
+        assert_eq!(
+            unavailable.to_string(),
+            "x86_64-avx512-vpclmulqdq: unavailable, OS has not enabled ZMM state"
+        );
+    }
+}
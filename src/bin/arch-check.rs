@@ -1,6 +1,12 @@
 // Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
 
 //! This is a simple program that checks if the target architecture supports certain features.
+//!
+//! With `--self-test`, it also cross-checks every available backend, exiting with a non-zero
+//! status if any checksum doesn't match.
+//!
+//! With `--json`, it prints the detected capabilities, the backend each algorithm uses and why
+//! the other backends weren't chosen as JSON instead, for host inventories.
 
 #[cfg(target_arch = "aarch64")]
 use std::arch::is_aarch64_feature_detected;
@@ -8,10 +14,29 @@ use std::arch::is_aarch64_feature_detected;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 use std::arch::is_x86_feature_detected;
 
-use crc_fast::get_calculator_target;
+#[cfg(target_arch = "riscv64")]
+use std::arch::is_riscv_feature_detected;
+
 use crc_fast::CrcAlgorithm::{Crc32Iscsi, Crc32IsoHdlc, Crc64Nvme};
+use crc_fast::{backend_report, get_calculator_target, self_test, BackendReport, SelfTestReport};
 
 fn main() {
+    let run_self_test = std::env::args().skip(1).any(|arg| arg == "--self-test");
+
+    if std::env::args().skip(1).any(|arg| arg == "--json") {
+        let self_test_report = run_self_test.then(self_test);
+        println!(
+            "{}",
+            report_json(&backend_report(), self_test_report.as_ref())
+        );
+
+        if self_test_report.is_some_and(|report| !report.passed()) {
+            std::process::exit(1);
+        }
+
+        return;
+    }
+
     // Check the target architecture and call the appropriate function
     #[cfg(target_arch = "aarch64")]
     aarch64_features();
@@ -19,9 +44,18 @@ fn main() {
     #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
     x86_features();
 
+    #[cfg(target_arch = "riscv64")]
+    riscv64_features();
+
     print_targets();
 
+    print_backends();
+
     print_cpu_info();
+
+    if run_self_test && !print_self_test() {
+        std::process::exit(1);
+    }
 }
 
 #[cfg(target_arch = "aarch64")]
@@ -98,6 +132,26 @@ fn x86_features() {
     }
 }
 
+#[cfg(target_arch = "riscv64")]
+fn riscv64_features() {
+    let checkmark: char = '✓';
+
+    println!("[RISC-V] Checking for features...");
+
+    if is_riscv_feature_detected!("zbc") {
+        println!("  {checkmark} Zbc",);
+    } else {
+        println!("  x Zbc");
+    }
+
+    // the vector extensions can only be detected at compile time on stable Rust
+    if cfg!(all(target_feature = "v", target_feature = "zvbc")) {
+        println!("  {checkmark} V + Zvbc (compiled in)\n",);
+    } else {
+        println!("  x V + Zvbc (not compiled in)\n");
+    }
+}
+
 /// Print the acceleration targets
 fn print_targets() {
     let checkmark: char = '✓';
@@ -121,6 +175,113 @@ fn print_targets() {
     );
 }
 
+/// Print the backend selection, and why the other backends weren't chosen
+fn print_backends() {
+    println!("[Backends]");
+    println!("  {}", backend_report());
+}
+
+/// Formats the backend report, and the self-test report if one was run, as JSON
+fn report_json(report: &BackendReport, self_test_report: Option<&SelfTestReport>) -> String {
+    let capabilities: Vec<String> = report
+        .capabilities
+        .features()
+        .iter()
+        .map(|(name, detected)| format!("{}:{detected}", json_string(name)))
+        .collect();
+
+    let backends: Vec<String> = report
+        .backends
+        .iter()
+        .map(|decision| {
+            format!(
+                "{{\"name\":{},\"available\":{},\"active\":{},\"reason\":{}}}",
+                json_string(decision.backend.name()),
+                decision.available,
+                decision.reason.is_none(),
+                decision
+                    .reason
+                    .as_deref()
+                    .map_or("null".to_string(), json_string)
+            )
+        })
+        .collect();
+
+    let algorithms: Vec<String> = report
+        .algorithms
+        .iter()
+        .map(|algorithm| {
+            format!(
+                "{{\"algorithm\":{},\"backend\":{},\"fusion\":{}}}",
+                json_string(&algorithm.algorithm.to_string()),
+                json_string(algorithm.backend.name()),
+                algorithm.fusion
+            )
+        })
+        .collect();
+
+    let mut json = format!(
+        "{{\"arch\":{},\"capabilities\":{{{}}},\"detected_backend\":{},\"active_backend\":{},\"forced\":{},\"force_rejected\":{},\"backends\":[{}],\"algorithms\":[{}]",
+        json_string(std::env::consts::ARCH),
+        capabilities.join(","),
+        json_string(report.detected.name()),
+        json_string(report.active.name()),
+        report.forced,
+        report
+            .force_rejected
+            .as_deref()
+            .map_or("null".to_string(), json_string),
+        backends.join(","),
+        algorithms.join(",")
+    );
+
+    if let Some(self_test_report) = self_test_report {
+        let failures: Vec<String> = self_test_report
+            .failures
+            .iter()
+            .map(|failure| json_string(&failure.to_string()))
+            .collect();
+
+        json.push_str(&format!(
+            ",\"self_test\":{{\"passed\":{},\"checks\":{},\"failures\":[{}]}}",
+            self_test_report.passed(),
+            self_test_report.checks,
+            failures.join(",")
+        ));
+    }
+
+    json.push('}');
+    json
+}
+
+/// Quotes and escapes a string for JSON
+fn json_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+/// Run the self-test and print its report, returning whether it passed
+fn print_self_test() -> bool {
+    let report = self_test();
+
+    println!("\n[Self-test]");
+    println!("  {report}");
+
+    report.passed()
+}
+
 /// Print the first entry of /proc/cpuinfo if it's available
 fn print_cpu_info() {
     println!("\n[CPU Info]");
@@ -1,9 +1,18 @@
 // Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
 
 //! This is a simple program to calculate a checksum from the command line
-
-use crc_fast::{checksum, checksum_file, CrcAlgorithm};
+//!
+//! With file arguments it prints coreutils-style `<crc>  <file>` lines, and `-c` verifies them.
+
+use crc_fast::{
+    available_backends, checksum, checksum_file, checksum_from_base64_with_params,
+    checksum_to_base64_with_params, checksum_to_be_bytes_with_params, forge, posix_cksum,
+    Calculator, CrcAlgorithm, CrcParams, Digest, PerformanceTier, PosixCksum,
+};
 use std::env;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::Path;
 use std::process::ExitCode;
 use std::str::FromStr;
 
@@ -12,8 +21,36 @@ struct Config {
     algorithm: String,
     file: Option<String>,
     string: Option<String>,
+    inputs: Vec<String>,
+    check: Option<CheckConfig>,
+    recursive: bool,
+    posix_cksum: bool,
     format: OutputFormat,
     benchmark: Option<BenchmarkConfig>,
+    matrix: Option<MatrixConfig>,
+    forge: Option<ForgeConfig>,
+}
+
+/// Computes the bytes to write at an offset so the input hashes to a target CRC
+#[derive(Debug)]
+struct ForgeConfig {
+    offset: usize,
+    target: u64,
+}
+
+#[derive(Debug)]
+struct CheckConfig {
+    quiet: bool,
+    status: bool,
+}
+
+/// Tallies of a check run, reported as warnings once every manifest is read
+#[derive(Debug, Default, PartialEq)]
+struct CheckSummary {
+    matched: usize,
+    mismatched: usize,
+    unreadable: usize,
+    improperly_formatted: usize,
 }
 
 #[derive(Debug)]
@@ -33,6 +70,8 @@ struct BenchmarkRunner {
     algorithm: CrcAlgorithm,
     data: BenchmarkData,
     duration: f64,
+    /// A forced backend, instead of the default dispatch
+    backend: Option<(PerformanceTier, Calculator)>,
 }
 
 #[derive(Debug)]
@@ -49,6 +88,81 @@ struct BenchmarkResult {
 enum OutputFormat {
     Hex,
     Decimal,
+    /// Base64 of the big-endian bytes, as cloud object stores exchange CRCs
+    Base64,
+    /// The raw big-endian bytes, for a single input
+    Bytes,
+    /// A JSON object per input
+    Json,
+}
+
+/// Sweeps algorithms, sizes and tiers, with several timed samples per combination
+#[derive(Debug)]
+struct MatrixConfig {
+    algorithms: Vec<CrcAlgorithm>,
+    sizes: Vec<usize>,
+    tiers: Vec<MatrixTier>,
+    duration: f64,
+    warmup: f64,
+    samples: usize,
+    format: MatrixFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MatrixTier {
+    /// The default dispatch, including the CRC-32 fusion paths
+    Auto,
+    Backend(PerformanceTier),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MatrixFormat {
+    Table,
+    Csv,
+    Json,
+}
+
+/// Summary statistics over the samples of one matrix combination
+#[derive(Debug, PartialEq)]
+struct SampleStats {
+    mean: f64,
+    median: f64,
+    min: f64,
+    max: f64,
+    stddev: f64,
+}
+
+impl MatrixTier {
+    fn name(self) -> &'static str {
+        match self {
+            MatrixTier::Auto => "auto",
+            MatrixTier::Backend(tier) => tier.name(),
+        }
+    }
+}
+
+impl SampleStats {
+    fn new(values: &[f64]) -> Self {
+        let mut sorted = values.to_vec();
+        sorted.sort_by(f64::total_cmp);
+
+        let n = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / n;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2.0
+        } else {
+            sorted[sorted.len() / 2]
+        };
+        let variance = sorted.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+
+        Self {
+            mean,
+            median,
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            stddev: variance.sqrt(),
+        }
+    }
 }
 
 impl BenchmarkConfig {
@@ -73,19 +187,54 @@ impl BenchmarkRunner {
             algorithm,
             data,
             duration,
+            backend: None,
         }
     }
 
+    /// Always uses the given backend, which must be available, for in-memory data
+    fn with_backend(mut self, backend: PerformanceTier) -> Result<Self, String> {
+        let calculator =
+            Calculator::new_with_backend(self.algorithm, backend).map_err(|e| e.to_string())?;
+        self.backend = Some((backend, calculator));
+
+        Ok(self)
+    }
+
     fn run(&self) -> Result<BenchmarkResult, String> {
+        self.run_for(self.duration)
+    }
+
+    fn run_for(&self, duration: f64) -> Result<BenchmarkResult, String> {
         use std::time::Instant;
 
+        // reading the clock costs more than checksumming small buffers, so check it in batches
+        let batch = match &self.data {
+            BenchmarkData::InMemory(data) => (65536 / data.len().max(1)).clamp(1, 1024) as u64,
+            BenchmarkData::File(_) => 1,
+        };
+
         let start = Instant::now();
         let mut iterations = 0u64;
 
-        while start.elapsed().as_secs_f64() < self.duration {
+        while start.elapsed().as_secs_f64() < duration {
             match &self.data {
                 BenchmarkData::InMemory(data) => {
-                    std::hint::black_box(checksum(self.algorithm, data));
+                    for _ in 0..batch {
+                        match &self.backend {
+                            Some((_, calculator)) => {
+                                std::hint::black_box(
+                                    calculator.checksum(std::hint::black_box(data)),
+                                );
+                            }
+                            None => {
+                                std::hint::black_box(checksum(
+                                    self.algorithm,
+                                    std::hint::black_box(data),
+                                ));
+                            }
+                        }
+                    }
+                    iterations += batch - 1;
                 }
                 BenchmarkData::File(filename) => {
                     match checksum_file(self.algorithm, filename, None) {
@@ -109,7 +258,10 @@ impl BenchmarkRunner {
                 .map_err(|e| format!("Failed to get file size: {}", e))?,
         };
 
-        let acceleration_target = crc_fast::get_calculator_target(self.algorithm);
+        let acceleration_target = match &self.backend {
+            Some((backend, _)) => backend.name().to_string(),
+            None => crc_fast::get_calculator_target(self.algorithm),
+        };
 
         Ok(BenchmarkResult::new(
             iterations,
@@ -184,30 +336,87 @@ fn generate_random_data(size: usize) -> Result<Vec<u8>, String> {
 }
 
 fn print_usage() {
-    println!("Usage: checksum -a algorithm [-f file] [-s string] [--format hex|decimal]");
+    println!("Usage: checksum -a algorithm [-f file] [-s string] [--format format]");
+    println!("       checksum -a algorithm [-r] [--format format] [file|-]...");
+    println!("       checksum -a algorithm -c [--quiet] [--status] [--format hex|decimal|base64] [manifest|-]...");
+    println!("       checksum --posix-cksum [-r] [-f file] [-s string] [file|-]...");
     println!(
         "       checksum -a algorithm -b [--size bytes] [--duration seconds] [-f file] [-s string]"
     );
+    println!(
+        "       checksum --bench-matrix [-a algorithm[,algorithm...]] [--sizes bytes[,bytes...]]"
+    );
+    println!("                [--tiers tier[,tier...]] [--duration seconds] [--warmup seconds]");
+    println!("                [--samples count] [--bench-format table|csv|json]");
+    println!("       checksum -a algorithm --forge offset --target crc [--format hex|bytes]");
+    println!("                [-f file] [-s string] [file|-]");
     println!();
     println!("Example: checksum -a CRC-32/ISCSI -f myfile.txt");
     println!("Example: checksum -a CRC-64/NVME -s 'Hello, world!' --format decimal");
+    println!("Example: checksum -a CRC-32/ISCSI --format base64 myfile.txt");
+    println!("Example: checksum -a CRC-32/ISO-HDLC -r src > manifest.txt");
+    println!("Example: checksum -a CRC-32/ISO-HDLC -c manifest.txt");
+    println!("Example: checksum --posix-cksum myfile.txt");
     println!("Example: checksum -a CRC-32/ISCSI -b --size 1048576 --duration 5.0");
+    println!("Example: checksum --bench-matrix -a CRC-32/ISCSI,CRC-64/NVME --sizes 64,4096 --bench-format csv");
+    println!("Example: checksum -a CRC-32/ISO-HDLC --forge 252 --target 0xdeadbeef firmware.bin");
     println!();
     println!("Options:");
     println!("  -a algorithm        Specify the checksum algorithm (required)");
     println!("  -f file             Calculate checksum for the specified file");
     println!("  -h, --help          Show this help message");
     println!("  -s string           Calculate checksum for the specified string");
-    println!("  --format hex|decimal Output format (default: hex)");
+    println!("  --format format     Output format (default: hex):");
+    println!("                        hex      zero-padded to the CRC width");
+    println!("                        decimal");
+    println!("                        base64   of the big-endian bytes, as in x-amz-checksum-*");
+    println!("                        bytes    the raw big-endian bytes, for a single input");
+    println!("                        json     {{\"algorithm\", \"width\", \"value\", \"input\"}} per input");
+    println!();
+    println!("Files:");
+    println!(
+        "  file...             Print a '<crc>  <file>' line for each file, reading stdin for '-'"
+    );
+    println!("                      or when no files are given");
+    println!("  -r, --recursive     Checksum every file under directory arguments");
+    println!("  -c, --check         Read '<crc>  <file>' lines from the files and verify them");
+    println!("  --quiet             Don't print OK for each verified file (with -c)");
+    println!("  --status            Print nothing, the exit status shows the result (with -c)");
+    println!("  --posix-cksum       Print 'CRC SIZE FILENAME' lines matching POSIX cksum, which");
+    println!("                      uses CRC-32/CKSUM with the length appended, so -a is optional");
     println!();
     println!("Benchmarking:");
     println!("  -b                  Enable benchmark mode");
     println!("  --duration seconds  Benchmark duration in seconds (default: 10.0)");
     println!("  --size bytes        Data size for random generation in benchmark mode (default: 1048576 [1MiB])");
     println!();
+    println!("Benchmark matrix:");
+    println!(
+        "  --bench-matrix      Benchmark every combination of algorithm, size and tier, with -a"
+    );
+    println!("                      taking a comma-separated list (default: every algorithm)");
+    println!("  --sizes bytes,...   Data sizes (default: 64,1024,65536,1048576)");
+    println!("  --tiers tier,...    'auto' (the default dispatch, including CRC-32 fusion) and/or");
+    println!("                      backend names, as listed by arch-check (default: auto and");
+    println!("                      every available backend)");
+    println!("  --duration seconds  Measured time per combination (default: 0.5)");
+    println!("  --warmup seconds    Unmeasured time before each combination (default: 0.1)");
+    println!("  --samples count     Samples the measured time is split into, for the statistics");
+    println!("                      (default: 5)");
+    println!("  --bench-format fmt  table, or csv/json with mean, median, min, max and stddev of");
+    println!("                      throughput and latency (default: table)");
+    println!();
+    println!("Forging:");
+    println!(
+        "  --forge offset      Print the width/8 bytes which, written at the byte offset in the"
+    );
+    println!("                      input, give the whole input the --target CRC (as hex, or raw");
+    println!("                      with --format bytes)");
+    println!("  --target crc        The CRC to forge, in hex");
     println!();
-    println!("Note: In normal mode, either -f or -s must be provided, but not both.");
+    println!("Note: -f and -s can't be combined with each other or with file arguments.");
     println!("      In benchmark mode (-b), -f or -s are optional for using specific data.");
+    println!("      The exit status is 1 if any file couldn't be read or didn't match.");
 }
 
 fn parse_args() -> Result<Config, String> {
@@ -225,10 +434,24 @@ fn parse_args() -> Result<Config, String> {
     let mut algorithm: Option<String> = None;
     let mut file: Option<String> = None;
     let mut string: Option<String> = None;
-    let mut format = OutputFormat::Hex; // Default to hex
+    let mut inputs: Vec<String> = Vec::new();
+    let mut check = false;
+    let mut quiet = false;
+    let mut status = false;
+    let mut recursive = false;
+    let mut posix_cksum = false;
+    let mut format: Option<OutputFormat> = None; // Default to hex
     let mut benchmark_mode = false;
     let mut benchmark_size: Option<usize> = None;
-    let mut benchmark_duration = 10.0; // Default duration
+    let mut benchmark_duration: Option<f64> = None;
+    let mut bench_matrix = false;
+    let mut matrix_sizes: Option<String> = None;
+    let mut matrix_tiers: Option<String> = None;
+    let mut matrix_warmup: Option<f64> = None;
+    let mut matrix_samples: Option<usize> = None;
+    let mut matrix_format: Option<MatrixFormat> = None;
+    let mut forge_offset: Option<usize> = None;
+    let mut forge_target: Option<u64> = None;
 
     let mut i = 1; // Skip program name
     while i < args.len() {
@@ -264,22 +487,45 @@ fn parse_args() -> Result<Config, String> {
                 if i + 1 >= args.len() {
                     return Err("Missing format after --format flag".to_string());
                 }
-                match args[i + 1].as_str() {
-                    "hex" => format = OutputFormat::Hex,
-                    "decimal" => format = OutputFormat::Decimal,
+                format = Some(match args[i + 1].as_str() {
+                    "hex" => OutputFormat::Hex,
+                    "decimal" => OutputFormat::Decimal,
+                    "base64" => OutputFormat::Base64,
+                    "bytes" => OutputFormat::Bytes,
+                    "json" => OutputFormat::Json,
                     invalid => {
                         return Err(format!(
-                            "Invalid format '{}'. Use 'hex' or 'decimal'",
-                            invalid
-                        ))
+                        "Invalid format '{}'. Use 'hex', 'decimal', 'base64', 'bytes' or 'json'",
+                        invalid
+                    ))
                     }
-                }
+                });
                 i += 2;
             }
             "-b" => {
                 benchmark_mode = true;
                 i += 1;
             }
+            "-c" | "--check" => {
+                check = true;
+                i += 1;
+            }
+            "--quiet" => {
+                quiet = true;
+                i += 1;
+            }
+            "--status" => {
+                status = true;
+                i += 1;
+            }
+            "-r" | "--recursive" => {
+                recursive = true;
+                i += 1;
+            }
+            "--posix-cksum" => {
+                posix_cksum = true;
+                i += 1;
+            }
             "--size" => {
                 if i + 1 >= args.len() {
                     return Err("Missing size value after --size flag".to_string());
@@ -295,22 +541,178 @@ fn parse_args() -> Result<Config, String> {
                 if i + 1 >= args.len() {
                     return Err("Missing duration value after --duration flag".to_string());
                 }
-                benchmark_duration = args[i + 1]
-                    .parse::<f64>()
-                    .map_err(|_| format!("Invalid duration value: {}", args[i + 1]))?;
+                benchmark_duration = Some(
+                    args[i + 1]
+                        .parse::<f64>()
+                        .map_err(|_| format!("Invalid duration value: {}", args[i + 1]))?,
+                );
                 i += 2;
             }
+            "--bench-matrix" => {
+                bench_matrix = true;
+                i += 1;
+            }
+            "--forge" => {
+                if i + 1 >= args.len() {
+                    return Err("Missing offset after --forge flag".to_string());
+                }
+                forge_offset = Some(
+                    args[i + 1]
+                        .parse::<usize>()
+                        .map_err(|_| format!("Invalid offset value: {}", args[i + 1]))?,
+                );
+                i += 2;
+            }
+            "--target" => {
+                if i + 1 >= args.len() {
+                    return Err("Missing CRC after --target flag".to_string());
+                }
+                forge_target = Some(
+                    u64::from_str_radix(args[i + 1].trim_start_matches("0x"), 16)
+                        .map_err(|_| format!("Invalid target CRC: {}", args[i + 1]))?,
+                );
+                i += 2;
+            }
+            "--sizes" | "--tiers" | "--warmup" | "--samples" | "--bench-format" => {
+                let flag = args[i].as_str();
+                let value = args
+                    .get(i + 1)
+                    .ok_or(format!("Missing value after {} flag", flag))?;
+
+                match flag {
+                    "--sizes" => matrix_sizes = Some(value.clone()),
+                    "--tiers" => matrix_tiers = Some(value.clone()),
+                    "--warmup" => {
+                        matrix_warmup = Some(
+                            value
+                                .parse::<f64>()
+                                .map_err(|_| format!("Invalid warmup value: {}", value))?,
+                        )
+                    }
+                    "--samples" => {
+                        matrix_samples = Some(
+                            value
+                                .parse::<usize>()
+                                .map_err(|_| format!("Invalid samples value: {}", value))?,
+                        )
+                    }
+                    _ => {
+                        matrix_format = Some(match value.as_str() {
+                            "table" => MatrixFormat::Table,
+                            "csv" => MatrixFormat::Csv,
+                            "json" => MatrixFormat::Json,
+                            invalid => {
+                                return Err(format!(
+                                    "Invalid benchmark format '{}'. Use 'table', 'csv' or 'json'",
+                                    invalid
+                                ))
+                            }
+                        })
+                    }
+                }
+                i += 2;
+            }
+            arg if arg == "-" || !arg.starts_with('-') => {
+                inputs.push(arg.to_string());
+                i += 1;
+            }
             arg => {
                 return Err(format!("Unknown argument: {}", arg));
             }
         }
     }
 
-    // Validate required arguments
-    let algorithm = algorithm.ok_or("Algorithm (-a) is required")?;
+    let matrix_flags = matrix_sizes.is_some()
+        || matrix_tiers.is_some()
+        || matrix_warmup.is_some()
+        || matrix_samples.is_some()
+        || matrix_format.is_some();
+
+    if bench_matrix {
+        if benchmark_mode
+            || benchmark_size.is_some()
+            || file.is_some()
+            || string.is_some()
+            || !inputs.is_empty()
+            || check
+            || recursive
+            || posix_cksum
+            || format.is_some()
+            || forge_offset.is_some()
+            || forge_target.is_some()
+        {
+            return Err(
+                "--bench-matrix only supports -a, --sizes, --tiers, --duration, --warmup, --samples and --bench-format"
+                    .to_string(),
+            );
+        }
+
+        let matrix = MatrixConfig::new(
+            algorithm.as_deref(),
+            matrix_sizes.as_deref(),
+            matrix_tiers.as_deref(),
+            benchmark_duration.unwrap_or(0.5),
+            matrix_warmup.unwrap_or(0.1),
+            matrix_samples.unwrap_or(5),
+            matrix_format.unwrap_or(MatrixFormat::Table),
+        )?;
+
+        return Ok(Config {
+            algorithm: algorithm.unwrap_or_default(),
+            file,
+            string,
+            inputs,
+            check: None,
+            recursive,
+            posix_cksum,
+            format: OutputFormat::Hex,
+            benchmark: None,
+            matrix: Some(matrix),
+            forge: None,
+        });
+    }
+
+    if matrix_flags {
+        return Err(
+            "--sizes, --tiers, --warmup, --samples and --bench-format can only be used with --bench-matrix"
+                .to_string(),
+        );
+    }
+
+    // Validate required arguments, --posix-cksum implies its algorithm
+    let algorithm = match algorithm {
+        Some(algorithm) => algorithm,
+        None if posix_cksum => "CRC-32/CKSUM".to_string(),
+        None => return Err("Algorithm (-a) is required".to_string()),
+    };
+
+    if posix_cksum && algorithm != "CRC-32/CKSUM" {
+        return Err("--posix-cksum always uses CRC-32/CKSUM".to_string());
+    }
+
+    let forge = match (forge_offset, forge_target) {
+        (Some(offset), Some(target)) => Some(ForgeConfig { offset, target }),
+        (None, None) => None,
+        _ => return Err("--forge and --target must be used together".to_string()),
+    };
+
+    if forge.is_some() {
+        if benchmark_mode || check || recursive || posix_cksum || inputs.len() > 1 {
+            return Err(
+                "--forge only supports a single input, with -f, -s or a file argument".to_string(),
+            );
+        }
+
+        if !matches!(
+            format,
+            None | Some(OutputFormat::Hex) | Some(OutputFormat::Bytes)
+        ) {
+            return Err("--forge prints hex or raw bytes".to_string());
+        }
+    }
 
     // Validate mutual exclusivity between benchmark and normal modes
-    if !benchmark_mode && (benchmark_size.is_some() || benchmark_duration != 10.0) {
+    if !benchmark_mode && (benchmark_size.is_some() || benchmark_duration.is_some()) {
         return Err("--size and --duration can only be used with -b flag".to_string());
     }
 
@@ -318,7 +720,7 @@ fn parse_args() -> Result<Config, String> {
     let benchmark = if benchmark_mode {
         let config = BenchmarkConfig {
             size: benchmark_size,
-            duration: benchmark_duration,
+            duration: benchmark_duration.unwrap_or(10.0),
         };
         config.validate()?;
         Some(config)
@@ -326,51 +728,484 @@ fn parse_args() -> Result<Config, String> {
         None
     };
 
-    // Validate input requirements based on mode
-    if benchmark.is_none() {
-        // Normal mode: require either file or string input
-        if file.is_none() && string.is_none() {
-            return Err(
-                "Either -f (file) or -s (string) must be provided in normal mode".to_string(),
-            );
-        }
+    // Validate input requirements based on mode: with neither -f nor -s, the file arguments (or
+    // stdin) are used, except in benchmark mode which generates data instead
+    if !inputs.is_empty() && (file.is_some() || string.is_some()) {
+        return Err("File arguments can't be combined with -f or -s".to_string());
+    }
+
+    if benchmark.is_some() && (!inputs.is_empty() || check || recursive) {
+        return Err("Benchmark mode (-b) only supports -f or -s input".to_string());
+    }
+
+    if check && (file.is_some() || string.is_some() || recursive) {
+        return Err("Check mode (-c) reads manifests from file arguments or stdin".to_string());
+    }
+
+    if posix_cksum && (check || benchmark.is_some()) {
+        return Err("--posix-cksum can't be combined with -c or -b".to_string());
+    }
+
+    if posix_cksum && format.is_some() {
+        return Err("--posix-cksum always prints 'CRC SIZE FILENAME' lines".to_string());
+    }
+
+    let format = format.unwrap_or(OutputFormat::Hex);
+
+    if check && matches!(format, OutputFormat::Bytes | OutputFormat::Json) {
+        return Err("Check mode (-c) reads hex, decimal or base64 checksums".to_string());
+    }
+
+    if matches!(format, OutputFormat::Bytes) && (inputs.len() > 1 || recursive) {
+        return Err("--format bytes only supports a single input".to_string());
+    }
+
+    if !check && (quiet || status) {
+        return Err("--quiet and --status can only be used with -c flag".to_string());
+    }
+
+    if recursive && inputs.is_empty() {
+        return Err("-r requires at least one file or directory argument".to_string());
+    }
+
+    // cksum prints a file name for -f too, and none for implicit stdin
+    if posix_cksum {
+        inputs.extend(file.take());
+    } else if file.is_none() && string.is_none() && inputs.is_empty() {
+        inputs.push("-".to_string());
     }
-    // Benchmark mode: file and string are optional (will use generated data if neither provided)
 
     Ok(Config {
         algorithm,
         file,
         string,
+        inputs,
+        check: check.then_some(CheckConfig { quiet, status }),
+        recursive,
+        posix_cksum,
         format,
         benchmark,
+        matrix: None,
+        forge,
     })
 }
 
-fn calculate_checksum(config: &Config) -> Result<(), String> {
+/// Runs the configured mode, returning whether every input was read (and, with -c, matched)
+fn calculate_checksum(config: &Config) -> Result<bool, String> {
+    if let Some(matrix_config) = &config.matrix {
+        return run_matrix(matrix_config).map(|_| true);
+    }
+
     let algorithm = CrcAlgorithm::from_str(&config.algorithm)
         .map_err(|_| format!("Invalid algorithm: {}", config.algorithm))?;
 
     // Check if benchmark mode is enabled
     if let Some(benchmark_config) = &config.benchmark {
-        return run_benchmark(config, benchmark_config, algorithm);
+        return run_benchmark(config, benchmark_config, algorithm).map(|_| true);
     }
 
-    let checksum = if let Some(ref filename) = config.file {
-        checksum_file(algorithm, filename, None).unwrap()
+    if let Some(check_config) = &config.check {
+        return Ok(run_check(config, check_config, algorithm));
+    }
+
+    if config.posix_cksum {
+        return Ok(run_posix_cksum(config));
+    }
+
+    if let Some(forge_config) = &config.forge {
+        return run_forge(config, forge_config, algorithm).map(|_| true);
+    }
+
+    let (checksum, input) = if let Some(ref filename) = config.file {
+        let checksum =
+            checksum_file(algorithm, filename, None).map_err(|e| format!("{}: {}", filename, e))?;
+
+        (checksum, Some(filename.as_str()))
     } else if let Some(ref text) = config.string {
-        checksum(algorithm, text.as_bytes())
+        (checksum(algorithm, text.as_bytes()), None)
     } else {
-        return Err("No input provided for checksum calculation".to_string());
+        return Ok(checksum_inputs(config, algorithm));
     };
 
+    let params = *Calculator::new(algorithm).params();
+
     match config.format {
-        OutputFormat::Hex => println!("{:#x?}", checksum),
-        OutputFormat::Decimal => println!("{}", checksum),
+        OutputFormat::Hex => println!("0x{}", format_value(checksum, &params, &config.format)),
+        OutputFormat::Decimal | OutputFormat::Base64 => {
+            println!("{}", format_value(checksum, &params, &config.format))
+        }
+        OutputFormat::Bytes => write_bytes(checksum, &params).map_err(|e| e.to_string())?,
+        OutputFormat::Json => println!("{}", format_json(checksum, &params, input)),
+    }
+
+    Ok(true)
+}
+
+/// Prints a `<crc>  <file>` line for each input, reporting unreadable ones on stderr
+fn checksum_inputs(config: &Config, algorithm: CrcAlgorithm) -> bool {
+    let params = *Calculator::new(algorithm).params();
+    let mut success = true;
+
+    for input in &config.inputs {
+        let mut paths = Vec::new();
+
+        if config.recursive && input != "-" && Path::new(input).is_dir() {
+            if let Err(e) = collect_files(Path::new(input), &mut paths) {
+                eprintln!("checksum: {}: {}", input, e);
+                success = false;
+            }
+        } else {
+            paths.push(input.clone());
+        }
+
+        for path in paths {
+            let result = if config.posix_cksum {
+                posix_cksum_input(&path).map(|(crc, size)| println!("{} {} {}", crc, size, path))
+            } else {
+                checksum_input(algorithm, &path).and_then(|value| match config.format {
+                    OutputFormat::Bytes => write_bytes(value, &params),
+                    OutputFormat::Json => {
+                        println!("{}", format_json(value, &params, Some(&path)));
+                        Ok(())
+                    }
+                    _ => {
+                        println!("{}  {}", format_value(value, &params, &config.format), path);
+                        Ok(())
+                    }
+                })
+            };
+
+            if let Err(e) = result {
+                eprintln!("checksum: {}: {}", path, e);
+                success = false;
+            }
+        }
+    }
+
+    success
+}
+
+/// Adds every file under `dir` to `paths`, in name order
+fn collect_files(dir: &Path, paths: &mut Vec<String>) -> io::Result<()> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+
+    for entry in entries {
+        if entry.is_dir() {
+            collect_files(&entry, paths)?;
+        } else {
+            paths.push(entry.to_string_lossy().into_owned());
+        }
+    }
+
+    Ok(())
+}
+
+/// Checksums a file, or stdin for `-`
+fn checksum_input(algorithm: CrcAlgorithm, path: &str) -> io::Result<u64> {
+    if path == "-" {
+        let mut digest = Digest::new(algorithm);
+        io::copy(&mut io::stdin().lock(), &mut digest)?;
+
+        return Ok(digest.finalize());
+    }
+
+    checksum_file(algorithm, path, None)
+}
+
+/// Prints `CRC SIZE FILENAME` lines like POSIX `cksum`, leaving out the name for a string or
+/// implicit stdin
+fn run_posix_cksum(config: &Config) -> bool {
+    if let Some(ref text) = config.string {
+        println!("{} {}", posix_cksum(text.as_bytes()), text.len());
+
+        return true;
+    }
+
+    if !config.inputs.is_empty() {
+        return checksum_inputs(config, CrcAlgorithm::Crc32Cksum);
+    }
+
+    match posix_cksum_input("-") {
+        Ok((crc, size)) => {
+            println!("{} {}", crc, size);
+            true
+        }
+        Err(e) => {
+            eprintln!("checksum: -: {}", e);
+            false
+        }
+    }
+}
+
+/// Calculates the POSIX `cksum` checksum and size of a file, or stdin for `-`
+fn posix_cksum_input(path: &str) -> io::Result<(u32, u64)> {
+    let mut cksum = PosixCksum::new();
+
+    if path == "-" {
+        io::copy(&mut io::stdin().lock(), &mut cksum)?;
+    } else {
+        io::copy(&mut fs::File::open(path)?, &mut cksum)?;
+    }
+
+    Ok((cksum.finalize(), cksum.get_amount()))
+}
+
+/// Formats a checksum as text, zero-padding hex to the CRC width
+fn format_value(value: u64, params: &CrcParams, format: &OutputFormat) -> String {
+    match format {
+        OutputFormat::Decimal => value.to_string(),
+        OutputFormat::Base64 => checksum_to_base64_with_params(params, value),
+        _ => format!("{:0digits$x}", value, digits = params.width as usize / 4),
+    }
+}
+
+/// Formats a checksum as a single-line JSON object, with a `null` input for strings
+fn format_json(value: u64, params: &CrcParams, input: Option<&str>) -> String {
+    format!(
+        "{{\"algorithm\":{},\"width\":{},\"value\":\"{}\",\"input\":{}}}",
+        json_string(params.name),
+        params.width,
+        format_value(value, params, &OutputFormat::Hex),
+        input.map_or("null".to_string(), json_string)
+    )
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+/// Writes the raw big-endian bytes of a checksum to stdout
+fn write_bytes(value: u64, params: &CrcParams) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    stdout.write_all(&checksum_to_be_bytes_with_params(params, value))?;
+    stdout.flush()
+}
+
+/// Prints the bytes which give the input the target CRC when written at the offset
+fn run_forge(
+    config: &Config,
+    forge_config: &ForgeConfig,
+    algorithm: CrcAlgorithm,
+) -> Result<(), String> {
+    let data = if let Some(ref filename) = config.file {
+        fs::read(filename).map_err(|e| format!("{}: {}", filename, e))?
+    } else if let Some(ref text) = config.string {
+        text.as_bytes().to_vec()
+    } else if config.inputs[0] == "-" {
+        let mut data = Vec::new();
+        io::stdin()
+            .lock()
+            .read_to_end(&mut data)
+            .map_err(|e| format!("-: {}", e))?;
+        data
+    } else {
+        fs::read(&config.inputs[0]).map_err(|e| format!("{}: {}", config.inputs[0], e))?
+    };
+
+    let params = *Calculator::new(algorithm).params();
+    let len = params.width as usize / 8;
+
+    if forge_config.offset.saturating_add(len) > data.len() {
+        return Err(format!(
+            "{} bytes at offset {} don't fit in the {} byte input",
+            len,
+            forge_config.offset,
+            data.len()
+        ));
+    }
+
+    if params.width < 64 && forge_config.target >> params.width != 0 {
+        return Err(format!(
+            "Target CRC 0x{:x} is wider than {} bits",
+            forge_config.target, params.width
+        ));
+    }
+
+    let bytes = forge(params, &data, forge_config.offset, forge_config.target);
+
+    if matches!(config.format, OutputFormat::Bytes) {
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(&bytes).map_err(|e| e.to_string())?;
+        stdout.flush().map_err(|e| e.to_string())?;
+    } else {
+        println!(
+            "{}",
+            bytes
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>()
+        );
+    }
+
+    Ok(())
+}
+
+/// Splits a manifest line into its checksum and file name, accepting GNU (`<crc>  <file>`, or
+/// `<crc> *<file>` for binary mode) and BSD (`<algorithm> (<file>) = <crc>`) styles
+fn parse_manifest_line(line: &str) -> Option<(&str, &str)> {
+    if let Some((head, crc)) = line.rsplit_once(") = ") {
+        if let Some((_, file)) = head.split_once(" (") {
+            return Some((crc, file));
+        }
+    }
+
+    let (crc, rest) = line.split_once(' ')?;
+    let file = rest.strip_prefix(' ').or_else(|| rest.strip_prefix('*'))?;
+
+    (!crc.is_empty() && !file.is_empty()).then_some((crc, file))
+}
+
+fn parse_value(crc: &str, params: &CrcParams, format: &OutputFormat) -> Option<u64> {
+    match format {
+        OutputFormat::Decimal => crc.parse().ok(),
+        OutputFormat::Base64 => checksum_from_base64_with_params(params, crc).ok(),
+        _ => u64::from_str_radix(crc.trim_start_matches("0x"), 16).ok(),
+    }
+}
+
+/// Verifies the `<crc>  <file>` lines of each manifest, returning whether every listed file was
+/// read and matched
+fn run_check(config: &Config, check_config: &CheckConfig, algorithm: CrcAlgorithm) -> bool {
+    let mut summary = CheckSummary::default();
+    let mut success = true;
+
+    for manifest in &config.inputs {
+        let reader: Box<dyn BufRead> = if manifest == "-" {
+            Box::new(io::stdin().lock())
+        } else {
+            match fs::File::open(manifest) {
+                Ok(file) => Box::new(BufReader::new(file)),
+                Err(e) => {
+                    eprintln!("checksum: {}: {}", manifest, e);
+                    success = false;
+                    continue;
+                }
+            }
+        };
+
+        let checked = summary.matched + summary.mismatched + summary.unreadable;
+
+        if let Err(e) = check_manifest(reader, config, check_config, algorithm, &mut summary) {
+            eprintln!("checksum: {}: {}", manifest, e);
+            success = false;
+        }
+
+        if summary.matched + summary.mismatched + summary.unreadable == checked {
+            eprintln!(
+                "checksum: {}: no properly formatted checksum lines found",
+                manifest
+            );
+            success = false;
+        }
+    }
+
+    if !check_config.status {
+        print_check_warnings(&summary);
+    }
+
+    success && summary.mismatched == 0 && summary.unreadable == 0
+}
+
+fn check_manifest(
+    reader: Box<dyn BufRead>,
+    config: &Config,
+    check_config: &CheckConfig,
+    algorithm: CrcAlgorithm,
+    summary: &mut CheckSummary,
+) -> io::Result<()> {
+    let params = *Calculator::new(algorithm).params();
+
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim_end_matches('\r');
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let Some((expected, path)) = parse_manifest_line(line)
+            .and_then(|(crc, path)| Some((parse_value(crc, &params, &config.format)?, path)))
+        else {
+            summary.improperly_formatted += 1;
+            continue;
+        };
+
+        match checksum_input(algorithm, path) {
+            Ok(actual) if actual == expected => {
+                summary.matched += 1;
+                if !check_config.quiet && !check_config.status {
+                    println!("{}: OK", path);
+                }
+            }
+            Ok(_) => {
+                summary.mismatched += 1;
+                if !check_config.status {
+                    println!("{}: FAILED", path);
+                }
+            }
+            Err(e) => {
+                summary.unreadable += 1;
+                if !check_config.status {
+                    eprintln!("checksum: {}: {}", path, e);
+                    println!("{}: FAILED open or read", path);
+                }
+            }
+        }
     }
 
     Ok(())
 }
 
+fn print_check_warnings(summary: &CheckSummary) {
+    let warnings = [
+        (
+            summary.improperly_formatted,
+            "line is",
+            "lines are",
+            "improperly formatted",
+        ),
+        (
+            summary.unreadable,
+            "listed file",
+            "listed files",
+            "could not be read",
+        ),
+        (
+            summary.mismatched,
+            "computed checksum",
+            "computed checksums",
+            "did NOT match",
+        ),
+    ];
+
+    for (count, singular, plural, message) in warnings {
+        match count {
+            0 => {}
+            1 => eprintln!("checksum: WARNING: 1 {} {}", singular, message),
+            _ => eprintln!("checksum: WARNING: {} {} {}", count, plural, message),
+        }
+    }
+}
+
 fn run_benchmark(
     config: &Config,
     benchmark_config: &BenchmarkConfig,
@@ -402,6 +1237,224 @@ fn run_benchmark(
     Ok(())
 }
 
+impl MatrixConfig {
+    fn new(
+        algorithms: Option<&str>,
+        sizes: Option<&str>,
+        tiers: Option<&str>,
+        duration: f64,
+        warmup: f64,
+        samples: usize,
+        format: MatrixFormat,
+    ) -> Result<Self, String> {
+        let algorithms = match algorithms {
+            Some(names) => names
+                .split(',')
+                .map(|name| {
+                    CrcAlgorithm::from_str(name).map_err(|_| format!("Invalid algorithm: {}", name))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            None => CrcAlgorithm::PREDEFINED.to_vec(),
+        };
+
+        let sizes = match sizes {
+            Some(sizes) => sizes
+                .split(',')
+                .map(|size| match size.parse::<usize>() {
+                    Ok(0) => Err("Size must be greater than 0".to_string()),
+                    Ok(size) if size > 1_073_741_824 => {
+                        Err("Data size too large (maximum 1 GiB)".to_string())
+                    }
+                    Ok(size) => Ok(size),
+                    Err(_) => Err(format!("Invalid size value: {}", size)),
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            None => vec![64, 1024, 65536, 1_048_576],
+        };
+
+        let tiers = match tiers {
+            Some(names) => names
+                .split(',')
+                .map(|name| match name {
+                    "auto" => Ok(MatrixTier::Auto),
+                    name => match PerformanceTier::from_name(name) {
+                        Some(tier) if tier.is_available() => Ok(MatrixTier::Backend(tier)),
+                        Some(_) => Err(format!("Tier not available on this CPU: {}", name)),
+                        None => Err(format!("Invalid tier: {}", name)),
+                    },
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            None => std::iter::once(MatrixTier::Auto)
+                .chain(available_backends().map(MatrixTier::Backend))
+                .collect(),
+        };
+
+        if duration <= 0.0 {
+            return Err("Duration must be greater than 0".to_string());
+        }
+
+        if warmup < 0.0 {
+            return Err("Warmup must not be negative".to_string());
+        }
+
+        if samples == 0 {
+            return Err("Samples must be greater than 0".to_string());
+        }
+
+        Ok(Self {
+            algorithms,
+            sizes,
+            tiers,
+            duration,
+            warmup,
+            samples,
+            format,
+        })
+    }
+}
+
+const MATRIX_CSV_HEADER: &str = "algorithm,tier,size_bytes,samples,iterations,\
+    throughput_gibs_mean,throughput_gibs_median,throughput_gibs_min,throughput_gibs_max,throughput_gibs_stddev,\
+    latency_ns_mean,latency_ns_median,latency_ns_min,latency_ns_max,latency_ns_stddev";
+
+/// Benchmarks every combination in the matrix, printing each row as soon as it's measured
+fn run_matrix(matrix: &MatrixConfig) -> Result<(), String> {
+    let max_size = matrix.sizes.iter().copied().max().unwrap_or(0);
+    let data = generate_random_data(max_size)?;
+
+    match matrix.format {
+        MatrixFormat::Table => println!(
+            "{:<24} {:<26} {:>10} {:>12} {:>10} {:>14}",
+            "Algorithm", "Tier", "Size", "GiB/s", "± stddev", "Latency"
+        ),
+        MatrixFormat::Csv => println!("{}", MATRIX_CSV_HEADER),
+        MatrixFormat::Json => println!("["),
+    }
+
+    let mut first = true;
+
+    for &algorithm in &matrix.algorithms {
+        for &size in &matrix.sizes {
+            for &tier in &matrix.tiers {
+                let runner = BenchmarkRunner::new(
+                    algorithm,
+                    BenchmarkData::InMemory(data[..size].to_vec()),
+                    matrix.duration / matrix.samples as f64,
+                );
+                let runner = match tier {
+                    MatrixTier::Auto => runner,
+                    MatrixTier::Backend(backend) => runner.with_backend(backend)?,
+                };
+
+                if matrix.warmup > 0.0 {
+                    runner.run_for(matrix.warmup)?;
+                }
+
+                let samples = (0..matrix.samples)
+                    .map(|_| runner.run())
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let row = format_matrix_row(matrix.format, algorithm, tier, size, &samples);
+
+                if matrix.format == MatrixFormat::Json && !first {
+                    println!(",");
+                }
+                if matrix.format == MatrixFormat::Json {
+                    print!("{}", row);
+                } else {
+                    println!("{}", row);
+                }
+                first = false;
+            }
+        }
+    }
+
+    if matrix.format == MatrixFormat::Json {
+        if !first {
+            println!();
+        }
+        println!("]");
+    }
+
+    Ok(())
+}
+
+fn format_matrix_row(
+    format: MatrixFormat,
+    algorithm: CrcAlgorithm,
+    tier: MatrixTier,
+    size: usize,
+    samples: &[BenchmarkResult],
+) -> String {
+    let throughput = SampleStats::new(
+        &samples
+            .iter()
+            .map(|sample| sample.throughput_gibs)
+            .collect::<Vec<_>>(),
+    );
+    let latency = SampleStats::new(
+        &samples
+            .iter()
+            .map(|sample| sample.time_per_iteration_nanos)
+            .collect::<Vec<_>>(),
+    );
+    let iterations: u64 = samples.iter().map(|sample| sample.iterations).sum();
+
+    match format {
+        MatrixFormat::Table => format!(
+            "{:<24} {:<26} {:>10} {:>12.2} {:>10.2} {:>11.1} ns",
+            algorithm.to_string(),
+            tier.name(),
+            format_number_with_commas(size as u64),
+            throughput.median,
+            throughput.stddev,
+            latency.median
+        ),
+        MatrixFormat::Csv => format!(
+            "{},{},{},{},{},{:.4},{:.4},{:.4},{:.4},{:.4},{:.1},{:.1},{:.1},{:.1},{:.1}",
+            algorithm,
+            tier.name(),
+            size,
+            samples.len(),
+            iterations,
+            throughput.mean,
+            throughput.median,
+            throughput.min,
+            throughput.max,
+            throughput.stddev,
+            latency.mean,
+            latency.median,
+            latency.min,
+            latency.max,
+            latency.stddev
+        ),
+        MatrixFormat::Json => {
+            let stats = |stats: &SampleStats, precision: usize| {
+                format!(
+                    "{{\"mean\":{:.p$},\"median\":{:.p$},\"min\":{:.p$},\"max\":{:.p$},\"stddev\":{:.p$}}}",
+                    stats.mean,
+                    stats.median,
+                    stats.min,
+                    stats.max,
+                    stats.stddev,
+                    p = precision
+                )
+            };
+
+            format!(
+                "  {{\"algorithm\":{},\"tier\":{},\"size_bytes\":{},\"samples\":{},\"iterations\":{},\"throughput_gibs\":{},\"latency_ns\":{}}}",
+                json_string(&algorithm.to_string()),
+                json_string(tier.name()),
+                size,
+                samples.len(),
+                iterations,
+                stats(&throughput, 4),
+                stats(&latency, 1)
+            )
+        }
+    }
+}
+
 // Format numbers with comma separators for better readability
 fn format_number_with_commas(n: u64) -> String {
     let s = n.to_string();
@@ -458,12 +1511,14 @@ fn display_benchmark_results(result: &BenchmarkResult, algorithm_name: &str) {
 
 fn main() -> ExitCode {
     match parse_args() {
-        Ok(config) => {
-            if let Err(e) = calculate_checksum(&config) {
+        Ok(config) => match calculate_checksum(&config) {
+            Ok(true) => {}
+            Ok(false) => return ExitCode::from(1),
+            Err(e) => {
                 eprintln!("Error: {}", e);
                 return ExitCode::from(1);
             }
-        }
+        },
         Err(msg) => {
             if msg == "help" {
                 print_usage();
@@ -577,6 +1632,61 @@ mod tests {
         assert!((result.time_per_iteration_nanos - expected_time_per_iter).abs() < 1e-6);
     }
 
+    #[test]
+    fn test_parse_manifest_line() {
+        assert_eq!(
+            parse_manifest_line("cbf43926  dir/a file.txt"),
+            Some(("cbf43926", "dir/a file.txt"))
+        );
+        assert_eq!(
+            parse_manifest_line("cbf43926 *a.bin"),
+            Some(("cbf43926", "a.bin"))
+        );
+        assert_eq!(
+            parse_manifest_line("CRC-32/ISO-HDLC (a (1).txt) = cbf43926"),
+            Some(("cbf43926", "a (1).txt"))
+        );
+        assert_eq!(parse_manifest_line("cbf43926 a.txt"), None);
+        assert_eq!(parse_manifest_line("cbf43926"), None);
+    }
+
+    #[test]
+    fn test_format_value_pads_to_width() {
+        let crc16 = *Calculator::new(CrcAlgorithm::Crc16Arc).params();
+        let crc32 = *Calculator::new(CrcAlgorithm::Crc32Iscsi).params();
+
+        assert_eq!(format_value(0x1234, &crc32, &OutputFormat::Hex), "00001234");
+        assert_eq!(format_value(0x1234, &crc16, &OutputFormat::Hex), "1234");
+        assert_eq!(format_value(0x1234, &crc32, &OutputFormat::Decimal), "4660");
+        assert_eq!(
+            format_value(0x1234, &crc32, &OutputFormat::Base64),
+            "AAASNA=="
+        );
+        assert_eq!(
+            parse_value("00001234", &crc32, &OutputFormat::Hex),
+            Some(0x1234)
+        );
+        assert_eq!(
+            parse_value("AAASNA==", &crc32, &OutputFormat::Base64),
+            Some(0x1234)
+        );
+        assert_eq!(parse_value("EjQ=", &crc32, &OutputFormat::Base64), None);
+    }
+
+    #[test]
+    fn test_format_json() {
+        let params = *Calculator::new(CrcAlgorithm::Crc32Iscsi).params();
+
+        assert_eq!(
+            format_json(0xe3069283, &params, Some("dir/\"quoted\".txt")),
+            r#"{"algorithm":"CRC-32/ISCSI","width":32,"value":"e3069283","input":"dir/\"quoted\".txt"}"#
+        );
+        assert_eq!(
+            format_json(0x1234, &params, None),
+            r#"{"algorithm":"CRC-32/ISCSI","width":32,"value":"00001234","input":null}"#
+        );
+    }
+
     #[test]
     fn test_benchmark_runner_creation() {
         let algorithm = CrcAlgorithm::from_str("CRC-32/ISCSI").unwrap();
@@ -608,7 +1718,13 @@ mod tests {
             algorithm: "CRC-32/ISCSI".to_string(),
             file: None,
             string: None,
+            inputs: Vec::new(),
+            check: None,
+            recursive: false,
+            posix_cksum: false,
             format: OutputFormat::Hex,
+            matrix: None,
+            forge: None,
             benchmark: Some(BenchmarkConfig {
                 size: Some(1024),
                 duration: 5.0,
@@ -627,8 +1743,14 @@ mod tests {
             algorithm: "CRC-32/ISCSI".to_string(),
             file: Some("test.txt".to_string()),
             string: None,
+            inputs: Vec::new(),
+            check: None,
+            recursive: false,
+            posix_cksum: false,
             format: OutputFormat::Hex,
             benchmark: None,
+            matrix: None,
+            forge: None,
         };
 
         assert!(config.benchmark.is_none());
@@ -642,7 +1764,13 @@ mod tests {
             algorithm: "CRC-32/ISCSI".to_string(),
             file: Some("test.txt".to_string()),
             string: None,
+            inputs: Vec::new(),
+            check: None,
+            recursive: false,
+            posix_cksum: false,
             format: OutputFormat::Hex,
+            matrix: None,
+            forge: None,
             benchmark: Some(BenchmarkConfig {
                 size: None,
                 duration: 1.0,
@@ -661,7 +1789,13 @@ mod tests {
             algorithm: "CRC-32/ISCSI".to_string(),
             file: None,
             string: Some("test data".to_string()),
+            inputs: Vec::new(),
+            check: None,
+            recursive: false,
+            posix_cksum: false,
             format: OutputFormat::Hex,
+            matrix: None,
+            forge: None,
             benchmark: Some(BenchmarkConfig {
                 size: None,
                 duration: 1.0,
@@ -678,7 +1812,13 @@ mod tests {
             algorithm: "CRC-32/ISCSI".to_string(),
             file: None,
             string: None,
+            inputs: Vec::new(),
+            check: None,
+            recursive: false,
+            posix_cksum: false,
             format: OutputFormat::Hex,
+            matrix: None,
+            forge: None,
             benchmark: Some(BenchmarkConfig {
                 size: Some(1024),
                 duration: 1.0,
@@ -729,4 +1869,62 @@ mod tests {
         assert_eq!(format_number_with_commas(123456789), "123,456,789");
         assert_eq!(format_number_with_commas(1000000000), "1,000,000,000");
     }
+
+    #[test]
+    fn test_sample_stats() {
+        let stats = SampleStats::new(&[4.0, 2.0, 8.0, 6.0]);
+
+        assert_eq!(stats.mean, 5.0);
+        assert_eq!(stats.median, 5.0);
+        assert_eq!(stats.min, 2.0);
+        assert_eq!(stats.max, 8.0);
+        assert!((stats.stddev - 5.0f64.sqrt()).abs() < 1e-12);
+
+        assert_eq!(SampleStats::new(&[3.0, 1.0, 2.0]).median, 2.0);
+    }
+
+    #[test]
+    fn test_matrix_config_defaults_and_validation() {
+        let matrix = MatrixConfig::new(None, None, None, 0.5, 0.1, 5, MatrixFormat::Table).unwrap();
+        assert_eq!(matrix.algorithms, CrcAlgorithm::PREDEFINED);
+        assert_eq!(matrix.sizes, vec![64, 1024, 65536, 1_048_576]);
+        assert_eq!(matrix.tiers[0], MatrixTier::Auto);
+        assert!(matrix
+            .tiers
+            .contains(&MatrixTier::Backend(PerformanceTier::SoftwareTable)));
+
+        let matrix = MatrixConfig::new(
+            Some("CRC-32/ISCSI,CRC-64/NVME"),
+            Some("1,4096"),
+            Some("auto,software-fallback-tables"),
+            0.5,
+            0.0,
+            1,
+            MatrixFormat::Csv,
+        )
+        .unwrap();
+        assert_eq!(
+            matrix.algorithms,
+            vec![CrcAlgorithm::Crc32Iscsi, CrcAlgorithm::Crc64Nvme]
+        );
+        assert_eq!(matrix.sizes, vec![1, 4096]);
+
+        let invalid = |algorithms, sizes, tiers, samples| {
+            MatrixConfig::new(
+                algorithms,
+                sizes,
+                tiers,
+                0.5,
+                0.1,
+                samples,
+                MatrixFormat::Table,
+            )
+            .is_err()
+        };
+        assert!(invalid(Some("CRC-32/NOPE"), None, None, 5));
+        assert!(invalid(None, Some("0"), None, 5));
+        assert!(invalid(None, Some("64,abc"), None, 5));
+        assert!(invalid(None, None, Some("turbo"), 5));
+        assert!(invalid(None, None, None, 0));
+    }
 }
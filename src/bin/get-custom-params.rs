@@ -2,9 +2,20 @@
 
 //! This is a simple program to get custom CRC parameters from the command line.
 
+use crc_fast::arch::software::{generate_table_u16, generate_table_u32, generate_table_u64};
+use crc_fast::{checksum_with_params, CrcParams};
 use std::env;
+use std::fmt::Write;
 use std::process::ExitCode;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Emit {
+    Rust,
+    C,
+    Json,
+    Reveng,
+}
+
 #[derive(Debug)]
 struct Config {
     width: Option<u32>,
@@ -14,6 +25,9 @@ struct Config {
     xorout: Option<u64>,
     check: Option<u64>,
     name: Option<String>,
+    emit: Option<Emit>,
+    /// The number of slice-by-N lookup table lanes to emit
+    tables: Option<usize>,
 }
 
 impl Config {
@@ -26,6 +40,8 @@ impl Config {
             xorout: None,
             check: None,
             name: None,
+            emit: None,
+            tables: None,
         }
     }
 
@@ -116,17 +132,306 @@ fn parse_args(args: &[String]) -> Result<Config, String> {
                 config.check = Some(parse_hex_or_decimal(&args[i + 1])?);
                 i += 2;
             }
+            "--emit" => {
+                if i + 1 >= args.len() {
+                    return Err("Missing value for --emit".to_string());
+                }
+                config.emit = Some(match args[i + 1].as_str() {
+                    "rust" => Emit::Rust,
+                    "c" => Emit::C,
+                    "json" => Emit::Json,
+                    "reveng" => Emit::Reveng,
+                    emit => {
+                        return Err(format!(
+                            "Invalid --emit value: {emit} (use rust, c, json or reveng)",
+                        ))
+                    }
+                });
+                i += 2;
+            }
+            "--tables" => {
+                if i + 1 >= args.len() {
+                    return Err("Missing value for --tables".to_string());
+                }
+                config.tables = Some(match args[i + 1].as_str() {
+                    "slice1" => 1,
+                    "slice4" => 4,
+                    "slice16" => 16,
+                    tables => {
+                        return Err(format!(
+                            "Invalid --tables value: {tables} (use slice1, slice4 or slice16)",
+                        ))
+                    }
+                });
+                i += 2;
+            }
             arg => {
                 return Err(format!("Unknown argument: {arg}",));
             }
         }
     }
 
+    if config.emit == Some(Emit::Reveng) && config.tables.is_some() {
+        return Err("--tables can't be used with --emit reveng".to_string());
+    }
+
+    if let Some(width) = config.width {
+        if width != 16 && width != 32 && width != 64 {
+            return Err(format!("Unsupported width: {width} (use 16, 32 or 64)",));
+        }
+    }
+
     Ok(config)
 }
 
+/// Converts a CRC name, such as CRC-32/ISCSI, into a constant identifier, such as CRC_32_ISCSI
+fn const_name(name: &str) -> String {
+    let ident: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{ident}")
+    } else {
+        ident
+    }
+}
+
+/// Formats a value as hex, zero-padded to the CRC width
+fn hex(params: &CrcParams, value: u64) -> String {
+    format!("0x{:0width$x}", value, width = params.width as usize / 4)
+}
+
+/// Calculates the residue, the register after reading an error-free codeword, without the final
+/// XOR
+fn residue(params: &CrcParams) -> u64 {
+    let check = checksum_with_params(*params, b"123456789");
+    let len = params.width as usize / 8;
+
+    // reflected CRCs are appended least significant byte first
+    let crc_bytes = if params.refout {
+        check.to_le_bytes()[..len].to_vec()
+    } else {
+        check.to_be_bytes()[8 - len..].to_vec()
+    };
+
+    let mut codeword = b"123456789".to_vec();
+    codeword.extend_from_slice(&crc_bytes);
+
+    checksum_with_params(*params, &codeword) ^ params.xorout
+}
+
+/// Generates the first `lanes` lanes of the slice-by-16 software lookup table
+fn lookup_table(params: &CrcParams, lanes: usize) -> Vec<Vec<u64>> {
+    let (width, poly, reflect) = (params.width, params.poly, params.refin);
+
+    let table: Vec<Vec<u64>> = match width {
+        16 => generate_table_u16(width, poly as u16, reflect)
+            .iter()
+            .map(|lane| lane.iter().map(|&v| v as u64).collect())
+            .collect(),
+        32 => generate_table_u32(width, poly as u32, reflect)
+            .iter()
+            .map(|lane| lane.iter().map(|&v| v as u64).collect())
+            .collect(),
+        _ => generate_table_u64(width, poly, reflect)
+            .iter()
+            .map(|lane| lane.to_vec())
+            .collect(),
+    };
+
+    table.into_iter().take(lanes).collect()
+}
+
+fn emit_rust(params: &CrcParams, tables: Option<&[Vec<u64>]>) -> String {
+    let ident = const_name(params.name);
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "use crc_fast::{{CrcAlgorithm, CrcKeysStorage, CrcParams}};"
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "// Generated CRC parameters for {}", params.name).unwrap();
+    writeln!(out, "pub const {ident}: CrcParams = CrcParams {{").unwrap();
+    writeln!(out, "    algorithm: CrcAlgorithm::CrcCustom,").unwrap();
+    writeln!(out, "    name: \"{}\",", params.name).unwrap();
+    writeln!(out, "    width: {},", params.width).unwrap();
+    writeln!(out, "    poly: {},", hex(params, params.poly)).unwrap();
+    writeln!(out, "    init: {},", hex(params, params.init)).unwrap();
+    writeln!(
+        out,
+        "    init_algorithm: {},",
+        hex(params, params.init_algorithm)
+    )
+    .unwrap();
+    writeln!(out, "    refin: {},", params.refin).unwrap();
+    writeln!(out, "    refout: {},", params.refout).unwrap();
+    writeln!(out, "    xorout: {},", hex(params, params.xorout)).unwrap();
+    writeln!(out, "    check: {},", hex(params, params.check)).unwrap();
+    writeln!(out, "    keys: CrcKeysStorage::KeysFold256([").unwrap();
+    for i in 0..23 {
+        writeln!(out, "        0x{:016x},", params.get_key(i)).unwrap();
+    }
+    writeln!(out, "    ]),").unwrap();
+    writeln!(out, "}};").unwrap();
+
+    if let Some(table) = tables {
+        let digits = params.width as usize / 4;
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "// Slice-by-{} lookup table for {}",
+            table.len(),
+            params.name
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "pub static {ident}_TABLE: [[u{}; 256]; {}] = [",
+            params.width,
+            table.len()
+        )
+        .unwrap();
+        for lane in table {
+            writeln!(out, "    [").unwrap();
+            for row in lane.chunks(8) {
+                let row: Vec<String> = row.iter().map(|v| format!("0x{v:0digits$x},")).collect();
+                writeln!(out, "        {}", row.join(" ")).unwrap();
+            }
+            writeln!(out, "    ],").unwrap();
+        }
+        writeln!(out, "];").unwrap();
+    }
+
+    out
+}
+
+fn emit_c(params: &CrcParams, tables: Option<&[Vec<u64>]>) -> String {
+    let ident = const_name(params.name);
+    let mut out = String::new();
+
+    writeln!(out, "#include \"libcrc_fast.h\"").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "/* Generated CRC parameters for {} */", params.name).unwrap();
+    writeln!(out, "static const uint64_t {ident}_KEYS[23] = {{").unwrap();
+    for i in 0..23 {
+        writeln!(out, "    0x{:016x}ULL,", params.get_key(i)).unwrap();
+    }
+    writeln!(out, "}};").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "static const CrcFastParams {ident} = {{").unwrap();
+    writeln!(out, "    .algorithm = CrcCustom,").unwrap();
+    writeln!(out, "    .width = {},", params.width).unwrap();
+    writeln!(out, "    .poly = {}ULL,", hex(params, params.poly)).unwrap();
+    writeln!(out, "    .init = {}ULL,", hex(params, params.init)).unwrap();
+    writeln!(out, "    .refin = {},", params.refin).unwrap();
+    writeln!(out, "    .refout = {},", params.refout).unwrap();
+    writeln!(out, "    .xorout = {}ULL,", hex(params, params.xorout)).unwrap();
+    writeln!(out, "    .check = {}ULL,", hex(params, params.check)).unwrap();
+    writeln!(out, "    .key_count = 23,").unwrap();
+    writeln!(out, "    .keys = {ident}_KEYS,").unwrap();
+    writeln!(out, "}};").unwrap();
+
+    if let Some(table) = tables {
+        let digits = params.width as usize / 4;
+        let suffix = if params.width == 64 { "ULL" } else { "" };
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "/* Slice-by-{} lookup table for {} */",
+            table.len(),
+            params.name
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "static const uint{}_t {ident}_TABLE[{}][256] = {{",
+            params.width,
+            table.len()
+        )
+        .unwrap();
+        for lane in table {
+            writeln!(out, "    {{").unwrap();
+            for row in lane.chunks(8) {
+                let row: Vec<String> = row
+                    .iter()
+                    .map(|v| format!("0x{v:0digits$x}{suffix},"))
+                    .collect();
+                writeln!(out, "        {}", row.join(" ")).unwrap();
+            }
+            writeln!(out, "    }},").unwrap();
+        }
+        writeln!(out, "}};").unwrap();
+    }
+
+    out
+}
+
+fn emit_json(params: &CrcParams, tables: Option<&[Vec<u64>]>) -> String {
+    let quoted = |value: u64| format!("\"{}\"", hex(params, value));
+    let keys: Vec<String> = (0..23)
+        .map(|i| format!("\"0x{:016x}\"", params.get_key(i)))
+        .collect();
+
+    let mut out = format!(
+        "{{\"name\":\"{}\",\"width\":{},\"poly\":{},\"init\":{},\"refin\":{},\"refout\":{},\"xorout\":{},\"check\":{},\"residue\":{},\"keys\":[{}]",
+        params.name.replace('\\', "\\\\").replace('"', "\\\""),
+        params.width,
+        quoted(params.poly),
+        quoted(params.init),
+        params.refin,
+        params.refout,
+        quoted(params.xorout),
+        quoted(params.check),
+        quoted(residue(params)),
+        keys.join(",")
+    );
+
+    if let Some(table) = tables {
+        let lanes: Vec<String> = table
+            .iter()
+            .map(|lane| {
+                let values: Vec<String> = lane.iter().map(|&v| quoted(v)).collect();
+                format!("[{}]", values.join(","))
+            })
+            .collect();
+        write!(out, ",\"tables\":[{}]", lanes.join(",")).unwrap();
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// Formats the parameters as a CRC RevEng catalogue line
+fn emit_reveng(params: &CrcParams) -> String {
+    format!(
+        "width={}  poly={}  init={}  refin={}  refout={}  xorout={}  check={}  residue={}  name=\"{}\"\n",
+        params.width,
+        hex(params, params.poly),
+        hex(params, params.init),
+        params.refin,
+        params.refout,
+        hex(params, params.xorout),
+        hex(params, params.check),
+        hex(params, residue(params)),
+        params.name
+    )
+}
+
 fn print_usage() {
     println!("Usage: get-custom-params -n <name> -w <width> -p <polynomial> -i <init> -r <reflected> -x <xorout> -c <check>");
+    println!(
+        "                         [--emit rust|c|json|reveng] [--tables slice1|slice4|slice16]"
+    );
     println!();
     println!("Example: get-custom-params -n CRC-32/ISCSI -w 32 -p 0x1edc6f41 -i 0xFFFFFFFF -r true -x 0xFFFFFFFF -c 0xe3069283");
     println!("Example: get-custom-params -n CRC-64/NVME -w 64 -p 0xad93d23594c93659 -i 0xffffffffffffffff -r true -x 0xffffffffffffffff -c 0xae8b14860a799888");
@@ -139,6 +444,15 @@ fn print_usage() {
     println!("  -r <reflected>  Reflected input/output (true/false)");
     println!("  -x <xorout>     XOR output value (hex or decimal)");
     println!("  -c <check>      Check value (hex or decimal)");
+    println!();
+    println!("Options:");
+    println!("  --emit <format> Generate a ready-to-paste Rust CrcParams const, C CrcFastParams");
+    println!("                  initializer, JSON object (including the residue) or CRC RevEng");
+    println!("                  catalogue line");
+    println!(
+        "  --tables <n>    Also generate the slice-by-1, 4 or 16 software lookup table, in the"
+    );
+    println!("                  --emit format (default: rust)");
 }
 
 fn main() -> ExitCode {
@@ -179,6 +493,25 @@ fn main() -> ExitCode {
         config.check.unwrap(),
     );
 
+    let tables = config.tables.map(|lanes| lookup_table(&params, lanes));
+
+    match (config.emit, tables) {
+        (Some(Emit::Rust), tables) | (None, tables @ Some(_)) => {
+            print!("{}", emit_rust(&params, tables.as_deref()))
+        }
+        (Some(Emit::C), tables) => print!("{}", emit_c(&params, tables.as_deref())),
+        (Some(Emit::Json), tables) => print!("{}", emit_json(&params, tables.as_deref())),
+        (Some(Emit::Reveng), _) => print!("{}", emit_reveng(&params)),
+        (None, None) => print_params(&params),
+    }
+
+    ExitCode::from(0)
+}
+
+/// Prints the parameters and keys in a human-readable form
+fn print_params(params: &CrcParams) {
+    let static_name = params.name;
+
     println!();
     println!("// Generated CRC parameters for {static_name}",);
     println!(
@@ -190,20 +523,16 @@ fn main() -> ExitCode {
     );
     println!(
         "    algorithm: CrcAlgorithm::{}Custom,",
-        if config.width.unwrap() == 32 {
-            "Crc32"
-        } else {
-            "Crc64"
-        }
+        if params.width == 32 { "Crc32" } else { "Crc64" }
     );
     println!("    name: \"{static_name}\",",);
-    println!("    width: {},", config.width.unwrap());
-    println!("    poly: 0x{:x},", config.polynomial.unwrap());
-    println!("    init: 0x{:x},", config.init.unwrap());
-    println!("    refin: {},", config.reflected.unwrap());
-    println!("    refout: {},", config.reflected.unwrap());
-    println!("    xorout: 0x{:x},", config.xorout.unwrap());
-    println!("    check: 0x{:x},", config.check.unwrap());
+    println!("    width: {},", params.width);
+    println!("    poly: 0x{:x},", params.poly);
+    println!("    init: 0x{:x},", params.init);
+    println!("    refin: {},", params.refin);
+    println!("    refout: {},", params.refout);
+    println!("    xorout: 0x{:x},", params.xorout);
+    println!("    check: 0x{:x},", params.check);
     println!("    keys: CrcKeysStorage::from_keys_fold_256([");
 
     // Print the keys array
@@ -215,6 +544,112 @@ fn main() -> ExitCode {
     println!("    ]),");
     println!("}};");
     println!();
+}
 
-    ExitCode::from(0)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crc32_iscsi() -> CrcParams {
+        CrcParams::new(
+            "CRC-32/ISCSI",
+            32,
+            0x1edc6f41,
+            0xffffffff,
+            true,
+            0xffffffff,
+            0xe3069283,
+        )
+    }
+
+    #[test]
+    fn test_const_name() {
+        assert_eq!(const_name("CRC-32/ISCSI"), "CRC_32_ISCSI");
+        assert_eq!(const_name("crc-16/my dev"), "CRC_16_MY_DEV");
+        assert_eq!(const_name("16-BIT"), "_16_BIT");
+    }
+
+    #[test]
+    fn test_residue_matches_catalogue() {
+        assert_eq!(residue(&crc32_iscsi()), 0xb798b438);
+
+        let genibus = CrcParams::new("CRC-16/GENIBUS", 16, 0x1021, 0xffff, false, 0xffff, 0xd64e);
+        assert_eq!(residue(&genibus), 0x1d0f);
+    }
+
+    #[test]
+    fn test_lookup_table_lanes() {
+        let params = crc32_iscsi();
+
+        assert_eq!(lookup_table(&params, 1).len(), 1);
+        assert_eq!(lookup_table(&params, 16).len(), 16);
+
+        // slice-by-1 lookup of "123456789"
+        let table = lookup_table(&params, 1);
+        let crc = b"123456789".iter().fold(0xffffffffu64, |crc, &b| {
+            (crc >> 8) ^ table[0][((crc ^ b as u64) & 0xff) as usize]
+        });
+        assert_eq!(crc ^ 0xffffffff, 0xe3069283);
+    }
+
+    #[test]
+    fn test_emit_formats() {
+        let params = crc32_iscsi();
+
+        let rust = emit_rust(&params, None);
+        assert!(rust.contains("pub const CRC_32_ISCSI: CrcParams = CrcParams {"));
+        assert!(rust.contains("    init_algorithm: 0xffffffff,"));
+        assert!(rust.contains("    keys: CrcKeysStorage::KeysFold256(["));
+
+        let c = emit_c(&params, Some(&lookup_table(&params, 4)));
+        assert!(c.contains("static const uint64_t CRC_32_ISCSI_KEYS[23] = {"));
+        assert!(c.contains("    .keys = CRC_32_ISCSI_KEYS,"));
+        assert!(c.contains("static const uint32_t CRC_32_ISCSI_TABLE[4][256] = {"));
+
+        let json = emit_json(&params, None);
+        assert!(json.starts_with("{\"name\":\"CRC-32/ISCSI\",\"width\":32,"));
+        assert!(json.contains("\"residue\":\"0xb798b438\""));
+
+        assert_eq!(
+            emit_reveng(&params),
+            "width=32  poly=0x1edc6f41  init=0xffffffff  refin=true  refout=true  xorout=0xffffffff  check=0xe3069283  residue=0xb798b438  name=\"CRC-32/ISCSI\"\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_args_rejects_invalid_options() {
+        let args = |extra: &[&str]| {
+            let mut args: Vec<String> = [
+                "get-custom-params",
+                "-n",
+                "CRC-32/ISCSI",
+                "-w",
+                "32",
+                "-p",
+                "0x1edc6f41",
+                "-i",
+                "0xffffffff",
+                "-r",
+                "true",
+                "-x",
+                "0xffffffff",
+                "-c",
+                "0xe3069283",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+            args.extend(extra.iter().map(|s| s.to_string()));
+            args
+        };
+
+        let config = parse_args(&args(&["--emit", "c", "--tables", "slice4"])).unwrap();
+        assert_eq!(config.emit, Some(Emit::C));
+        assert_eq!(config.tables, Some(4));
+
+        assert!(parse_args(&args(&["--emit", "go"])).is_err());
+        assert!(parse_args(&args(&["--tables", "slice8"])).is_err());
+        assert!(parse_args(&args(&["--emit", "reveng", "--tables", "slice1"])).is_err());
+        assert!(parse_args(&args(&["-w", "24"])).is_err());
+    }
 }
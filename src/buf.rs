@@ -0,0 +1,204 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Integration with the [`bytes`](https://docs.rs/bytes) crate's `Buf` and `BufMut` traits.
+//!
+//! Network stacks often hold a message as a chain of non-contiguous buffers. Walking
+//! [`Buf::chunk`] feeds each segment straight into the [`Digest`], so nothing is copied into a
+//! contiguous buffer first and the CRC state simply carries over from one segment to the next.
+
+#![cfg(feature = "bytes")]
+
+use crate::{checksum_to_be_bytes_with_params, ChecksumBytes, CrcParams, Digest};
+use bytes::{Buf, BufMut};
+
+impl Digest {
+    /// Updates the CRC state with all remaining bytes in `buf`, advancing it to the end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytes::{Buf, Bytes};
+    /// use crc_fast::{Digest, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let mut message = Bytes::from_static(b"1234").chain(Bytes::from_static(b"56789"));
+    ///
+    /// let mut digest = Digest::new(Crc32IsoHdlc);
+    /// digest.update_buf(&mut message);
+    ///
+    /// assert_eq!(digest.finalize(), 0xcbf43926);
+    /// assert!(!message.has_remaining());
+    /// ```
+    pub fn update_buf<B: Buf + ?Sized>(&mut self, buf: &mut B) {
+        while buf.has_remaining() {
+            let chunk = buf.chunk();
+            let len = chunk.len();
+            self.update(chunk);
+            buf.advance(len);
+        }
+    }
+}
+
+/// Extension trait for appending CRC checksums to a [`BufMut`].
+///
+/// Checksums are written in wire byte order: least-significant byte first for reflected
+/// algorithms and most-significant byte first otherwise, using exactly `width / 8` bytes. This
+/// is the order which makes the CRC of a message with its checksum appended equal to the
+/// algorithm's constant residue, so receivers can verify the whole frame in one pass.
+///
+/// For reflected algorithms, such as CRC-32/ISO-HDLC, this is the reverse of the big-endian
+/// bytes from [`checksum_to_be_bytes`](crate::checksum_to_be_bytes), which protocols and object
+/// stores use to exchange checksums outside the frame.
+pub trait CrcBufMutExt: BufMut {
+    /// Appends the finalized checksum of `digest` in wire byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytes::BytesMut;
+    /// use crc_fast::{CrcBufMutExt, Digest, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let mut digest = Digest::new(Crc32IsoHdlc);
+    /// digest.update(b"123456789");
+    ///
+    /// let mut frame = BytesMut::from(&b"123456789"[..]);
+    /// frame.put_crc(&digest);
+    ///
+    /// assert_eq!(&frame[9..], &0xcbf43926u32.to_le_bytes());
+    /// ```
+    fn put_crc(&mut self, digest: &Digest) {
+        self.put_crc_with_params(&digest.params, digest.finalize());
+    }
+
+    /// Appends a finalized `checksum` computed with `params` in wire byte order.
+    fn put_crc_with_params(&mut self, params: &CrcParams, checksum: u64) {
+        self.put_slice(&wire_bytes(params, checksum));
+    }
+}
+
+impl<B: BufMut + ?Sized> CrcBufMutExt for B {}
+
+/// Returns the checksum's bytes in wire order, which for the reflected algorithms is the reverse
+/// of [`checksum_to_be_bytes_with_params`].
+#[inline(always)]
+fn wire_bytes(params: &CrcParams, checksum: u64) -> ChecksumBytes {
+    if params.refout {
+        ChecksumBytes::from_le_u64(checksum, params.width as usize / 8)
+    } else {
+        checksum_to_be_bytes_with_params(params, checksum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use bytes::{Bytes, BytesMut};
+    use std::collections::VecDeque;
+
+    fn test_data() -> Vec<u8> {
+        (0..4099).map(|i| (i * 7 % 256) as u8).collect()
+    }
+
+    #[test]
+    fn test_update_buf_chained() {
+        let data = test_data();
+
+        for config in TEST_ALL_CONFIGS {
+            let expected = config.checksum_with_reference(&data);
+
+            // uneven segment sizes cross every SIMD block boundary
+            for split in [0, 1, 15, 16, 63, 64, 255, 256, 1000, data.len()] {
+                let (a, b) = data.split_at(split);
+                let mut chain = Bytes::copy_from_slice(a)
+                    .chain(Bytes::copy_from_slice(&b[..b.len() / 2]))
+                    .chain(Bytes::copy_from_slice(&b[b.len() / 2..]));
+
+                let mut digest = Digest::new(config.get_algorithm());
+                digest.update_buf(&mut chain);
+
+                assert_eq!(digest.finalize(), expected, "split at {split}");
+                assert_eq!(digest.get_amount(), data.len() as u64);
+                assert!(!chain.has_remaining());
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_buf_segmented() {
+        let data = test_data();
+
+        // a VecDeque which has wrapped around exposes its contents as two separate chunks
+        let mut deque: VecDeque<u8> = VecDeque::with_capacity(data.len());
+        deque.extend(std::iter::repeat_n(0, 100));
+        while deque.pop_front().is_some() {}
+        deque.extend(&data);
+        assert_ne!(deque.chunk().len(), data.len());
+
+        for config in TEST_ALL_CONFIGS {
+            let mut segmented = deque.clone();
+            let mut digest = Digest::new(config.get_algorithm());
+            digest.update_buf(&mut segmented);
+
+            assert_eq!(digest.finalize(), config.checksum_with_reference(&data));
+        }
+    }
+
+    #[test]
+    fn test_put_crc_residue() {
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+
+            // appending in wire order makes the checksum of the whole frame a constant
+            let residues: Vec<u64> = [&b""[..], b"123456789", &test_data()]
+                .iter()
+                .map(|message| {
+                    let mut digest = Digest::new(algorithm);
+                    digest.update(message);
+
+                    let mut frame = BytesMut::from(*message);
+                    frame.put_crc(&digest);
+                    assert_eq!(
+                        frame.len(),
+                        message.len() + config.get_params().width as usize / 8
+                    );
+
+                    crate::checksum(algorithm, &frame)
+                })
+                .collect();
+
+            assert!(
+                residues.windows(2).all(|pair| pair[0] == pair[1]),
+                "{:?} residues differ: {residues:x?}",
+                algorithm
+            );
+        }
+    }
+
+    #[test]
+    fn test_put_crc_byte_order() {
+        #[cfg(feature = "crc16")]
+        {
+            let mut reflected = Vec::new();
+            let mut digest = Digest::new(crate::CrcAlgorithm::Crc16Arc);
+            digest.update(b"123456789");
+            reflected.put_crc(&digest);
+            assert_eq!(reflected, 0xbb3du16.to_le_bytes());
+        }
+
+        #[cfg(feature = "crc32")]
+        {
+            let mut forward = Vec::new();
+            let mut digest = Digest::new(crate::CrcAlgorithm::Crc32Bzip2);
+            digest.update(b"123456789");
+            forward.put_crc(&digest);
+            assert_eq!(forward, 0xfc891918u32.to_be_bytes());
+        }
+
+        #[cfg(feature = "crc64")]
+        {
+            let mut forward = Vec::new();
+            forward.put_crc_with_params(&crate::crc64::consts::CRC64_ECMA_182, 0x6c40df5f0b497347);
+            assert_eq!(forward, 0x6c40df5f0b497347u64.to_be_bytes());
+        }
+    }
+}
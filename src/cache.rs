@@ -1,24 +1,44 @@
 // Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
 
-//! CRC parameter caching system
+//! Caching for custom CRC parameters.
 //!
-//! This module provides a thread-safe cache for CRC folding keys to avoid expensive
-//! regeneration when the same CRC parameters are used multiple times. The cache uses
-//! a read-write lock pattern optimized for the common case of cache hits.
+//! Custom parameters need folding keys for the SIMD paths, generated by [CrcParams::new], and
+//! lookup tables for the software path, generated on first use, along with a search for the
+//! sparse polynomial multiple the software path folds long inputs with. All three are cached so
+//! that repeated use of the same parameters doesn't regenerate them.
+//!
+//! Each cache holds at most [capacity] entries, evicting the least recently used entry beyond
+//! that, so services accepting arbitrary user-defined parameters stay bounded. The caches can be
+//! observed with [stats], filled ahead of time with [prewarm], emptied with [clear], and turned
+//! off entirely with [set_enabled].
 //!
 //! # Performance Characteristics
 //!
 //! - Cache hits: ~50-100x faster than key generation
 //! - Cache misses: ~100-200ns overhead compared to direct generation
-//! - Memory usage: ~200 bytes per unique parameter set
+//! - Memory usage: ~200 bytes per set of keys, and up to 32 KiB per software table
 //! - Thread safety: Multiple concurrent readers, exclusive writers
 //!
-//! # Usage
+//! Caching requires the `std` or `cache` feature. Without either, keys are generated every time,
+//! the software path calculates custom CRCs bit by bit rather than generating a table for every
+//! update, and these functions have no effect.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crc_fast::{cache, CrcParams};
 //!
-//! The cache is used automatically by `CrcParams::new()` and requires no manual management.
-//! The cache is transparent to users and handles all memory management internally.
+//! cache::set_capacity(16);
+//!
+//! let params = CrcParams::new("CRC-32/CUSTOM", 32, 0x1edc6f41, 0xffffffff, true, 0xffffffff, 0xe3069283);
+//! cache::prewarm(&params);
+//!
+//! let stats = cache::stats();
+//! assert!(stats.key_entries <= 16);
+//! ```
 
 use crate::generate;
+use crate::{CrcAlgorithm, CrcParams};
 
 #[cfg(feature = "std")]
 use std::collections::HashMap;
@@ -30,15 +50,283 @@ use hashbrown::HashMap;
 #[cfg(all(not(feature = "std"), feature = "cache"))]
 use spin::{Once, RwLock};
 
+#[cfg(any(feature = "std", feature = "cache"))]
+use core::hash::Hash;
+#[cfg(any(feature = "std", feature = "cache"))]
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// The number of entries each cache holds unless changed with [set_capacity].
+pub const DEFAULT_CAPACITY: usize = 256;
+
+#[cfg(any(feature = "std", feature = "cache"))]
+static CAPACITY: AtomicUsize = AtomicUsize::new(DEFAULT_CAPACITY);
+
+#[cfg(any(feature = "std", feature = "cache"))]
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+#[cfg(any(feature = "std", feature = "cache"))]
+static HITS: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(any(feature = "std", feature = "cache"))]
+static MISSES: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(any(feature = "std", feature = "cache"))]
+static EVICTIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Logical clock for recency, shared by every cache so a single tick orders all uses.
+#[cfg(any(feature = "std", feature = "cache"))]
+static CLOCK: AtomicUsize = AtomicUsize::new(0);
+
 /// Global cache storage for CRC parameter keys
 ///
 /// Uses OnceLock for thread-safe lazy initialization and RwLock for concurrent access.
 /// The cache maps parameter combinations to their pre-computed folding keys.
 #[cfg(feature = "std")]
-static CACHE: OnceLock<RwLock<HashMap<CrcParamsCacheKey, [u64; 23]>>> = OnceLock::new();
+static CACHE: OnceLock<RwLock<Lru<CrcParamsCacheKey, [u64; 23]>>> = OnceLock::new();
 
 #[cfg(all(not(feature = "std"), feature = "cache"))]
-static CACHE: Once<RwLock<HashMap<CrcParamsCacheKey, [u64; 23]>>> = Once::new();
+static CACHE: Once<RwLock<Lru<CrcParamsCacheKey, [u64; 23]>>> = Once::new();
+
+/// A snapshot of the cache counters and sizes, as returned by [stats].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Lookups served from a cache, since the program started
+    pub hits: u64,
+    /// Lookups that had to generate keys or a table, since the program started
+    pub misses: u64,
+    /// Entries removed to stay within the capacity, since the program started
+    pub evictions: u64,
+    /// Sets of folding keys currently cached
+    pub key_entries: usize,
+    /// Software lookup tables currently cached, across all widths
+    pub table_entries: usize,
+    /// Software fold plans currently cached, including searches which found no plan
+    pub plan_entries: usize,
+    /// The maximum number of entries in each cache
+    pub capacity: usize,
+    /// Whether caching is enabled
+    pub enabled: bool,
+}
+
+/// Gets the maximum number of entries in each cache.
+///
+/// The folding key cache and the software table cache for each width are bounded separately.
+pub fn capacity() -> usize {
+    #[cfg(any(feature = "std", feature = "cache"))]
+    {
+        CAPACITY.load(Ordering::Relaxed)
+    }
+
+    #[cfg(not(any(feature = "std", feature = "cache")))]
+    {
+        0
+    }
+}
+
+/// Sets the maximum number of entries in each cache, evicting the least recently used entries
+/// of any cache which is now over capacity.
+///
+/// A capacity of zero caches nothing, but unlike [set_enabled] still counts hits and misses.
+pub fn set_capacity(capacity: usize) {
+    #[cfg(any(feature = "std", feature = "cache"))]
+    {
+        CAPACITY.store(capacity, Ordering::Relaxed);
+
+        with_keys_mut(|keys| keys.shrink_to(capacity));
+
+        #[cfg(feature = "alloc")]
+        {
+            crate::arch::software::shrink_table_caches(capacity);
+            crate::arch::chorba::shrink_plan_cache(capacity);
+        }
+    }
+
+    #[cfg(not(any(feature = "std", feature = "cache")))]
+    let _ = capacity;
+}
+
+/// Returns whether caching is enabled, which it is by default when the `std` or `cache` feature
+/// is.
+pub fn is_enabled() -> bool {
+    #[cfg(any(feature = "std", feature = "cache"))]
+    {
+        ENABLED.load(Ordering::Relaxed)
+    }
+
+    #[cfg(not(any(feature = "std", feature = "cache")))]
+    {
+        false
+    }
+}
+
+/// Enables or disables caching. Disabling also clears the caches, and while disabled, keys are
+/// generated on every use, and the software path calculates custom CRCs bit by bit, without
+/// touching the caches or their counters.
+pub fn set_enabled(enabled: bool) {
+    #[cfg(any(feature = "std", feature = "cache"))]
+    {
+        ENABLED.store(enabled, Ordering::Relaxed);
+
+        if !enabled {
+            clear();
+        }
+    }
+
+    #[cfg(not(any(feature = "std", feature = "cache")))]
+    let _ = enabled;
+}
+
+/// Gets the current cache counters and sizes.
+pub fn stats() -> CacheStats {
+    #[cfg(any(feature = "std", feature = "cache"))]
+    {
+        #[cfg(feature = "alloc")]
+        let (table_entries, plan_entries) = (
+            crate::arch::software::table_cache_len(),
+            crate::arch::chorba::plan_cache_len(),
+        );
+        #[cfg(not(feature = "alloc"))]
+        let (table_entries, plan_entries) = (0, 0);
+
+        CacheStats {
+            hits: HITS.load(Ordering::Relaxed) as u64,
+            misses: MISSES.load(Ordering::Relaxed) as u64,
+            evictions: EVICTIONS.load(Ordering::Relaxed) as u64,
+            key_entries: with_keys(|keys| keys.len()),
+            table_entries,
+            plan_entries,
+            capacity: capacity(),
+            enabled: is_enabled(),
+        }
+    }
+
+    #[cfg(not(any(feature = "std", feature = "cache")))]
+    {
+        CacheStats::default()
+    }
+}
+
+/// Caches everything needed for the given parameters ahead of first use.
+///
+/// The folding keys are always cached, and the software lookup table is too when the software
+/// backend is active. Predefined algorithms carry their own keys and tables, so there's nothing
+/// to cache for them.
+pub fn prewarm(params: &CrcParams) {
+    #[allow(deprecated)]
+    let custom = matches!(
+        params.algorithm,
+        CrcAlgorithm::CrcCustom | CrcAlgorithm::Crc32Custom | CrcAlgorithm::Crc64Custom
+    );
+
+    if !custom {
+        return;
+    }
+
+    get_or_generate_keys(params.width, params.poly, params.refin);
+
+    #[cfg(feature = "alloc")]
+    if crate::feature_detection::get_active_tier() == crate::PerformanceTier::SoftwareTable {
+        crate::arch::software::prewarm_table(params);
+    }
+}
+
+/// Removes every cached set of keys, software table and fold plan.
+///
+/// The counters in [stats] are left running. Uses best-effort error handling - lock poisoning
+/// doesn't cause a panic, and a poisoned cache is cleared regardless.
+///
+/// # Thread Safety
+///
+/// This function is thread-safe and can be called concurrently with other cache operations.
+/// However, clearing the cache while other threads are actively using it may temporarily
+/// reduce performance as those threads will need to regenerate keys on their next access.
+pub fn clear() {
+    #[cfg(any(feature = "std", feature = "cache"))]
+    {
+        with_keys_mut(|keys| keys.clear());
+
+        #[cfg(feature = "alloc")]
+        {
+            crate::arch::software::clear_table_caches();
+            crate::arch::chorba::clear_plan_cache();
+        }
+    }
+}
+
+/// A map which evicts its least recently used entries beyond the configured capacity, and
+/// counts its hits, misses and evictions towards [stats].
+///
+/// Lookups only need a shared reference, so the key cache can keep serving concurrent readers.
+#[cfg(any(feature = "std", feature = "cache"))]
+pub(crate) struct Lru<K, V> {
+    entries: HashMap<K, (V, AtomicUsize)>,
+}
+
+#[cfg(any(feature = "std", feature = "cache"))]
+impl<K: Hash + Eq + Clone, V: Clone> Lru<K, V> {
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Looks up a value, marking it as the most recently used.
+    pub(crate) fn get(&self, key: &K) -> Option<V> {
+        match self.entries.get(key) {
+            Some((value, last_used)) => {
+                last_used.store(tick(), Ordering::Relaxed);
+                HITS.fetch_add(1, Ordering::Relaxed);
+                Some(value.clone())
+            }
+            None => {
+                MISSES.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Inserts a value as the most recently used, then evicts down to `capacity`.
+    pub(crate) fn insert(&mut self, key: K, value: V, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+
+        self.entries.insert(key, (value, AtomicUsize::new(tick())));
+        self.shrink_to(capacity);
+    }
+
+    /// Evicts the least recently used entries until at most `capacity` remain.
+    pub(crate) fn shrink_to(&mut self, capacity: usize) {
+        while self.entries.len() > capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| last_used.load(Ordering::Relaxed))
+                .map(|(key, _)| key.clone());
+
+            match oldest {
+                Some(key) => {
+                    self.entries.remove(&key);
+                    EVICTIONS.fetch_add(1, Ordering::Relaxed);
+                }
+                None => break,
+            }
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(any(feature = "std", feature = "cache"))]
+fn tick() -> usize {
+    CLOCK.fetch_add(1, Ordering::Relaxed)
+}
 
 /// Cache key for storing CRC parameters that affect key generation
 ///
@@ -82,13 +370,38 @@ impl CrcParamsCacheKey {
 /// Uses OnceLock to ensure thread-safe lazy initialization without requiring
 /// static initialization overhead. The cache is only created when first accessed.
 #[cfg(feature = "std")]
-fn get_cache() -> &'static RwLock<HashMap<CrcParamsCacheKey, [u64; 23]>> {
-    CACHE.get_or_init(|| RwLock::new(HashMap::new()))
+fn get_cache() -> &'static RwLock<Lru<CrcParamsCacheKey, [u64; 23]>> {
+    CACHE.get_or_init(|| RwLock::new(Lru::new()))
 }
 
 #[cfg(all(not(feature = "std"), feature = "cache"))]
-fn get_cache() -> &'static RwLock<HashMap<CrcParamsCacheKey, [u64; 23]>> {
-    CACHE.call_once(|| RwLock::new(HashMap::new()))
+fn get_cache() -> &'static RwLock<Lru<CrcParamsCacheKey, [u64; 23]>> {
+    CACHE.call_once(|| RwLock::new(Lru::new()))
+}
+
+/// Runs `f` with shared access to the key cache, recovering from lock poisoning.
+#[cfg(any(feature = "std", feature = "cache"))]
+fn with_keys<R>(f: impl FnOnce(&Lru<CrcParamsCacheKey, [u64; 23]>) -> R) -> R {
+    #[cfg(feature = "std")]
+    let keys = get_cache().read().unwrap_or_else(|e| e.into_inner());
+
+    // spin::RwLock returns guards directly (no Result wrapper)
+    #[cfg(not(feature = "std"))]
+    let keys = get_cache().read();
+
+    f(&keys)
+}
+
+/// Runs `f` with exclusive access to the key cache, recovering from lock poisoning.
+#[cfg(any(feature = "std", feature = "cache"))]
+fn with_keys_mut<R>(f: impl FnOnce(&mut Lru<CrcParamsCacheKey, [u64; 23]>) -> R) -> R {
+    #[cfg(feature = "std")]
+    let mut keys = get_cache().write().unwrap_or_else(|e| e.into_inner());
+
+    #[cfg(not(feature = "std"))]
+    let mut keys = get_cache().write();
+
+    f(&mut keys)
 }
 
 /// Get cached keys or generate and cache them if not present
@@ -103,8 +416,7 @@ fn get_cache() -> &'static RwLock<HashMap<CrcParamsCacheKey, [u64; 23]>> {
 /// The key generation happens outside the write lock because it's computationally expensive
 /// (~1000x slower than cache lookup) and we want to minimize the time other threads are blocked.
 ///
-/// All cache operations use best-effort error handling - lock poisoning or allocation failures
-/// don't cause panics, instead falling back to direct key generation to maintain functionality.
+/// Lock poisoning doesn't cause panics, since the cache only ever holds complete entries.
 ///
 /// # Arguments
 ///
@@ -115,52 +427,24 @@ fn get_cache() -> &'static RwLock<HashMap<CrcParamsCacheKey, [u64; 23]>> {
 /// # Returns
 ///
 /// Array of 23 pre-computed folding keys for SIMD CRC calculation
-pub fn get_or_generate_keys(width: u8, poly: u64, reflected: bool) -> [u64; 23] {
-    #[cfg(feature = "std")]
+pub(crate) fn get_or_generate_keys(width: u8, poly: u64, reflected: bool) -> [u64; 23] {
+    #[cfg(any(feature = "std", feature = "cache"))]
     {
-        let cache_key = CrcParamsCacheKey::new(width, poly, reflected);
-
-        // Try cache read first - multiple threads can read simultaneously
-        // If lock is poisoned or read fails, continue to key generation
-        if let Ok(cache) = get_cache().read() {
-            if let Some(keys) = cache.get(&cache_key) {
-                return *keys;
-            }
+        if !is_enabled() {
+            return generate::keys(width, poly, reflected);
         }
 
-        // Generate keys outside of write lock to minimize lock hold time
-        let keys = generate::keys(width, poly, reflected);
-
-        // Try to cache the result (best effort - if this fails, we still return valid keys)
-        // Lock poisoning or write failure doesn't affect functionality
-        let _ = get_cache()
-            .write()
-            .map(|mut cache| cache.insert(cache_key, keys));
-
-        keys
-    }
-
-    #[cfg(all(not(feature = "std"), feature = "cache"))]
-    {
         let cache_key = CrcParamsCacheKey::new(width, poly, reflected);
 
         // Try cache read first - multiple threads can read simultaneously
-        // spin::RwLock returns guards directly (no Result wrapper)
-        {
-            let cache = get_cache().read();
-            if let Some(keys) = cache.get(&cache_key) {
-                return *keys;
-            }
-        } // Drop read lock before generating keys
+        if let Some(keys) = with_keys(|cache| cache.get(&cache_key)) {
+            return keys;
+        }
 
         // Generate keys outside of write lock to minimize lock hold time
         let keys = generate::keys(width, poly, reflected);
 
-        // Cache the result - spin::RwLock doesn't use Result wrapper
-        {
-            let mut cache = get_cache().write();
-            cache.insert(cache_key, keys);
-        }
+        with_keys_mut(|cache| cache.insert(cache_key, keys, capacity()));
 
         keys
     }
@@ -171,37 +455,6 @@ pub fn get_or_generate_keys(width: u8, poly: u64, reflected: bool) -> [u64; 23]
     }
 }
 
-/// Clear all cached CRC parameter keys
-///
-/// This function is primarily intended for testing scenarios where you need to reset
-/// the cache state to ensure test isolation.
-///
-/// Uses best-effort error handling - lock poisoning or other failures don't cause
-/// panics, ensuring this function never disrupts program execution. If the cache
-/// cannot be cleared, the function silently continues without error.
-///
-/// # Thread Safety
-///
-/// This function is thread-safe and can be called concurrently with other cache operations.
-/// However, clearing the cache while other threads are actively using it may temporarily
-/// reduce performance as those threads will need to regenerate keys on their next access.
-#[cfg(test)]
-pub(crate) fn clear_cache() {
-    #[cfg(feature = "std")]
-    {
-        // Best-effort cache clear - if lock is poisoned or unavailable, silently continue
-        // This ensures the function never panics or blocks program execution
-        let _ = get_cache().write().map(|mut cache| cache.clear());
-    }
-
-    #[cfg(all(not(feature = "std"), feature = "cache"))]
-    {
-        // spin::RwLock doesn't use Result wrapper
-        let mut cache = get_cache().write();
-        cache.clear();
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -269,7 +522,7 @@ mod tests {
 
     #[test]
     fn test_cache_hit_scenarios() {
-        clear_cache();
+        clear();
 
         // First call should be a cache miss and generate keys
         let keys1 = get_or_generate_keys(32, 0x04C11DB7, true);
@@ -292,7 +545,7 @@ mod tests {
 
     #[test]
     fn test_cache_miss_scenarios() {
-        clear_cache();
+        clear();
 
         // Different width - should be cache miss
         let keys_32 = get_or_generate_keys(32, 0x04C11DB7, true);
@@ -327,7 +580,7 @@ mod tests {
 
     #[test]
     fn test_cached_keys_identical_to_generated_keys() {
-        clear_cache();
+        clear();
 
         // Test CRC32 parameters
         let width = 32;
@@ -368,7 +621,7 @@ mod tests {
 
     #[test]
     fn test_multiple_parameter_combinations() {
-        clear_cache();
+        clear();
 
         // Test various common CRC parameter combinations
         let test_cases = [
@@ -413,7 +666,7 @@ mod tests {
     #[test]
     fn test_cache_management_utilities() {
         // Clear cache to start with clean state
-        clear_cache();
+        clear();
 
         // Generate and cache some keys
         let keys1 = get_or_generate_keys(32, 0x04C11DB7, true);
@@ -427,7 +680,7 @@ mod tests {
         assert_eq!(keys2, cached_keys2);
 
         // Clear cache
-        clear_cache();
+        clear();
 
         // Verify cache was cleared by checking that new calls still work
         // (we can't directly verify cache is empty, but we can verify functionality)
@@ -438,12 +691,12 @@ mod tests {
     #[test]
     fn test_cache_error_handling() {
         // Test that cache operations don't panic even if called multiple times
-        clear_cache();
-        clear_cache(); // Should not panic on empty cache
+        clear();
+        clear(); // Should not panic on empty cache
 
         // Test that get_or_generate_keys works even after multiple clears
         let keys = get_or_generate_keys(32, 0x04C11DB7, true);
-        clear_cache();
+        clear();
         let keys2 = get_or_generate_keys(32, 0x04C11DB7, true);
 
         // Keys should be identical (same parameters produce same keys)
@@ -475,7 +728,7 @@ mod tests {
         use std::sync::{Arc, Barrier};
         use std::thread;
 
-        clear_cache();
+        clear();
 
         // Pre-populate cache with a known value
         let expected_keys = get_or_generate_keys(32, 0x04C11DB7, true);
@@ -521,7 +774,7 @@ mod tests {
         use std::sync::{Arc, Barrier};
         use std::thread;
 
-        clear_cache();
+        clear();
 
         let num_threads = 6;
         let barrier = Arc::new(Barrier::new(num_threads));
@@ -587,7 +840,7 @@ mod tests {
         use std::thread;
         use std::time::Duration;
 
-        clear_cache();
+        clear();
 
         // Pre-populate cache with some values
         let _keys1 = get_or_generate_keys(32, 0x04C11DB7, true);
@@ -681,7 +934,7 @@ mod tests {
         use std::sync::{Arc, Barrier};
         use std::thread;
 
-        clear_cache();
+        clear();
 
         let num_threads = 10;
         let barrier = Arc::new(Barrier::new(num_threads));
@@ -741,7 +994,7 @@ mod tests {
         use std::thread;
         use std::time::Duration;
 
-        clear_cache();
+        clear();
 
         let num_threads = 8;
         let barrier = Arc::new(Barrier::new(num_threads));
@@ -776,7 +1029,7 @@ mod tests {
                         3 => {
                             // Occasional cache clear (but not too often to avoid disrupting other tests)
                             if operations % 10 == 0 {
-                                clear_cache();
+                                clear();
                             }
                             let _keys = get_or_generate_keys(32, 0x04C11DB7, true);
                         }
@@ -823,7 +1076,7 @@ mod tests {
         use std::sync::{Arc, Mutex};
         use std::thread;
 
-        clear_cache();
+        clear();
 
         // Pre-populate cache with known values
         let expected_keys = get_or_generate_keys(32, 0x04C11DB7, true);
@@ -863,7 +1116,7 @@ mod tests {
 
     #[test]
     fn test_cache_fallback_to_direct_generation() {
-        clear_cache();
+        clear();
 
         // Test that even if cache operations fail, we still get valid keys
         // This tests the fallback mechanism in get_or_generate_keys
@@ -904,7 +1157,7 @@ mod tests {
 
     #[test]
     fn test_cache_operations_under_memory_pressure() {
-        clear_cache();
+        clear();
 
         // Simulate memory pressure by creating many cache entries
         // This tests that cache operations remain stable under load
@@ -944,7 +1197,7 @@ mod tests {
         assert_eq!(new_keys, cached_new_keys, "New entry should be cached");
 
         // Test cache clearing still works
-        clear_cache();
+        clear();
 
         // Verify cache was cleared by testing that operations still work
         let post_clear_keys = get_or_generate_keys(32, 0x04C11DB7, true);
@@ -957,7 +1210,7 @@ mod tests {
 
     #[test]
     fn test_cache_error_recovery_patterns() {
-        clear_cache();
+        clear();
 
         // Test various error recovery patterns to ensure robustness
 
@@ -986,7 +1239,7 @@ mod tests {
             let keys2 = get_or_generate_keys(64, 0x42F0E1EBA9EA3693, false);
 
             if i % 3 == 0 {
-                clear_cache();
+                clear();
             }
 
             // Operations should still work after clearing
@@ -1005,7 +1258,7 @@ mod tests {
         use std::thread;
         use std::time::Duration;
 
-        clear_cache();
+        clear();
 
         let num_threads = 8;
         let barrier = Arc::new(Barrier::new(num_threads));
@@ -1035,7 +1288,7 @@ mod tests {
                         }
                         2 => {
                             // Cache clearing (potential contention point)
-                            clear_cache();
+                            clear();
                         }
                         3 => {
                             // Mixed width operations
@@ -1108,7 +1361,7 @@ mod tests {
     }
 
     fn cache_memory_allocation_stress(count: i32) {
-        clear_cache();
+        clear();
 
         // Test cache behavior under memory allocation stress
         // Create a large number of unique cache entries to stress memory allocation
@@ -1148,7 +1401,7 @@ mod tests {
         );
 
         // Test cache clearing works under memory pressure
-        clear_cache();
+        clear();
 
         // Verify cache operations still work after clearing
         let post_stress_keys = get_or_generate_keys(32, 0x04C11DB7, true);
@@ -1164,7 +1417,7 @@ mod tests {
     fn test_crc_params_new_behavior_unchanged() {
         use crate::CrcParams;
 
-        clear_cache();
+        clear();
 
         // Test that CrcParams::new() creates identical instances regardless of caching
         let params1 = CrcParams::new(
@@ -1232,7 +1485,7 @@ mod tests {
     fn test_existing_crc_parameter_combinations() {
         use crate::test::consts::TEST_ALL_CONFIGS;
 
-        clear_cache();
+        clear();
 
         // Test all existing CRC parameter combinations work correctly with caching
         for config in TEST_ALL_CONFIGS {
@@ -1271,7 +1524,7 @@ mod tests {
 
     #[test]
     fn test_cached_vs_uncached_results_identical() {
-        clear_cache();
+        clear();
 
         // Test parameters that affect key generation
         let test_cases = [
@@ -1288,7 +1541,7 @@ mod tests {
             let uncached_keys = generate::keys(width, poly, reflected);
 
             // Clear cache to ensure first call is cache miss
-            clear_cache();
+            clear();
 
             // Create CrcParams instance (first call - cache miss)
             let params1 =
@@ -1319,7 +1572,7 @@ mod tests {
 
     #[test]
     fn test_multiple_crc_params_instances_use_cached_keys() {
-        clear_cache();
+        clear();
 
         // Create multiple CrcParams instances with the same parameters
         let width = 32;
@@ -1438,7 +1691,7 @@ mod tests {
     fn test_crc_params_api_compatibility() {
         use crate::{CrcAlgorithm, CrcParams};
 
-        clear_cache();
+        clear();
 
         // Test that the CrcParams API remains unchanged
         let params = CrcParams::new(
@@ -1477,7 +1730,7 @@ mod tests {
     fn test_crc_params_with_all_standard_algorithms() {
         use crate::test::consts::TEST_ALL_CONFIGS;
 
-        clear_cache();
+        clear();
 
         // Test creating CrcParams for all standard CRC algorithms
         for config in TEST_ALL_CONFIGS {
@@ -1533,7 +1786,7 @@ mod tests {
 
     #[test]
     fn test_crc_params_edge_cases() {
-        clear_cache();
+        clear();
 
         // Test edge cases for CrcParams creation
 
@@ -1584,7 +1837,7 @@ mod tests {
         use std::sync::{Arc, Barrier};
         use std::thread;
 
-        clear_cache();
+        clear();
 
         let num_threads = 8;
         let barrier = Arc::new(Barrier::new(num_threads));
@@ -1654,7 +1907,7 @@ mod tests {
         use std::sync::{Arc, Barrier};
         use std::thread;
 
-        clear_cache();
+        clear();
 
         // This test is tricky because we need to poison the lock without
         // actually breaking our test. We'll simulate lock poisoning by
@@ -1694,7 +1947,7 @@ mod tests {
 
                     // Occasional cache clear to increase contention
                     if j % 7 == 0 {
-                        clear_cache();
+                        clear();
                     }
                 }
 
@@ -1722,7 +1975,7 @@ mod tests {
     fn test_cache_behavior_with_thread_local_access() {
         use std::thread;
 
-        clear_cache();
+        clear();
 
         // Test that cache works correctly when accessed from different threads
         // in sequence (not concurrently)
@@ -1762,4 +2015,32 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_lru_evicts_least_recently_used() {
+        let mut lru = Lru::new();
+
+        for i in 0..4u64 {
+            lru.insert(i, i * 10, 4);
+        }
+
+        // touch the oldest entry, so the next insert evicts the second oldest instead
+        assert_eq!(lru.get(&0), Some(0));
+        lru.insert(4, 40, 4);
+
+        assert_eq!(lru.len(), 4);
+        assert_eq!(lru.get(&0), Some(0));
+        assert_eq!(lru.get(&1), None);
+        assert_eq!(lru.get(&4), Some(40));
+
+        lru.shrink_to(2);
+        assert_eq!(lru.len(), 2);
+        assert_eq!(lru.get(&0), Some(0));
+        assert_eq!(lru.get(&4), Some(40));
+
+        // nothing is stored with no capacity
+        lru.clear();
+        lru.insert(5, 50, 0);
+        assert_eq!(lru.len(), 0);
+    }
 }
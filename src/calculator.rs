@@ -0,0 +1,270 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Pre-resolved CRC calculators for hot paths.
+//!
+//! [crate::checksum] matches on the algorithm to find its parameters, and every update then
+//! re-dispatches on the detected CPU tier and the CRC width. A [Calculator] does both once, up
+//! front, and keeps a direct pointer to the routine specialised for them, along with the static
+//! table used for short inputs.
+
+use crate::arch::{SmallTable, SMALL_INPUT_THRESHOLD};
+use crate::{
+    arch, get_calculator_params, get_fusion, BackendUnavailable, CalculatorFn, CrcAlgorithm,
+    CrcParams, Digest, PerformanceTier,
+};
+
+/// A CRC calculator resolved once for an algorithm or set of custom parameters.
+///
+/// Creating a `Calculator` performs the algorithm lookup and CPU tier dispatch ahead of time, so
+/// [checksum](Calculator::checksum), [update](Calculator::update) and any [Digest] created by
+/// [digest](Calculator::digest) call straight into the specialised routine. Results are identical
+/// to [crate::checksum] and [Digest::new].
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{Calculator, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// let calculator = Calculator::new(Crc32IsoHdlc);
+///
+/// assert_eq!(calculator.checksum(b"123456789"), 0xcbf43926);
+///
+/// let mut digest = calculator.digest();
+/// digest.update(b"1234");
+/// digest.update(b"56789");
+///
+/// assert_eq!(digest.finalize(), 0xcbf43926);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Calculator {
+    /// The parameters for the CRC computation, such as polynomial, initial value, etc.
+    params: CrcParams,
+
+    /// The routine resolved for the algorithm, the CRC width and the detected CPU tier.
+    calculator: CalculatorFn,
+
+    /// The static table for inputs shorter than [SMALL_INPUT_THRESHOLD], if the algorithm has one.
+    small: Option<SmallTable>,
+}
+
+impl Calculator {
+    /// Creates a new `Calculator` for the specified CRC algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics for the custom algorithms, which require parameters via
+    /// [new_with_params](Calculator::new_with_params).
+    pub fn new(algorithm: CrcAlgorithm) -> Self {
+        let (_, params) = get_calculator_params(algorithm);
+
+        match get_fusion(algorithm) {
+            // the native CRC instructions are quicker than the table at every length
+            Some(calculator) => Self {
+                params,
+                calculator,
+                small: None,
+            },
+            None => Self::resolved(arch::resolve(&params), params),
+        }
+    }
+
+    /// Creates a new `Calculator` for the specified CRC algorithm which uses the given backend,
+    /// rather than the detected or overridden performance tier.
+    ///
+    /// Returns an error if this CPU doesn't support the backend.
+    ///
+    /// # Panics
+    ///
+    /// Panics for the custom algorithms, which require parameters via
+    /// [new_with_params](Calculator::new_with_params).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{Calculator, PerformanceTier, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let calculator = Calculator::new_with_backend(Crc32IsoHdlc, PerformanceTier::SoftwareTable)?;
+    ///
+    /// assert_eq!(calculator.checksum(b"123456789"), 0xcbf43926);
+    /// # Ok::<(), crc_fast::BackendUnavailable>(())
+    /// ```
+    pub fn new_with_backend(
+        algorithm: CrcAlgorithm,
+        backend: PerformanceTier,
+    ) -> Result<Self, BackendUnavailable> {
+        let (_, params) = get_calculator_params(algorithm);
+
+        let calculator = arch::resolve_tier(backend, &params).ok_or(BackendUnavailable(backend))?;
+
+        Ok(Self::resolved(calculator, params))
+    }
+
+    /// Creates a new `Calculator` with custom CRC parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{Calculator, CrcParams};
+    ///
+    /// // Define custom CRC-32 parameters (equivalent to CRC-32/ISO-HDLC)
+    /// let custom_params = CrcParams::new(
+    ///     "CRC-32/CUSTOM",
+    ///     32,
+    ///     0x04c11db7,
+    ///     0xffffffff,
+    ///     true,
+    ///     0xffffffff,
+    ///     0xcbf43926,
+    /// );
+    ///
+    /// let calculator = Calculator::new_with_params(custom_params);
+    ///
+    /// assert_eq!(calculator.checksum(b"123456789"), 0xcbf43926);
+    /// ```
+    pub fn new_with_params(params: CrcParams) -> Self {
+        Self::resolved(arch::resolve(&params), params)
+    }
+
+    /// Pairs a resolved routine with the small-input table for `params`.
+    fn resolved(calculator: CalculatorFn, params: CrcParams) -> Self {
+        Self {
+            small: SmallTable::resolve(&params),
+            params,
+            calculator,
+        }
+    }
+
+    /// Computes the CRC checksum for the given data.
+    #[inline(always)]
+    pub fn checksum(&self, data: &[u8]) -> u64 {
+        self.update(self.params.init_algorithm, data) ^ self.params.xorout
+    }
+
+    /// Updates a non-finalized CRC state with the given data and returns the new state.
+    ///
+    /// The state has the same form as [Digest::get_state], so a computation can be started from
+    /// the algorithm's initial state, carried across calls, and finalized by XORing with
+    /// `xorout` from [params](Calculator::params).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{Calculator, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let calculator = Calculator::new(Crc32IsoHdlc);
+    /// let params = calculator.params();
+    ///
+    /// let state = calculator.update(params.init_algorithm, b"1234");
+    /// let state = calculator.update(state, b"56789");
+    ///
+    /// assert_eq!(state ^ params.xorout, 0xcbf43926);
+    /// ```
+    #[inline(always)]
+    pub fn update(&self, state: u64, data: &[u8]) -> u64 {
+        match self.small {
+            Some(table) if data.len() < SMALL_INPUT_THRESHOLD => table.update(state, data),
+            _ => (self.calculator)(state, data, &self.params),
+        }
+    }
+
+    /// Creates a new [Digest] which uses this calculator's resolved routine.
+    #[inline(always)]
+    pub fn digest(&self) -> Digest {
+        Digest {
+            state: self.params.init_algorithm,
+            amount: 0,
+            params: self.params,
+            calculator: self.calculator,
+            small: self.small,
+            tables: None,
+        }
+    }
+
+    /// Gets the CRC parameters this calculator was created with.
+    #[inline(always)]
+    pub fn params(&self) -> &CrcParams {
+        &self.params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::{TEST_ALL_CONFIGS, TEST_CHECK_STRING};
+
+    fn test_data() -> Vec<u8> {
+        (0..4099).map(|i| (i * 7 % 256) as u8).collect()
+    }
+
+    #[test]
+    fn test_checksum_matches_reference() {
+        let data = test_data();
+
+        for config in TEST_ALL_CONFIGS {
+            let calculator = Calculator::new(config.get_algorithm());
+
+            assert_eq!(calculator.checksum(TEST_CHECK_STRING), config.get_check());
+
+            // cover the small-input path, the SIMD engine and everything in between
+            for len in [0, 1, 15, 16, 63, 64, 255, 256, 1024, data.len()] {
+                assert_eq!(
+                    calculator.checksum(&data[..len]),
+                    config.checksum_with_reference(&data[..len]),
+                    "{} mismatch at {len} bytes",
+                    config.get_name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_and_digest_match_digest() {
+        let data = test_data();
+
+        for config in TEST_ALL_CONFIGS {
+            let calculator = Calculator::new(config.get_algorithm());
+            let params = calculator.params();
+
+            let (a, b) = data.split_at(1000);
+            let state = calculator.update(params.init_algorithm, a);
+            let state = calculator.update(state, b);
+
+            let mut digest = calculator.digest();
+            digest.update(a);
+            digest.update(b);
+
+            let mut expected = Digest::new(config.get_algorithm());
+            expected.update(&data);
+
+            assert_eq!(state ^ params.xorout, expected.finalize());
+            assert_eq!(digest.finalize(), expected.finalize());
+            assert_eq!(digest.get_amount(), data.len() as u64);
+        }
+    }
+
+    #[test]
+    fn test_new_with_params() {
+        let data = test_data();
+
+        for config in TEST_ALL_CONFIGS {
+            let params = config.get_params();
+            let custom = CrcParams::new(
+                "CRC/CUSTOM",
+                params.width,
+                params.poly,
+                params.init,
+                params.refin,
+                params.xorout,
+                params.check,
+            );
+
+            let calculator = Calculator::new_with_params(custom);
+
+            assert_eq!(
+                calculator.checksum(&data),
+                config.checksum_with_reference(&data)
+            );
+            assert_eq!(calculator.checksum(TEST_CHECK_STRING), config.get_check());
+        }
+    }
+}
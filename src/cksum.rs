@@ -0,0 +1,169 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! POSIX `cksum` compatible checksums.
+//!
+//! `cksum` uses CRC-32/CKSUM, but appends the message length to the message first, as the fewest
+//! little-endian bytes which hold it (none for an empty message). Rather than hashing the data
+//! again, the CRC of those length bytes is combined with the CRC of the data.
+
+use crate::CrcAlgorithm::Crc32Cksum;
+use crate::{checksum, checksum_combine, Digest};
+#[cfg(feature = "std")]
+use std::io::Write;
+
+/// Calculates the POSIX `cksum` checksum of the data, as printed by `cksum` alongside its length.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::posix_cksum;
+///
+/// // `printf '123456789' | cksum` prints "930766865 9"
+/// assert_eq!(posix_cksum(b"123456789"), 930766865);
+/// ```
+#[inline]
+pub fn posix_cksum(data: &[u8]) -> u32 {
+    append_length(checksum(Crc32Cksum, data), data.len() as u64)
+}
+
+/// A streaming POSIX `cksum` checksum, for data which isn't available all at once.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::PosixCksum;
+///
+/// let mut cksum = PosixCksum::new();
+/// cksum.update(b"1234");
+/// cksum.update(b"56789");
+///
+/// assert_eq!(cksum.finalize(), 930766865);
+/// assert_eq!(cksum.get_amount(), 9);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct PosixCksum {
+    digest: Digest,
+}
+
+impl Default for PosixCksum {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PosixCksum {
+    /// Creates a new `PosixCksum` instance.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            digest: Digest::new(Crc32Cksum),
+        }
+    }
+
+    /// Updates the checksum with the given data.
+    #[inline(always)]
+    pub fn update(&mut self, data: &[u8]) {
+        self.digest.update(data);
+    }
+
+    /// Finalizes the checksum, including the length of the data so far, and returns the result.
+    #[inline(always)]
+    pub fn finalize(&self) -> u32 {
+        append_length(self.digest.finalize(), self.digest.get_amount())
+    }
+
+    /// Finalizes the checksum, resets the state, and returns the result.
+    #[inline(always)]
+    pub fn finalize_reset(&mut self) -> u32 {
+        let result = self.finalize();
+        self.reset();
+
+        result
+    }
+
+    /// Resets the checksum to its initial state.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        self.digest.reset();
+    }
+
+    /// Gets the amount of data processed so far, which `cksum` prints as the size
+    #[inline(always)]
+    pub fn get_amount(&self) -> u64 {
+        self.digest.get_amount()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Write for PosixCksum {
+    #[inline(always)]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Extends a CRC-32/CKSUM checksum of `len` bytes with the bytes of `len` itself
+#[inline]
+fn append_length(crc: u64, len: u64) -> u32 {
+    let suffix_len = (u64::BITS - len.leading_zeros()).div_ceil(8) as usize;
+
+    if suffix_len == 0 {
+        return crc as u32;
+    }
+
+    let suffix = &len.to_le_bytes()[..suffix_len];
+
+    checksum_combine(
+        Crc32Cksum,
+        crc,
+        checksum(Crc32Cksum, suffix),
+        suffix_len as u64,
+    ) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (data, `cksum` output) pairs from GNU coreutils 9.1, covering 0 to 3 length bytes
+    fn coreutils_vectors() -> Vec<(Vec<u8>, u32)> {
+        vec![
+            (Vec::new(), 4294967295),
+            (b"a".to_vec(), 1220704766),
+            (b"123456789".to_vec(), 930766865),
+            (vec![0; 300], 351385237),
+            (
+                (0..70000).map(|i| (i * 7 % 256) as u8).collect(),
+                3451499348,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_posix_cksum_matches_coreutils() {
+        for (data, expected) in coreutils_vectors() {
+            assert_eq!(posix_cksum(&data), expected, "{} bytes", data.len());
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_one_shot() {
+        for (data, expected) in coreutils_vectors() {
+            let mut cksum = PosixCksum::new();
+
+            for chunk in data.chunks(4097) {
+                cksum.update(chunk);
+            }
+
+            assert_eq!(cksum.get_amount(), data.len() as u64);
+            assert_eq!(cksum.finalize_reset(), expected);
+            assert_eq!(cksum.finalize(), 4294967295);
+        }
+    }
+}
@@ -84,35 +84,21 @@ fn gf2_matrix_square(square: &mut [u64; 64], mat: &[u64; 64]) {
 first sequence of bytes, crc2 is the CRC of the immediately following
 sequence of bytes, and len2 is the length of the second sequence.  The CRC
 of the combined sequence is returned. */
-pub fn checksums(mut crc1: u64, crc2: u64, mut len2: u64, params: &CrcParams) -> u64 {
-    let mut col: u64;
-    let mut even = [0u64; 64]; /* even-power-of-two zeros operator */
-    let mut odd = [0u64; 64]; /* odd-power-of-two zeros operator */
-
+pub fn checksums(crc1: u64, crc2: u64, len2: u64, params: &CrcParams) -> u64 {
     /* exclusive-or the result with len2 zeros applied to the CRC of an empty
     sequence */
-    crc1 ^= params.init_algorithm ^ params.xorout;
+    let crc1 = zeros(crc1 ^ params.init_algorithm ^ params.xorout, len2, params);
+
+    /* return combined crc */
+    crc1 ^ crc2
+}
+
+/// Feeds `len` zero bytes through the CRC register `crc`, in O(log(len)) operator squarings.
+pub(crate) fn zeros(mut crc: u64, mut len: u64, params: &CrcParams) -> u64 {
+    let mut even = [0u64; 64]; /* even-power-of-two zeros operator */
 
     /* construct the operator for one zero bit and put in odd[] */
-    if params.refin && params.refout {
-        // use the reflected POLY
-        odd[0] = reflect_poly(params.poly, params.width as u32);
-        col = 1;
-        for n in 1..params.width {
-            odd[n as usize] = col;
-            col <<= 1;
-        }
-    } else if !params.refin && !params.refout {
-        col = 2;
-        for n in 0..params.width - 1 {
-            odd[n as usize] = col;
-            col <<= 1;
-        }
-        // Put poly at the last valid index (width-1)
-        odd[(params.width - 1) as usize] = params.poly;
-    } else {
-        panic!("Unsupported CRC configuration");
-    }
+    let mut odd = zero_bit_operator(params); /* odd-power-of-two zeros operator */
 
     /* put operator for two zero bits in even */
     gf2_matrix_square(&mut even, &odd);
@@ -120,38 +106,82 @@ pub fn checksums(mut crc1: u64, crc2: u64, mut len2: u64, params: &CrcParams) ->
     /* put operator for four zero bits in odd */
     gf2_matrix_square(&mut odd, &even);
 
-    /* apply len2 zeros to crc1 (first square will put the operator for one
+    /* apply len zeros to crc (first square will put the operator for one
     zero byte, eight zero bits, in even) */
     loop {
-        /* apply zeros operator for this bit of len2 */
+        /* apply zeros operator for this bit of len */
         gf2_matrix_square(&mut even, &odd);
-        if len2 & 1 == 1 {
-            crc1 = gf2_matrix_times(&even, crc1);
+        if len & 1 == 1 {
+            crc = gf2_matrix_times(&even, crc);
         }
-        len2 >>= 1;
+        len >>= 1;
 
         /* if no more bits set, then done */
-        if len2 == 0 {
+        if len == 0 {
             break;
         }
 
         /* another iteration of the loop with odd and even swapped */
         gf2_matrix_square(&mut odd, &even);
-        if len2 & 1 == 1 {
-            crc1 = gf2_matrix_times(&odd, crc1);
+        if len & 1 == 1 {
+            crc = gf2_matrix_times(&odd, crc);
         }
-        len2 >>= 1;
+        len >>= 1;
 
         /* if no more bits set, then done */
-        if len2 == 0 {
+        if len == 0 {
             break;
         }
     }
 
-    /* return combined crc */
-    crc1 ^= crc2;
+    crc
+}
+
+/* Construct the operator which feeds one zero bit through the CRC register. */
+fn zero_bit_operator(params: &CrcParams) -> [u64; 64] {
+    let mut odd = [0u64; 64];
+    let mut col: u64;
+
+    if params.refin && params.refout {
+        // use the reflected POLY
+        odd[0] = reflect_poly(params.poly, params.width as u32);
+        col = 1;
+        for n in 1..params.width {
+            odd[n as usize] = col;
+            col <<= 1;
+        }
+    } else if !params.refin && !params.refout {
+        col = 2;
+        for n in 0..params.width - 1 {
+            odd[n as usize] = col;
+            col <<= 1;
+        }
+        // Put poly at the last valid index (width-1)
+        odd[(params.width - 1) as usize] = params.poly;
+    } else {
+        panic!("Unsupported CRC configuration");
+    }
+
+    odd
+}
+
+/// Returns the operator which feeds one zero byte through the CRC register, for use with
+/// [apply_operator].
+pub(crate) fn zero_byte_operator(params: &CrcParams) -> [u64; 64] {
+    let mut even = [0u64; 64];
+    let mut odd = zero_bit_operator(params);
+
+    gf2_matrix_square(&mut even, &odd); /* two zero bits */
+    gf2_matrix_square(&mut odd, &even); /* four zero bits */
+    gf2_matrix_square(&mut even, &odd); /* eight zero bits */
+
+    even
+}
 
-    crc1
+/// Applies a zeros operator, such as the one from [zero_byte_operator], to a CRC register.
+#[inline]
+pub(crate) fn apply_operator(operator: &[u64; 64], crc: u64) -> u64 {
+    gf2_matrix_times(operator, crc)
 }
 
 fn reflect_poly(poly: u64, width: u32) -> u64 {
@@ -2,6 +2,7 @@
 
 #![allow(dead_code)]
 
+#[cfg(feature = "crc16")]
 use crate::consts::{
     NAME_CRC16_ARC, NAME_CRC16_CDMA2000, NAME_CRC16_CMS, NAME_CRC16_DDS_110, NAME_CRC16_DECT_R,
     NAME_CRC16_DECT_X, NAME_CRC16_DNP, NAME_CRC16_EN_13757, NAME_CRC16_GENIBUS, NAME_CRC16_GSM,
@@ -12,7 +13,9 @@ use crate::consts::{
     NAME_CRC16_TMS37157, NAME_CRC16_UMTS, NAME_CRC16_USB, NAME_CRC16_XMODEM,
 };
 use crate::structs::Algorithm;
+#[cfg(feature = "crc16")]
 use crate::CrcAlgorithm;
+#[cfg(feature = "crc16")]
 use crate::CrcParams;
 
 // Native CRC-16 algorithm constants matching the CRC catalogue specification
@@ -360,6 +363,7 @@ pub const CRC_16_XMODEM: Algorithm<u16> = Algorithm {
 };
 
 // width=16 poly=0x8005 init=0x0000 refin=true refout=true xorout=0x0000 check=0xbb3d residue=0x0000 name="CRC-16/ARC"
+#[cfg(feature = "crc16")]
 pub const CRC16_ARC: CrcParams = CrcParams {
     name: NAME_CRC16_ARC,
     algorithm: CrcAlgorithm::Crc16Arc,
@@ -375,6 +379,7 @@ pub const CRC16_ARC: CrcParams = CrcParams {
 };
 
 // width=16 poly=0xc867 init=0xffff refin=false refout=false xorout=0x0000 check=0x4c06 residue=0x0000 name="CRC-16/CDMA2000"
+#[cfg(feature = "crc16")]
 pub const CRC16_CDMA2000: CrcParams = CrcParams {
     name: NAME_CRC16_CDMA2000,
     algorithm: CrcAlgorithm::Crc16Cdma2000,
@@ -390,6 +395,7 @@ pub const CRC16_CDMA2000: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x8005 init=0xffff refin=false refout=false xorout=0x0000 check=0xaee7 residue=0x0000 name="CRC-16/CMS"
+#[cfg(feature = "crc16")]
 pub const CRC16_CMS: CrcParams = CrcParams {
     name: NAME_CRC16_CMS,
     algorithm: CrcAlgorithm::Crc16Cms,
@@ -405,6 +411,7 @@ pub const CRC16_CMS: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x8005 init=0x800d refin=false refout=false xorout=0x0000 check=0x9ecf residue=0x0000 name="CRC-16/DDS-110"
+#[cfg(feature = "crc16")]
 pub const CRC16_DDS_110: CrcParams = CrcParams {
     name: NAME_CRC16_DDS_110,
     algorithm: CrcAlgorithm::Crc16Dds110,
@@ -420,6 +427,7 @@ pub const CRC16_DDS_110: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x0589 init=0x0000 refin=false refout=false xorout=0x0001 check=0x007e residue=0x0589 name="CRC-16/DECT-R"
+#[cfg(feature = "crc16")]
 pub const CRC16_DECT_R: CrcParams = CrcParams {
     name: NAME_CRC16_DECT_R,
     algorithm: CrcAlgorithm::Crc16DectR,
@@ -435,6 +443,7 @@ pub const CRC16_DECT_R: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x0589 init=0x0000 refin=false refout=false xorout=0x0000 check=0x007f residue=0x0000 name="CRC-16/DECT-X"
+#[cfg(feature = "crc16")]
 pub const CRC16_DECT_X: CrcParams = CrcParams {
     name: NAME_CRC16_DECT_X,
     algorithm: CrcAlgorithm::Crc16DectX,
@@ -450,6 +459,7 @@ pub const CRC16_DECT_X: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x3d65 init=0x0000 refin=true refout=true xorout=0xffff check=0xea82 residue=0x66c5 name="CRC-16/DNP"
+#[cfg(feature = "crc16")]
 pub const CRC16_DNP: CrcParams = CrcParams {
     name: NAME_CRC16_DNP,
     algorithm: CrcAlgorithm::Crc16Dnp,
@@ -465,6 +475,7 @@ pub const CRC16_DNP: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x3d65 init=0x0000 refin=false refout=false xorout=0xffff check=0xc2b7 residue=0xa366 name="CRC-16/EN-13757"
+#[cfg(feature = "crc16")]
 pub const CRC16_EN_13757: CrcParams = CrcParams {
     name: NAME_CRC16_EN_13757,
     algorithm: CrcAlgorithm::Crc16En13757,
@@ -480,6 +491,7 @@ pub const CRC16_EN_13757: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x1021 init=0xffff refin=false refout=false xorout=0xffff check=0xd64e residue=0x1d0f name="CRC-16/GENIBUS"
+#[cfg(feature = "crc16")]
 pub const CRC16_GENIBUS: CrcParams = CrcParams {
     name: NAME_CRC16_GENIBUS,
     algorithm: CrcAlgorithm::Crc16Genibus,
@@ -495,6 +507,7 @@ pub const CRC16_GENIBUS: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x1021 init=0x0000 refin=false refout=false xorout=0xffff check=0xce3c residue=0x1d0f name="CRC-16/GSM"
+#[cfg(feature = "crc16")]
 pub const CRC16_GSM: CrcParams = CrcParams {
     name: NAME_CRC16_GSM,
     algorithm: CrcAlgorithm::Crc16Gsm,
@@ -510,6 +523,7 @@ pub const CRC16_GSM: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x1021 init=0xffff refin=false refout=false xorout=0x0000 check=0x29b1 residue=0x0000 name="CRC-16/IBM-3740"
+#[cfg(feature = "crc16")]
 pub const CRC16_IBM_3740: CrcParams = CrcParams {
     name: NAME_CRC16_IBM_3740,
     algorithm: CrcAlgorithm::Crc16Ibm3740,
@@ -525,6 +539,7 @@ pub const CRC16_IBM_3740: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x1021 init=0xffff refin=true refout=true xorout=0xffff check=0x906e residue=0xf0b8 name="CRC-16/IBM-SDLC"
+#[cfg(feature = "crc16")]
 pub const CRC16_IBM_SDLC: CrcParams = CrcParams {
     name: NAME_CRC16_IBM_SDLC,
     algorithm: CrcAlgorithm::Crc16IbmSdlc,
@@ -540,6 +555,7 @@ pub const CRC16_IBM_SDLC: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x1021 init=0xc6c6 refin=true refout=true xorout=0x0000 check=0xbf05 residue=0x0000 name="CRC-16/ISO-IEC-14443-3-A"
+#[cfg(feature = "crc16")]
 pub const CRC16_ISO_IEC_14443_3_A: CrcParams = CrcParams {
     name: NAME_CRC16_ISO_IEC_14443_3_A,
     algorithm: CrcAlgorithm::Crc16IsoIec144433A,
@@ -556,6 +572,7 @@ pub const CRC16_ISO_IEC_14443_3_A: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x1021 init=0x0000 refin=true refout=true xorout=0x0000 check=0x2189 residue=0x0000 name="CRC-16/KERMIT"
+#[cfg(feature = "crc16")]
 pub const CRC16_KERMIT: CrcParams = CrcParams {
     name: NAME_CRC16_KERMIT,
     algorithm: CrcAlgorithm::Crc16Kermit,
@@ -571,6 +588,7 @@ pub const CRC16_KERMIT: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x6f63 init=0x0000 refin=false refout=false xorout=0x0000 check=0xbdf4 residue=0x0000 name="CRC-16/LJ1200"
+#[cfg(feature = "crc16")]
 pub const CRC16_LJ1200: CrcParams = CrcParams {
     name: NAME_CRC16_LJ1200,
     algorithm: CrcAlgorithm::Crc16Lj1200,
@@ -586,6 +604,7 @@ pub const CRC16_LJ1200: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x5935 init=0xffff refin=false refout=false xorout=0x0000 check=0x772b residue=0x0000 name="CRC-16/M17"
+#[cfg(feature = "crc16")]
 pub const CRC16_M17: CrcParams = CrcParams {
     name: NAME_CRC16_M17,
     algorithm: CrcAlgorithm::Crc16M17,
@@ -601,6 +620,7 @@ pub const CRC16_M17: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x8005 init=0x0000 refin=true refout=true xorout=0xffff check=0x44c2 residue=0xb001 name="CRC-16/MAXIM-DOW"
+#[cfg(feature = "crc16")]
 pub const CRC16_MAXIM_DOW: CrcParams = CrcParams {
     name: NAME_CRC16_MAXIM_DOW,
     algorithm: CrcAlgorithm::Crc16MaximDow,
@@ -616,6 +636,7 @@ pub const CRC16_MAXIM_DOW: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x1021 init=0xffff refin=true refout=true xorout=0x0000 check=0x6f91 residue=0x0000 name="CRC-16/MCRF4XX"
+#[cfg(feature = "crc16")]
 pub const CRC16_MCRF4XX: CrcParams = CrcParams {
     name: NAME_CRC16_MCRF4XX,
     algorithm: CrcAlgorithm::Crc16Mcrf4xx,
@@ -631,6 +652,7 @@ pub const CRC16_MCRF4XX: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x8005 init=0xffff refin=true refout=true xorout=0x0000 check=0x4b37 residue=0x0000 name="CRC-16/MODBUS"
+#[cfg(feature = "crc16")]
 pub const CRC16_MODBUS: CrcParams = CrcParams {
     name: NAME_CRC16_MODBUS,
     algorithm: CrcAlgorithm::Crc16Modbus,
@@ -646,6 +668,7 @@ pub const CRC16_MODBUS: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x080b init=0xffff refin=true refout=true xorout=0x0000 check=0xa066 residue=0x0000 name="CRC-16/NRSC-5"
+#[cfg(feature = "crc16")]
 pub const CRC16_NRSC_5: CrcParams = CrcParams {
     name: NAME_CRC16_NRSC_5,
     algorithm: CrcAlgorithm::Crc16Nrsc5,
@@ -661,6 +684,7 @@ pub const CRC16_NRSC_5: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x5935 init=0x0000 refin=false refout=false xorout=0x0000 check=0x5d38 residue=0x0000 name="CRC-16/OPENSAFETY-A"
+#[cfg(feature = "crc16")]
 pub const CRC16_OPENSAFETY_A: CrcParams = CrcParams {
     name: NAME_CRC16_OPENSAFETY_A,
     algorithm: CrcAlgorithm::Crc16OpensafetyA,
@@ -676,6 +700,7 @@ pub const CRC16_OPENSAFETY_A: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x755b init=0x0000 refin=false refout=false xorout=0x0000 check=0x20fe residue=0x0000 name="CRC-16/OPENSAFETY-B"
+#[cfg(feature = "crc16")]
 pub const CRC16_OPENSAFETY_B: CrcParams = CrcParams {
     name: NAME_CRC16_OPENSAFETY_B,
     algorithm: CrcAlgorithm::Crc16OpensafetyB,
@@ -691,6 +716,7 @@ pub const CRC16_OPENSAFETY_B: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x1dcf init=0xffff refin=false refout=false xorout=0xffff check=0xa819 residue=0xe394 name="CRC-16/PROFIBUS"
+#[cfg(feature = "crc16")]
 pub const CRC16_PROFIBUS: CrcParams = CrcParams {
     name: NAME_CRC16_PROFIBUS,
     algorithm: CrcAlgorithm::Crc16Profibus,
@@ -706,6 +732,7 @@ pub const CRC16_PROFIBUS: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x1021 init=0xb2aa refin=true refout=true xorout=0x0000 check=0x63d0 residue=0x0000 name="CRC-16/RIELLO"
+#[cfg(feature = "crc16")]
 pub const CRC16_RIELLO: CrcParams = CrcParams {
     name: NAME_CRC16_RIELLO,
     algorithm: CrcAlgorithm::Crc16Riello,
@@ -722,6 +749,7 @@ pub const CRC16_RIELLO: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x1021 init=0x1d0f refin=false refout=false xorout=0x0000 check=0xe5cc residue=0x0000 name="CRC-16/SPI-FUJITSU"
+#[cfg(feature = "crc16")]
 pub const CRC16_SPI_FUJITSU: CrcParams = CrcParams {
     name: NAME_CRC16_SPI_FUJITSU,
     algorithm: CrcAlgorithm::Crc16SpiFujitsu,
@@ -737,6 +765,7 @@ pub const CRC16_SPI_FUJITSU: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x8bb7 init=0x0000 refin=false refout=false xorout=0x0000 check=0xd0db residue=0x0000 name="CRC-16/T10-DIF"
+#[cfg(feature = "crc16")]
 pub const CRC16_T10_DIF: CrcParams = CrcParams {
     name: NAME_CRC16_T10_DIF,
     algorithm: CrcAlgorithm::Crc16T10Dif,
@@ -752,6 +781,7 @@ pub const CRC16_T10_DIF: CrcParams = CrcParams {
 };
 
 // width=16 poly=0xa097 init=0x0000 refin=false refout=false xorout=0x0000 check=0x0fb3 residue=0x0000 name="CRC-16/TELEDISK"
+#[cfg(feature = "crc16")]
 pub const CRC16_TELEDISK: CrcParams = CrcParams {
     name: NAME_CRC16_TELEDISK,
     algorithm: CrcAlgorithm::Crc16Teledisk,
@@ -767,6 +797,7 @@ pub const CRC16_TELEDISK: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x1021 init=0x89ec refin=true refout=true xorout=0x0000 check=0x26b1 residue=0x0000 name="CRC-16/TMS37157"
+#[cfg(feature = "crc16")]
 pub const CRC16_TMS37157: CrcParams = CrcParams {
     name: NAME_CRC16_TMS37157,
     algorithm: CrcAlgorithm::Crc16Tms37157,
@@ -783,6 +814,7 @@ pub const CRC16_TMS37157: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x8005 init=0x0000 refin=false refout=false xorout=0x0000 check=0xfee8 residue=0x0000 name="CRC-16/UMTS"
+#[cfg(feature = "crc16")]
 pub const CRC16_UMTS: CrcParams = CrcParams {
     name: NAME_CRC16_UMTS,
     algorithm: CrcAlgorithm::Crc16Umts,
@@ -798,6 +830,7 @@ pub const CRC16_UMTS: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x8005 init=0xffff refin=true refout=true xorout=0xffff check=0xb4c8 residue=0xb001 name="CRC-16/USB"
+#[cfg(feature = "crc16")]
 pub const CRC16_USB: CrcParams = CrcParams {
     name: NAME_CRC16_USB,
     algorithm: CrcAlgorithm::Crc16Usb,
@@ -813,6 +846,7 @@ pub const CRC16_USB: CrcParams = CrcParams {
 };
 
 // width=16 poly=0x1021 init=0x0000 refin=false refout=false xorout=0x0000 check=0x31c3 residue=0x0000 name="CRC-16/XMODEM"
+#[cfg(feature = "crc16")]
 pub const CRC16_XMODEM: CrcParams = CrcParams {
     name: NAME_CRC16_XMODEM,
     algorithm: CrcAlgorithm::Crc16Xmodem,
@@ -3,7 +3,7 @@
 pub(crate) mod algorithm;
 pub(crate) mod consts;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "crc16"))]
 mod property_tests {
     use crate::crc16::consts::{CRC16_IBM_SDLC, CRC16_T10_DIF};
     use crate::test::consts::{RUST_CRC16_IBM_SDLC, RUST_CRC16_T10_DIF};
@@ -3,7 +3,12 @@
 //! This module provides the CRC-32 algorithm implementations for areas where it differs from
 //! CRC-64.
 
-#![cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+#![cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
 
 use crate::enums::Reflector;
 use crate::structs::CrcState;
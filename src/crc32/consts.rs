@@ -2,13 +2,16 @@
 
 #![allow(dead_code)]
 
+#[cfg(feature = "crc32")]
 use crate::consts::{
     NAME_CRC32_AIXM, NAME_CRC32_AUTOSAR, NAME_CRC32_BASE91_D, NAME_CRC32_BZIP2,
     NAME_CRC32_CD_ROM_EDC, NAME_CRC32_CKSUM, NAME_CRC32_ISCSI, NAME_CRC32_ISO_HDLC,
     NAME_CRC32_JAMCRC, NAME_CRC32_MEF, NAME_CRC32_MPEG_2, NAME_CRC32_XFER,
 };
 use crate::structs::Algorithm;
+#[cfg(feature = "crc32")]
 use crate::CrcAlgorithm;
+#[cfg(feature = "crc32")]
 use crate::CrcParams;
 
 pub const CRC_32_AIXM: Algorithm<u32> = Algorithm {
@@ -144,6 +147,7 @@ pub const CRC_32_XFER: Algorithm<u32> = Algorithm {
 };
 
 // width=32 poly=0x814141ab init=0x00000000 refin=false refout=false xorout=0x00000000 check=0x3010bf7f residue=0x00000000 name="CRC-32/AIXM"
+#[cfg(feature = "crc32")]
 pub const CRC32_AIXM: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Aixm,
     name: NAME_CRC32_AIXM,
@@ -159,6 +163,7 @@ pub const CRC32_AIXM: CrcParams = CrcParams {
 };
 
 // width=32 poly=0xf4acfb13 init=0xffffffff refin=true refout=true xorout=0xffffffff check=0x1697d06a residue=0x904cddbf name="CRC-32/AUTOSAR"
+#[cfg(feature = "crc32")]
 pub const CRC32_AUTOSAR: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Autosar,
     name: NAME_CRC32_AUTOSAR,
@@ -174,6 +179,7 @@ pub const CRC32_AUTOSAR: CrcParams = CrcParams {
 };
 
 // width=32 poly=0xa833982b init=0xffffffff refin=true refout=true xorout=0xffffffff check=0x87315576 residue=0x45270551 name="CRC-32/BASE91-D"
+#[cfg(feature = "crc32")]
 pub const CRC32_BASE91_D: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Base91D,
     name: NAME_CRC32_BASE91_D,
@@ -189,6 +195,7 @@ pub const CRC32_BASE91_D: CrcParams = CrcParams {
 };
 
 // width=32 poly=0x04c11db7 init=0xffffffff refin=false refout=false xorout=0xffffffff check=0xfc891918 residue=0xc704dd7b name="CRC-32/BZIP2"
+#[cfg(feature = "crc32")]
 pub const CRC32_BZIP2: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Bzip2,
     name: NAME_CRC32_BZIP2,
@@ -204,6 +211,7 @@ pub const CRC32_BZIP2: CrcParams = CrcParams {
 };
 
 // width=32 poly=0x8001801b init=0x00000000 refin=true refout=true xorout=0x00000000 check=0x6ec2edc4 residue=0x00000000 name="CRC-32/CD-ROM-EDC"
+#[cfg(feature = "crc32")]
 pub const CRC32_CD_ROM_EDC: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32CdRomEdc,
     name: NAME_CRC32_CD_ROM_EDC,
@@ -219,6 +227,7 @@ pub const CRC32_CD_ROM_EDC: CrcParams = CrcParams {
 };
 
 // width=32 poly=0x04c11db7 init=0x00000000 refin=false refout=false xorout=0xffffffff check=0x765e7680 residue=0xc704dd7b name="CRC-32/CKSUM"
+#[cfg(feature = "crc32")]
 pub const CRC32_CKSUM: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Cksum,
     name: NAME_CRC32_CKSUM,
@@ -234,6 +243,7 @@ pub const CRC32_CKSUM: CrcParams = CrcParams {
 };
 
 // width=32 poly=0x1edc6f41 init=0xffffffff refin=true refout=true xorout=0xffffffff check=0xe3069283 residue=0xb798b438 name="CRC-32/ISCSI"
+#[cfg(feature = "crc32")]
 pub const CRC32_ISCSI: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Iscsi,
     name: NAME_CRC32_ISCSI,
@@ -249,6 +259,7 @@ pub const CRC32_ISCSI: CrcParams = CrcParams {
 };
 
 // width=32 poly=0x04c11db7 init=0xffffffff refin=true refout=true xorout=0xffffffff check=0xcbf43926 residue=0xdebb20e3 name="CRC-32/ISO-HDLC"
+#[cfg(feature = "crc32")]
 pub const CRC32_ISO_HDLC: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32IsoHdlc,
     name: NAME_CRC32_ISO_HDLC,
@@ -264,6 +275,7 @@ pub const CRC32_ISO_HDLC: CrcParams = CrcParams {
 };
 
 // width=32 poly=0x04c11db7 init=0xffffffff refin=true refout=true xorout=0x00000000 check=0x340bc6d9 residue=0x00000000 name="CRC-32/JAMCRC"
+#[cfg(feature = "crc32")]
 pub const CRC32_JAMCRC: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Jamcrc,
     name: NAME_CRC32_JAMCRC,
@@ -279,6 +291,7 @@ pub const CRC32_JAMCRC: CrcParams = CrcParams {
 };
 
 // width=32 poly=0x741b8cd7 init=0xffffffff refin=true refout=true xorout=0x00000000 check=0xd2c22f51 residue=0x00000000 name="CRC-32/MEF"
+#[cfg(feature = "crc32")]
 pub const CRC32_MEF: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Mef,
     name: NAME_CRC32_MEF,
@@ -294,6 +307,7 @@ pub const CRC32_MEF: CrcParams = CrcParams {
 };
 
 // width=32 poly=0x04c11db7 init=0xffffffff refin=false refout=false xorout=0x00000000 check=0x0376e6e7 residue=0x00000000 name="CRC-32/MPEG-2"
+#[cfg(feature = "crc32")]
 pub const CRC32_MPEG_2: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Mpeg2,
     name: NAME_CRC32_MPEG_2,
@@ -309,6 +323,7 @@ pub const CRC32_MPEG_2: CrcParams = CrcParams {
 };
 
 // width=32 poly=0x000000af init=0x00000000 refin=false refout=false xorout=0x00000000 check=0xbd0be338 residue=0x00000000 name="CRC-32/XFER"
+#[cfg(feature = "crc32")]
 pub const CRC32_XFER: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Xfer,
     name: NAME_CRC32_XFER,
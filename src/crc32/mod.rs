@@ -8,11 +8,12 @@ pub(crate) mod width32_ops;
 
 #[cfg(all(
     feature = "std",
+    feature = "crc32",
     any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
 ))]
 pub(crate) mod fusion;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "crc32"))]
 mod property_tests {
     use crate::test::miri_compatible_proptest_config;
     use crate::{checksum, CrcAlgorithm};
@@ -2,9 +2,12 @@
 
 #![allow(dead_code)]
 
+#[cfg(feature = "crc64")]
 use crate::consts::*;
 use crate::structs::Algorithm;
+#[cfg(feature = "crc64")]
 use crate::CrcAlgorithm;
+#[cfg(feature = "crc64")]
 use crate::CrcParams;
 
 pub const CRC_64_ECMA_182: Algorithm<u64> = Algorithm {
@@ -85,6 +88,7 @@ pub const CRC_64_XZ: Algorithm<u64> = Algorithm {
 };
 
 // width=64 poly=0x42f0e1eba9ea3693 init=0x0000000000000000 refin=false refout=false xorout=0x0000000000000000 check=0x6c40df5f0b497347 residue=0x0000000000000000 name="CRC-64/ECMA-182"
+#[cfg(feature = "crc64")]
 pub const CRC64_ECMA_182: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc64Ecma182,
     name: NAME_CRC64_ECMA_182,
@@ -100,6 +104,7 @@ pub const CRC64_ECMA_182: CrcParams = CrcParams {
 };
 
 // width=64 poly=0x000000000000001b init=0xffffffffffffffff refin=true refout=true xorout=0xffffffffffffffff check=0xb90956c775a41001 residue=0x5300000000000000 name="CRC-64/GO-ISO"
+#[cfg(feature = "crc64")]
 pub const CRC64_GO_ISO: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc64GoIso,
     name: NAME_CRC64_GO_ISO,
@@ -115,6 +120,7 @@ pub const CRC64_GO_ISO: CrcParams = CrcParams {
 };
 
 // width=64 poly=0x259c84cba6426349 init=0xffffffffffffffff refin=true refout=true xorout=0x0000000000000000 check=0x75d4b74f024eceea residue=0x0000000000000000 name="CRC-64/MS"
+#[cfg(feature = "crc64")]
 pub const CRC64_MS: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc64Ms,
     name: NAME_CRC64_MS,
@@ -131,6 +137,7 @@ pub const CRC64_MS: CrcParams = CrcParams {
 
 // https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-64-nvme
 // width=64 poly=0xad93d23594c93659 init=0xffffffffffffffff refin=true refout=true xorout=0xffffffffffffffff check=0xae8b14860a799888 residue=0xf310303b2b6f6e42 name="CRC-64/NVME"
+#[cfg(feature = "crc64")]
 pub const CRC64_NVME: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc64Nvme,
     name: NAME_CRC64_NVME,
@@ -146,6 +153,7 @@ pub const CRC64_NVME: CrcParams = CrcParams {
 };
 
 // width=64 poly=0xad93d23594c935a9 init=0x0000000000000000 refin=true refout=true xorout=0x0000000000000000 check=0xe9c6d914c4b8d9ca residue=0x0000000000000000 name="CRC-64/REDIS"
+#[cfg(feature = "crc64")]
 pub const CRC64_REDIS: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc64Redis,
     name: NAME_CRC64_REDIS,
@@ -161,6 +169,7 @@ pub const CRC64_REDIS: CrcParams = CrcParams {
 };
 
 // width=64 poly=0x42f0e1eba9ea3693 init=0xffffffffffffffff refin=false refout=false xorout=0xffffffffffffffff check=0x62ec59e3f1a4f00a residue=0xfcacbebd5931a992 name="CRC-64/WE"
+#[cfg(feature = "crc64")]
 pub const CRC64_WE: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc64We,
     name: NAME_CRC64_WE,
@@ -176,6 +185,7 @@ pub const CRC64_WE: CrcParams = CrcParams {
 };
 
 // width=64 poly=0x42f0e1eba9ea3693 init=0xffffffffffffffff refin=true refout=true xorout=0xffffffffffffffff check=0x995dc9bbdf1939fa residue=0x49958c9abd7d353f name="CRC-64/XZ"
+#[cfg(feature = "crc64")]
 pub const CRC64_XZ: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc64Xz,
     name: NAME_CRC64_XZ,
@@ -6,7 +6,7 @@ pub mod algorithm;
 pub mod consts;
 pub mod utils;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "crc64"))]
 mod property_tests {
     use crate::test::miri_compatible_proptest_config;
     use crate::{checksum, CrcAlgorithm};
@@ -0,0 +1,156 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Caller-owned lookup tables for custom CRC parameters.
+//!
+//! The software path needs a lookup table for every set of parameters. Predefined algorithms have
+//! static ones, while custom parameters otherwise generate theirs at runtime, which requires the
+//! `alloc` feature and keeps them for the life of the program. A [CustomTables] holds the table
+//! instead, so the caller decides where it lives and for how long.
+
+use crate::arch::software::CustomTable;
+use crate::feature_detection::get_active_tier;
+use crate::{arch, CrcParams, PerformanceTier};
+use core::fmt::{Debug, Formatter};
+
+/// Custom CRC parameters along with the software lookup table for them.
+///
+/// Works without the `alloc` feature, so `no_std` firmware can use custom CRCs on targets without
+/// SIMD acceleration, via [checksum_with_tables](crate::checksum_with_tables) or
+/// [Digest::new_with_tables](crate::Digest::new_with_tables). Where SIMD acceleration is
+/// available, the table is only used while the software backend is forced.
+///
+/// The table is up to 32 KiB (for CRC-64 with the default slice-by-16 tables), so prefer a
+/// `static` to the stack. [new](CustomTables::new) is a `const fn`, so with `const` parameters,
+/// from [CrcParams::const_new] or those printed by `get-custom-params --emit rust`, the table is
+/// generated at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum_with_tables, CrcParams, CustomTables};
+///
+/// // CRC-32/ISO-HDLC, as custom parameters
+/// let params = CrcParams::new("CRC-32/CUSTOM", 32, 0x04c11db7, 0xffffffff, true, 0xffffffff, 0xcbf43926);
+/// let tables = CustomTables::new(params);
+///
+/// assert_eq!(checksum_with_tables(&tables, b"123456789"), 0xcbf43926);
+/// ```
+pub struct CustomTables {
+    params: CrcParams,
+    table: CustomTable,
+}
+
+impl CustomTables {
+    /// Generates the lookup table for the given custom CRC parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width isn't 16, 32 or 64.
+    pub const fn new(params: CrcParams) -> Self {
+        Self {
+            table: CustomTable::new(&params),
+            params,
+        }
+    }
+
+    /// Gets the CRC parameters these tables were generated for.
+    #[inline(always)]
+    pub fn params(&self) -> &CrcParams {
+        &self.params
+    }
+
+    /// Updates a CRC state with the given data, using the table when the software backend is
+    /// active and the SIMD implementation otherwise.
+    #[inline(always)]
+    pub(crate) fn update(&self, state: u64, data: &[u8]) -> u64 {
+        if get_active_tier() == PerformanceTier::SoftwareTable {
+            return self.table.update(state, data, &self.params);
+        }
+
+        unsafe { arch::update(state, data, &self.params) }
+    }
+}
+
+impl Debug for CustomTables {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CustomTables")
+            .field("params", &self.params)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use crate::test::enums::AnyCrcTestConfig;
+    use crate::{checksum_with_params, checksum_with_tables};
+
+    fn custom_params(config: &AnyCrcTestConfig) -> CrcParams {
+        let params = config.get_params();
+
+        CrcParams::new(
+            "CUSTOM",
+            params.width,
+            params.poly,
+            params.init,
+            params.refin,
+            params.xorout,
+            params.check,
+        )
+    }
+
+    #[test]
+    fn test_tables_match_params() {
+        let data: Vec<u8> = (0..5000u32).map(|i| (i * 7 + (i >> 5)) as u8).collect();
+
+        for config in TEST_ALL_CONFIGS {
+            let params = custom_params(config);
+            let tables = CustomTables::new(params);
+
+            assert_eq!(
+                checksum_with_tables(&tables, b"123456789"),
+                params.check,
+                "{}",
+                config.get_name()
+            );
+
+            for len in [0, 1, 15, 16, 63, 64, 255, 4096, 5000] {
+                let expected = checksum_with_params(params, &data[..len]);
+
+                // the software path directly, regardless of the active tier
+                let state = tables
+                    .table
+                    .update(params.init_algorithm, &data[..len], &params);
+
+                assert_eq!(
+                    state ^ params.xorout,
+                    expected,
+                    "{} with {len} bytes",
+                    config.get_name()
+                );
+                assert_eq!(checksum_with_tables(&tables, &data[..len]), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "crc32")]
+    fn test_digest_with_tables() {
+        use crate::Digest;
+
+        static TABLES: CustomTables = CustomTables::new(crate::crc32::consts::CRC32_ISCSI);
+
+        let mut digest = Digest::new_with_tables(&TABLES);
+        digest.update(b"1234");
+        digest.update(b"56789");
+
+        assert_eq!(digest.finalize(), 0xe3069283);
+        assert_eq!(digest.get_amount(), 9);
+
+        digest.reset();
+        digest.update(b"123456789");
+
+        assert_eq!(digest.finalize(), 0xe3069283);
+    }
+}
@@ -0,0 +1,638 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Error correction for short frames using the Hamming distance of a CRC.
+//!
+//! A CRC with a Hamming distance of at least 3 over a frame can correct any single-bit error in
+//! it, and at least 5 any double-bit error, rather than discarding the frame. The syndrome (the
+//! received CRC XORed with the CRC of the received data) depends only on which bits were flipped,
+//! so the flipped bits are located by matching the syndrome against a table of the syndromes of
+//! each bit position, built for the frame length with the same zero operator that
+//! [checksum_combine](crate::checksum_combine) uses.
+//!
+//! Single-bit errors are always searched for, and double-bit errors and short bursts if enabled.
+//! When more than one error pattern matches, the frame is [Uncorrectable::Ambiguous] rather than
+//! guessed at.
+//!
+//! Building the table costs one zero operator application per data bit, so an [ErrorCorrector]
+//! should be reused for frames of the same length.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crc_fast::ecc::{BitError, ErrorCorrector};
+//! use crc_fast::{checksum, CrcAlgorithm, Calculator};
+//!
+//! let params = *Calculator::new(CrcAlgorithm::Crc32IsoHdlc).params();
+//! let mut frame = *b"a short radio frame";
+//! let crc = checksum(CrcAlgorithm::Crc32IsoHdlc, &frame);
+//!
+//! frame[3] ^= 0x10;
+//!
+//! let corrector = ErrorCorrector::new(params, frame.len());
+//! let correction = corrector.correct(&mut frame, crc).unwrap();
+//!
+//! assert_eq!(&frame, b"a short radio frame");
+//! assert_eq!(correction.errors, vec![BitError::Data { byte: 3, bit: 4 }]);
+//! ```
+
+use crate::combine::{apply_operator, zero_byte_operator};
+use crate::{checksum_with_params, CrcParams};
+use core::fmt::{Display, Formatter};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+
+/// Each extra bit doubles the number of burst patterns, and bursts longer than half the CRC width
+/// are rarely correctable anyway.
+const MAX_BURST: usize = 16;
+
+/// A single flipped bit, where bit 0 is the least significant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BitError {
+    /// A flipped bit in the data
+    Data { byte: usize, bit: u8 },
+
+    /// A flipped bit in the received CRC
+    Crc { bit: u8 },
+}
+
+/// The result of correcting a frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Correction {
+    /// The corrected bits in transmission order, or empty if the frame was already valid
+    pub errors: Vec<BitError>,
+
+    /// The corrected CRC, which matches the corrected data
+    pub crc: u64,
+}
+
+/// The error returned when a frame can't be corrected.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Uncorrectable {
+    /// No error pattern within the enabled search matches the syndrome
+    NotFound,
+
+    /// More than one error pattern of the fewest bits matches the syndrome
+    Ambiguous,
+
+    /// The frame isn't the length the [ErrorCorrector] was built for
+    LengthMismatch { expected: usize, actual: usize },
+}
+
+impl Display for Uncorrectable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "no correctable error pattern matches the CRC"),
+            Self::Ambiguous => write!(f, "more than one error pattern matches the CRC"),
+            Self::LengthMismatch { expected, actual } => write!(
+                f,
+                "frame is {actual} bytes, but the corrector was built for {expected}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for Uncorrectable {}
+
+/// Corrects bit errors in frames of a fixed length protected by a CRC.
+///
+/// Bit positions run in transmission order: the data, least significant bit of each byte first
+/// for reflected CRCs and most significant first otherwise, followed by the CRC in the same bit
+/// order. Bursts are contiguous in this order.
+#[derive(Clone, Debug)]
+pub struct ErrorCorrector {
+    params: CrcParams,
+    len: usize,
+    double_bit: bool,
+    max_burst: usize,
+    /// The syndrome of each bit position
+    syndromes: Vec<u64>,
+    /// (syndrome, position) pairs, sorted for lookups
+    index: Vec<(u64, usize)>,
+}
+
+impl ErrorCorrector {
+    /// Builds the syndrome table for frames of `len` bytes, correcting single-bit errors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `refin` and `refout` differ, which no catalogued CRC does.
+    pub fn new(params: CrcParams, len: usize) -> Self {
+        let width = params.width as usize;
+        let reflected = params.refin;
+        let operator = zero_byte_operator(&params);
+
+        // the syndrome of each bit in the last byte, which the zero operator then shifts back
+        // through the preceding bytes
+        let zero = checksum_with_params(params, &[0]);
+        let mut columns = [0u64; 8];
+        for (bit, column) in columns.iter_mut().enumerate() {
+            *column = checksum_with_params(params, &[1 << bit]) ^ zero;
+        }
+
+        let mut syndromes = vec![0u64; len * 8 + width];
+        for byte in (0..len).rev() {
+            for (bit, column) in columns.iter_mut().enumerate() {
+                syndromes[data_position(byte, bit, reflected)] = *column;
+                *column = apply_operator(&operator, *column);
+            }
+        }
+        for bit in 0..width {
+            syndromes[len * 8 + crc_offset(bit, width, reflected)] = 1 << bit;
+        }
+
+        let mut index: Vec<(u64, usize)> = syndromes
+            .iter()
+            .enumerate()
+            .map(|(position, &syndrome)| (syndrome, position))
+            .collect();
+        index.sort_unstable();
+
+        Self {
+            params,
+            len,
+            double_bit: false,
+            max_burst: 0,
+            syndromes,
+            index,
+        }
+    }
+
+    /// Also searches for double-bit errors anywhere in the frame when no single-bit error matches.
+    pub fn with_double_bit(mut self, enabled: bool) -> Self {
+        self.double_bit = enabled;
+        self
+    }
+
+    /// Also searches for bursts of up to `max_burst` bits. A burst is only corrected when no other
+    /// burst of up to `max_burst` bits, single-bit error or double-bit error matches, since bursts
+    /// longer than the CRC can tell apart at this frame length would otherwise be miscorrected.
+    /// Zero disables the burst search.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_burst` exceeds 16.
+    pub fn with_max_burst(mut self, max_burst: usize) -> Self {
+        assert!(
+            max_burst <= MAX_BURST,
+            "max_burst must be at most {MAX_BURST}"
+        );
+        self.max_burst = max_burst;
+        self
+    }
+
+    /// The frame length, in bytes, this corrector was built for.
+    pub fn frame_len(&self) -> usize {
+        self.len
+    }
+
+    /// Corrects `data` in place, returning the flipped bits and the corrected CRC.
+    ///
+    /// `data` is left unchanged if it can't be corrected.
+    pub fn correct(&self, data: &mut [u8], crc: u64) -> Result<Correction, Uncorrectable> {
+        if data.len() != self.len {
+            return Err(Uncorrectable::LengthMismatch {
+                expected: self.len,
+                actual: data.len(),
+            });
+        }
+
+        let positions = self.locate(checksum_with_params(self.params, data) ^ crc)?;
+
+        let width = self.params.width as usize;
+        let reflected = self.params.refin;
+        let mut crc = crc;
+        let errors = positions
+            .into_iter()
+            .map(|position| {
+                if position < self.len * 8 {
+                    let byte = position / 8;
+                    let bit = if reflected {
+                        position % 8
+                    } else {
+                        7 - position % 8
+                    };
+                    data[byte] ^= 1 << bit;
+
+                    BitError::Data {
+                        byte,
+                        bit: bit as u8,
+                    }
+                } else {
+                    let bit = crc_offset(position - self.len * 8, width, reflected);
+                    crc ^= 1 << bit;
+
+                    BitError::Crc { bit: bit as u8 }
+                }
+            })
+            .collect();
+
+        Ok(Correction { errors, crc })
+    }
+
+    /// Finds the bit positions, in ascending order, whose syndromes XOR to `syndrome`.
+    fn locate(&self, syndrome: u64) -> Result<Vec<usize>, Uncorrectable> {
+        if syndrome == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut found = match self.lookup(syndrome) {
+            [] => None,
+            [(_, position)] => Some(vec![*position]),
+            _ => return Err(Uncorrectable::Ambiguous),
+        };
+
+        if found.is_none() && self.double_bit {
+            for (first, &first_syndrome) in self.syndromes.iter().enumerate() {
+                for &(_, second) in self.lookup(syndrome ^ first_syndrome) {
+                    if second > first {
+                        if found.is_some() {
+                            return Err(Uncorrectable::Ambiguous);
+                        }
+                        found = Some(vec![first, second]);
+                    }
+                }
+            }
+        }
+
+        // a burst has to be the only pattern of its span matching, including any single-bit or
+        // double-bit error already found, or it's as likely to be a miscorrection
+        for length in 2..=self.max_burst.min(self.syndromes.len()) {
+            if let Some(burst) = self.locate_burst(syndrome, length)? {
+                match &found {
+                    Some(positions) if *positions != burst => return Err(Uncorrectable::Ambiguous),
+                    Some(_) => {}
+                    None => found = Some(burst),
+                }
+            }
+        }
+
+        found.ok_or(Uncorrectable::NotFound)
+    }
+
+    /// Finds the burst of exactly `length` bits, its first and last bits flipped, matching
+    /// `syndrome`.
+    fn locate_burst(
+        &self,
+        syndrome: u64,
+        length: usize,
+    ) -> Result<Option<Vec<usize>>, Uncorrectable> {
+        let inner = length - 2;
+        let mut found = None;
+
+        for start in 0..=self.syndromes.len() - length {
+            let end = start + length - 1;
+            let mut value = self.syndromes[start] ^ self.syndromes[end];
+            let mut pattern = 0usize;
+
+            // walk the inner bit patterns in Gray code order, one bit flip per step
+            for step in 0..1usize << inner {
+                if step > 0 {
+                    let bit = step.trailing_zeros() as usize;
+                    pattern ^= 1 << bit;
+                    value ^= self.syndromes[start + 1 + bit];
+                }
+
+                if value == syndrome {
+                    if found.is_some() {
+                        return Err(Uncorrectable::Ambiguous);
+                    }
+
+                    let mut positions = vec![start];
+                    positions.extend(
+                        (0..inner)
+                            .filter(|bit| pattern & (1 << bit) != 0)
+                            .map(|bit| start + 1 + bit),
+                    );
+                    positions.push(end);
+                    found = Some(positions);
+                }
+            }
+        }
+
+        Ok(found)
+    }
+
+    /// The index entries with exactly this syndrome.
+    fn lookup(&self, syndrome: u64) -> &[(u64, usize)] {
+        let start = self.index.partition_point(|&(value, _)| value < syndrome);
+        let end = self.index.partition_point(|&(value, _)| value <= syndrome);
+
+        &self.index[start..end]
+    }
+}
+
+/// Corrects a single-bit error in `data` in place, returning the flipped bit and the corrected CRC.
+///
+/// This builds a new [ErrorCorrector] each time, so reuse one instead for many frames of the same
+/// length, or to also correct double-bit errors and bursts.
+pub fn correct(params: CrcParams, data: &mut [u8], crc: u64) -> Result<Correction, Uncorrectable> {
+    ErrorCorrector::new(params, data.len()).correct(data, crc)
+}
+
+#[inline(always)]
+fn data_position(byte: usize, bit: usize, reflected: bool) -> usize {
+    byte * 8 + if reflected { bit } else { 7 - bit }
+}
+
+/// The offset of a CRC bit from the start of the CRC, in transmission order.
+#[inline(always)]
+fn crc_offset(bit: usize, width: usize, reflected: bool) -> usize {
+    if reflected {
+        bit
+    } else {
+        width - 1 - bit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use crate::test::enums::AnyCrcTestConfig;
+    #[cfg(feature = "crc32")]
+    use crate::{Calculator, CrcAlgorithm};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn configs() -> impl Iterator<Item = &'static AnyCrcTestConfig> {
+        TEST_ALL_CONFIGS
+            .iter()
+            .filter(|config| matches!(config.get_width(), 16 | 32))
+    }
+
+    fn random_frame(rng: &mut StdRng, len: usize) -> Vec<u8> {
+        (0..len).map(|_| rng.random()).collect()
+    }
+
+    /// Counts the single-bit errors, and the double-bit errors or the bursts of up to `max_burst`
+    /// bits, with this syndrome, by brute force rather than the corrector's own search.
+    fn count_matches(corrector: &ErrorCorrector, syndrome: u64, max_burst: usize) -> usize {
+        let syndromes = &corrector.syndromes;
+        let bits = syndromes.len();
+        let mut count = syndromes.iter().filter(|&&s| s == syndrome).count();
+
+        if corrector.double_bit {
+            for first in 0..bits {
+                for second in first + 1..bits {
+                    count += usize::from(syndromes[first] ^ syndromes[second] == syndrome);
+                }
+            }
+        }
+
+        for length in 2..=max_burst {
+            for start in 0..=bits - length {
+                let ends = syndromes[start] ^ syndromes[start + length - 1];
+
+                for inner in 0..1usize << (length - 2) {
+                    let value = (0..length - 2)
+                        .filter(|bit| inner & (1 << bit) != 0)
+                        .fold(ends, |value, bit| value ^ syndromes[start + 1 + bit]);
+
+                    count += usize::from(value == syndrome);
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Flips the bit at a transmission order position, in either the data or the CRC.
+    fn flip(params: &CrcParams, data: &mut [u8], crc: &mut u64, position: usize) {
+        let width = params.width as usize;
+        if position < data.len() * 8 {
+            let bit = data_position(0, position % 8, params.refin);
+            data[position / 8] ^= 1 << bit;
+        } else {
+            *crc ^= 1 << crc_offset(position - data.len() * 8, width, params.refin);
+        }
+    }
+
+    #[test]
+    fn test_single_bit_errors() {
+        let mut rng = StdRng::seed_from_u64(0x5eed_0001);
+
+        for config in configs() {
+            let params = *config.get_params();
+            let len = rng.random_range(16..=64);
+            let corrector = ErrorCorrector::new(params, len);
+            let bits = len * 8 + params.width as usize;
+
+            for _ in 0..32 {
+                let original = random_frame(&mut rng, len);
+                let crc = checksum_with_params(params, &original);
+
+                let position = rng.random_range(0..bits);
+
+                let mut data = original.clone();
+                let mut received = crc;
+                flip(&params, &mut data, &mut received, position);
+
+                // some polynomials repeat within a frame, giving bits the same syndrome
+                match corrector.correct(&mut data, received) {
+                    Ok(correction) => {
+                        assert_eq!(data, original, "{}", config.get_name());
+                        assert_eq!(correction.crc, crc, "{}", config.get_name());
+                        assert_eq!(correction.errors.len(), 1, "{}", config.get_name());
+                    }
+                    Err(error) => {
+                        assert_eq!(error, Uncorrectable::Ambiguous, "{}", config.get_name());
+                        assert!(corrector.lookup(corrector.syndromes[position]).len() > 1);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_error_locations() {
+        let mut rng = StdRng::seed_from_u64(0x5eed_0002);
+
+        for config in configs() {
+            let params = *config.get_params();
+            let original = random_frame(&mut rng, 8);
+            let crc = checksum_with_params(params, &original);
+
+            let mut data = original.clone();
+            data[5] ^= 1 << 2;
+            assert_eq!(
+                correct(params, &mut data, crc).map(|correction| correction.errors),
+                Ok(vec![BitError::Data { byte: 5, bit: 2 }]),
+                "{}",
+                config.get_name()
+            );
+            assert_eq!(data, original);
+
+            let correction = correct(params, &mut data, crc ^ (1 << 7)).unwrap();
+            assert_eq!(correction.errors, vec![BitError::Crc { bit: 7 }]);
+            assert_eq!(correction.crc, crc);
+            assert_eq!(data, original);
+
+            let correction = correct(params, &mut data, crc).unwrap();
+            assert!(correction.errors.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_double_bit_errors() {
+        let mut rng = StdRng::seed_from_u64(0x5eed_0003);
+
+        for config in configs() {
+            let params = *config.get_params();
+            let len = rng.random_range(4..=8);
+            let corrector = ErrorCorrector::new(params, len).with_double_bit(true);
+            let bits = len * 8 + params.width as usize;
+            let mut corrected = 0;
+
+            for _ in 0..16 {
+                let original = random_frame(&mut rng, len);
+                let crc = checksum_with_params(params, &original);
+
+                let first = rng.random_range(0..bits);
+                let second = (first + rng.random_range(1..bits)) % bits;
+
+                let mut data = original.clone();
+                let mut received = crc;
+                flip(&params, &mut data, &mut received, first);
+                flip(&params, &mut data, &mut received, second);
+
+                // weaker CRCs can't tell some pairs apart, but never correct the wrong bits
+                match corrector.correct(&mut data, received) {
+                    Ok(correction) => {
+                        assert_eq!(data, original, "{}", config.get_name());
+                        assert_eq!(correction.crc, crc, "{}", config.get_name());
+                        assert_eq!(correction.errors.len(), 2, "{}", config.get_name());
+                        corrected += 1;
+                    }
+                    Err(error) => {
+                        assert_eq!(error, Uncorrectable::Ambiguous, "{}", config.get_name());
+
+                        let syndrome = corrector.syndromes[first] ^ corrector.syndromes[second];
+                        assert!(
+                            count_matches(&corrector, syndrome, 0) > 1,
+                            "{} refused bits {first} and {second}",
+                            config.get_name()
+                        );
+                    }
+                }
+            }
+
+            assert!(corrected > 0, "{}", config.get_name());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "crc32")]
+    fn test_double_bit_error_locations() {
+        // CRC-32/ISO-HDLC has a Hamming distance of 6 at this length, so every pair is unique
+        let params = *Calculator::new(CrcAlgorithm::Crc32IsoHdlc).params();
+        let corrector = ErrorCorrector::new(params, 8).with_double_bit(true);
+        let original = random_frame(&mut StdRng::seed_from_u64(0x5eed_0004), 8);
+        let crc = checksum_with_params(params, &original);
+
+        let mut data = original.clone();
+        data[1] ^= 1 << 6;
+        data[6] ^= 1 << 0;
+        let correction = corrector.correct(&mut data, crc).unwrap();
+        assert_eq!(
+            correction.errors,
+            vec![
+                BitError::Data { byte: 1, bit: 6 },
+                BitError::Data { byte: 6, bit: 0 }
+            ]
+        );
+        assert_eq!(data, original);
+
+        data[7] ^= 1 << 7;
+        let correction = corrector.correct(&mut data, crc ^ (1 << 3)).unwrap();
+        assert_eq!(
+            correction.errors,
+            vec![BitError::Data { byte: 7, bit: 7 }, BitError::Crc { bit: 3 }]
+        );
+        assert_eq!(correction.crc, crc);
+        assert_eq!(data, original);
+    }
+
+    #[test]
+    fn test_burst_errors() {
+        let mut rng = StdRng::seed_from_u64(0x5eed_0005);
+
+        for config in configs() {
+            let params = *config.get_params();
+            let max_burst = params.width as usize / 4;
+            let len = rng.random_range(4..=16);
+            let corrector = ErrorCorrector::new(params, len).with_max_burst(max_burst);
+            let bits = len * 8 + params.width as usize;
+            let mut corrected = 0;
+
+            for _ in 0..16 {
+                let original = random_frame(&mut rng, len);
+                let crc = checksum_with_params(params, &original);
+
+                let length = rng.random_range(2..=max_burst);
+                let start = rng.random_range(0..=bits - length);
+                let inner: usize = rng.random_range(0..1 << (length - 2));
+
+                let mut data = original.clone();
+                let mut received = crc;
+                let mut syndrome = 0;
+                let flipped = (0..length - 2)
+                    .filter(|bit| inner & (1 << bit) != 0)
+                    .map(|bit| start + 1 + bit)
+                    .chain([start, start + length - 1]);
+                for position in flipped {
+                    flip(&params, &mut data, &mut received, position);
+                    syndrome ^= corrector.syndromes[position];
+                }
+
+                // bursts the CRC can't tell apart at this length are refused, never miscorrected
+                match corrector.correct(&mut data, received) {
+                    Ok(correction) => {
+                        assert_eq!(data, original, "{}", config.get_name());
+                        assert_eq!(correction.crc, crc, "{}", config.get_name());
+                        corrected += 1;
+                    }
+                    Err(error) => {
+                        assert_eq!(error, Uncorrectable::Ambiguous, "{}", config.get_name());
+                        assert!(
+                            count_matches(&corrector, syndrome, max_burst) > 1,
+                            "{} refused a {length} bit burst at {start}",
+                            config.get_name()
+                        );
+                    }
+                }
+            }
+
+            assert!(corrected > 0, "{}", config.get_name());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "crc32")]
+    fn test_uncorrectable() {
+        let params = *Calculator::new(CrcAlgorithm::Crc32IsoHdlc).params();
+        let original = random_frame(&mut StdRng::seed_from_u64(0x5eed_0006), 32);
+        let crc = checksum_with_params(params, &original);
+
+        // a Hamming distance above 4 at this length keeps three flipped bits from matching one
+        let mut data = original.clone();
+        data[0] ^= 0x01;
+        data[10] ^= 0x10;
+        data[20] ^= 0x80;
+        let corrupted = data.clone();
+        assert_eq!(
+            correct(params, &mut data, crc),
+            Err(Uncorrectable::NotFound)
+        );
+        assert_eq!(data, corrupted);
+
+        let mut short = original[..31].to_vec();
+        assert_eq!(
+            ErrorCorrector::new(params, 32).correct(&mut short, crc),
+            Err(Uncorrectable::LengthMismatch {
+                expected: 32,
+                actual: 31
+            })
+        );
+    }
+}
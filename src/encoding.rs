@@ -0,0 +1,304 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Byte and base64 encodings of finalized checksums.
+//!
+//! Checksums are returned as a `u64` regardless of the CRC width, but protocols exchange them as
+//! exactly `width / 8` big-endian bytes, and cloud object stores as the base64 of those bytes
+//! (such as `x-amz-checksum-crc64nvme` or `x-goog-hash: crc32c=`).
+
+use crate::{get_calculator_params, CrcAlgorithm, CrcParams};
+use core::fmt::{Display, Formatter};
+use core::ops::Deref;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+extern crate alloc;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::String;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Bytes sized to the CRC width (2, 4 or 8 bytes), such as a checksum's big-endian bytes, or the
+/// bytes from [forge](crate::forge).
+///
+/// Dereferences to `[u8]`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ChecksumBytes {
+    bytes: [u8; 8],
+    len: usize,
+}
+
+impl ChecksumBytes {
+    /// The low `len` bytes of `value`, least significant first.
+    #[inline(always)]
+    pub(crate) fn from_le_u64(value: u64, len: usize) -> Self {
+        let mut bytes = [0; 8];
+        bytes[..len].copy_from_slice(&value.to_le_bytes()[..len]);
+
+        Self { bytes, len }
+    }
+}
+
+impl Deref for ChecksumBytes {
+    type Target = [u8];
+
+    #[inline(always)]
+    fn deref(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl AsRef<[u8]> for ChecksumBytes {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+/// The error returned when decoding a base64 checksum fails.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChecksumDecodeError {
+    /// The input isn't valid padded or unpadded standard base64
+    InvalidBase64,
+
+    /// The input decoded to a different number of bytes than the CRC width
+    InvalidLength { expected: usize, actual: usize },
+}
+
+impl Display for ChecksumDecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidBase64 => write!(f, "invalid base64 checksum"),
+            Self::InvalidLength { expected, actual } => write!(
+                f,
+                "checksum is {actual} bytes, but the CRC width requires {expected}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ChecksumDecodeError {}
+
+/// Returns the big-endian bytes of a checksum calculated with the specified algorithm.
+///
+/// # Panics
+///
+/// Panics for the custom algorithms, which require
+/// [checksum_to_be_bytes_with_params] instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum, checksum_to_be_bytes, CrcAlgorithm::Crc32Iscsi};
+///
+/// let checksum = checksum(Crc32Iscsi, b"123456789");
+///
+/// assert_eq!(*checksum_to_be_bytes(Crc32Iscsi, checksum), [0xe3, 0x06, 0x92, 0x83]);
+/// ```
+#[inline]
+pub fn checksum_to_be_bytes(algorithm: CrcAlgorithm, checksum: u64) -> ChecksumBytes {
+    checksum_to_be_bytes_with_params(&get_calculator_params(algorithm).1, checksum)
+}
+
+/// Returns the big-endian bytes of a checksum calculated with custom CRC parameters.
+#[inline]
+pub fn checksum_to_be_bytes_with_params(params: &CrcParams, checksum: u64) -> ChecksumBytes {
+    let len = params.width as usize / 8;
+    let mut bytes = [0; 8];
+    bytes[..len].copy_from_slice(&checksum.to_be_bytes()[8 - len..]);
+
+    ChecksumBytes { bytes, len }
+}
+
+/// Returns the standard, padded base64 encoding of a checksum's big-endian bytes, as used by the
+/// `x-amz-checksum-*` and `x-goog-hash` headers.
+///
+/// # Panics
+///
+/// Panics for the custom algorithms, which require [checksum_to_base64_with_params] instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum, checksum_to_base64, CrcAlgorithm::Crc64Nvme};
+///
+/// let checksum = checksum(Crc64Nvme, b"123456789");
+///
+/// assert_eq!(checksum_to_base64(Crc64Nvme, checksum), "rosUhgp5mIg=");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn checksum_to_base64(algorithm: CrcAlgorithm, checksum: u64) -> String {
+    checksum_to_base64_with_params(&get_calculator_params(algorithm).1, checksum)
+}
+
+/// Returns the standard, padded base64 encoding of a checksum calculated with custom CRC
+/// parameters.
+#[cfg(feature = "alloc")]
+pub fn checksum_to_base64_with_params(params: &CrcParams, checksum: u64) -> String {
+    let bytes = checksum_to_be_bytes_with_params(params, checksum);
+    let mut encoded = String::with_capacity(12);
+
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, &byte)| {
+            group | (byte as u32) << (16 - 8 * i)
+        });
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64_ALPHABET[(group >> (18 - 6 * i)) as usize & 0x3f] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+/// Decodes a base64 checksum, such as an `x-amz-checksum-*` header, for the specified algorithm.
+///
+/// Accepts standard base64 with or without padding, which must decode to exactly the CRC width.
+///
+/// # Panics
+///
+/// Panics for the custom algorithms, which require [checksum_from_base64_with_params] instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum_from_base64, CrcAlgorithm::Crc32Iscsi};
+///
+/// assert_eq!(checksum_from_base64(Crc32Iscsi, "4waSgw=="), Ok(0xe3069283));
+/// assert!(checksum_from_base64(Crc32Iscsi, "rosUhgp5mIg=").is_err());
+/// ```
+#[inline]
+pub fn checksum_from_base64(
+    algorithm: CrcAlgorithm,
+    encoded: &str,
+) -> Result<u64, ChecksumDecodeError> {
+    checksum_from_base64_with_params(&get_calculator_params(algorithm).1, encoded)
+}
+
+/// Decodes a base64 checksum for custom CRC parameters.
+pub fn checksum_from_base64_with_params(
+    params: &CrcParams,
+    encoded: &str,
+) -> Result<u64, ChecksumDecodeError> {
+    let expected = params.width as usize / 8;
+    let data = encoded.as_bytes();
+
+    // padding, if any, must complete the final group of 4
+    let unpadded = encoded.trim_end_matches('=');
+    let padding = data.len() - unpadded.len();
+    if padding > 2 || (padding > 0 && !data.len().is_multiple_of(4)) || unpadded.len() % 4 == 1 {
+        return Err(ChecksumDecodeError::InvalidBase64);
+    }
+
+    let mut checksum = 0u64;
+    let mut actual = 0;
+    let mut bits = 0u32;
+    let mut pending = 0u32;
+
+    for &c in unpadded.as_bytes() {
+        let value = BASE64_ALPHABET
+            .iter()
+            .position(|&a| a == c)
+            .ok_or(ChecksumDecodeError::InvalidBase64)?;
+
+        pending = (pending << 6) | value as u32;
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            checksum = (checksum << 8) | ((pending >> bits) & 0xff) as u64;
+            pending &= (1 << bits) - 1;
+            actual += 1;
+        }
+    }
+
+    if actual != expected {
+        return Err(ChecksumDecodeError::InvalidLength { expected, actual });
+    }
+
+    Ok(checksum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(all(feature = "crc32", feature = "crc64"))]
+    use crate::checksum;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    #[cfg(all(feature = "crc32", feature = "crc64"))]
+    use crate::test::consts::TEST_CHECK_STRING;
+    #[cfg(feature = "crc32")]
+    use crate::CrcAlgorithm;
+
+    #[test]
+    #[cfg(all(feature = "crc32", feature = "crc64"))]
+    fn test_cloud_header_values() {
+        // `x-amz-checksum-*` values for "123456789"
+        for (algorithm, expected) in [
+            (CrcAlgorithm::Crc32IsoHdlc, "y/Q5Jg=="),
+            (CrcAlgorithm::Crc32Iscsi, "4waSgw=="),
+            (CrcAlgorithm::Crc64Nvme, "rosUhgp5mIg="),
+        ] {
+            let value = checksum(algorithm, TEST_CHECK_STRING);
+
+            assert_eq!(checksum_to_base64(algorithm, value), expected);
+            assert_eq!(checksum_from_base64(algorithm, expected), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_round_trip_all_algorithms() {
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+            let value = config.get_check();
+            let bytes = checksum_to_be_bytes(algorithm, value);
+
+            assert_eq!(bytes.len(), config.get_width() as usize / 8);
+            assert_eq!(
+                bytes.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64),
+                value
+            );
+
+            let encoded = checksum_to_base64(algorithm, value);
+            assert_eq!(checksum_from_base64(algorithm, &encoded), Ok(value));
+            assert_eq!(
+                checksum_from_base64(algorithm, encoded.trim_end_matches('=')),
+                Ok(value)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "crc32")]
+    fn test_from_base64_rejects_invalid_input() {
+        let algorithm = CrcAlgorithm::Crc32Iscsi;
+
+        for invalid in ["4waSgw===", "4waSg", "4wa*gw==", "4w=aSgw=", "=4waSgw="] {
+            assert_eq!(
+                checksum_from_base64(algorithm, invalid),
+                Err(ChecksumDecodeError::InvalidBase64),
+                "{invalid}"
+            );
+        }
+
+        assert_eq!(
+            checksum_from_base64(algorithm, "rosUhgp5mIg="),
+            Err(ChecksumDecodeError::InvalidLength {
+                expected: 4,
+                actual: 8
+            })
+        );
+        assert_eq!(
+            checksum_from_base64(algorithm, ""),
+            Err(ChecksumDecodeError::InvalidLength {
+                expected: 4,
+                actual: 0
+            })
+        );
+    }
+}
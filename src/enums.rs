@@ -5,6 +5,196 @@ use crate::CrcAlgorithm;
 use core::fmt::{Display, Formatter};
 use core::str::FromStr;
 
+impl CrcAlgorithm {
+    /// Every predefined algorithm in this build, which excludes the custom ones and any
+    /// families disabled by the `crc16`, `crc32` and `crc64` features.
+    pub const PREDEFINED: &'static [CrcAlgorithm] = &[
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Arc,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Cdma2000,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Cms,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Dds110,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16DectR,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16DectX,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Dnp,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16En13757,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Genibus,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Gsm,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Ibm3740,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16IbmSdlc,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16IsoIec144433A,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Kermit,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Lj1200,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16M17,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16MaximDow,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Mcrf4xx,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Modbus,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Nrsc5,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16OpensafetyA,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16OpensafetyB,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Profibus,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Riello,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16SpiFujitsu,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16T10Dif,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Teledisk,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Tms37157,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Umts,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Usb,
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Xmodem,
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Aixm,
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Autosar,
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Base91D,
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Bzip2,
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32CdRomEdc,
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Cksum,
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Iscsi,
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32IsoHdlc,
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Jamcrc,
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Mef,
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Mpeg2,
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Xfer,
+        #[cfg(feature = "crc64")]
+        CrcAlgorithm::Crc64Ecma182,
+        #[cfg(feature = "crc64")]
+        CrcAlgorithm::Crc64GoIso,
+        #[cfg(feature = "crc64")]
+        CrcAlgorithm::Crc64Ms,
+        #[cfg(feature = "crc64")]
+        CrcAlgorithm::Crc64Nvme,
+        #[cfg(feature = "crc64")]
+        CrcAlgorithm::Crc64Redis,
+        #[cfg(feature = "crc64")]
+        CrcAlgorithm::Crc64We,
+        #[cfg(feature = "crc64")]
+        CrcAlgorithm::Crc64Xz,
+    ];
+
+    /// Whether this algorithm can be calculated in this build.
+    ///
+    /// The custom algorithms always can. A predefined algorithm needs its family's `crc16`,
+    /// `crc32` or `crc64` feature, and the checksum functions panic for one that isn't compiled
+    /// in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::CrcAlgorithm;
+    ///
+    /// assert!(CrcAlgorithm::CrcCustom.is_supported());
+    /// assert_eq!(CrcAlgorithm::Crc32IsoHdlc.is_supported(), cfg!(feature = "crc32"));
+    /// ```
+    #[allow(clippy::match_like_matches_macro)] // the arms fold to constants per feature set
+    pub fn is_supported(self) -> bool {
+        match self.family_feature() {
+            Some("crc16") => cfg!(feature = "crc16"),
+            Some("crc32") => cfg!(feature = "crc32"),
+            Some("crc64") => cfg!(feature = "crc64"),
+            _ => true,
+        }
+    }
+
+    /// The feature which compiles in this algorithm's family, or `None` for the custom
+    /// algorithms.
+    #[allow(deprecated)]
+    pub(crate) const fn family_feature(self) -> Option<&'static str> {
+        match self {
+            CrcAlgorithm::CrcCustom | CrcAlgorithm::Crc32Custom | CrcAlgorithm::Crc64Custom => None,
+            CrcAlgorithm::Crc16Arc
+            | CrcAlgorithm::Crc16Cdma2000
+            | CrcAlgorithm::Crc16Cms
+            | CrcAlgorithm::Crc16Dds110
+            | CrcAlgorithm::Crc16DectR
+            | CrcAlgorithm::Crc16DectX
+            | CrcAlgorithm::Crc16Dnp
+            | CrcAlgorithm::Crc16En13757
+            | CrcAlgorithm::Crc16Genibus
+            | CrcAlgorithm::Crc16Gsm
+            | CrcAlgorithm::Crc16Ibm3740
+            | CrcAlgorithm::Crc16IbmSdlc
+            | CrcAlgorithm::Crc16IsoIec144433A
+            | CrcAlgorithm::Crc16Kermit
+            | CrcAlgorithm::Crc16Lj1200
+            | CrcAlgorithm::Crc16M17
+            | CrcAlgorithm::Crc16MaximDow
+            | CrcAlgorithm::Crc16Mcrf4xx
+            | CrcAlgorithm::Crc16Modbus
+            | CrcAlgorithm::Crc16Nrsc5
+            | CrcAlgorithm::Crc16OpensafetyA
+            | CrcAlgorithm::Crc16OpensafetyB
+            | CrcAlgorithm::Crc16Profibus
+            | CrcAlgorithm::Crc16Riello
+            | CrcAlgorithm::Crc16SpiFujitsu
+            | CrcAlgorithm::Crc16T10Dif
+            | CrcAlgorithm::Crc16Teledisk
+            | CrcAlgorithm::Crc16Tms37157
+            | CrcAlgorithm::Crc16Umts
+            | CrcAlgorithm::Crc16Usb
+            | CrcAlgorithm::Crc16Xmodem => Some("crc16"),
+            CrcAlgorithm::Crc32Aixm
+            | CrcAlgorithm::Crc32Autosar
+            | CrcAlgorithm::Crc32Base91D
+            | CrcAlgorithm::Crc32Bzip2
+            | CrcAlgorithm::Crc32CdRomEdc
+            | CrcAlgorithm::Crc32Cksum
+            | CrcAlgorithm::Crc32Iscsi
+            | CrcAlgorithm::Crc32IsoHdlc
+            | CrcAlgorithm::Crc32Jamcrc
+            | CrcAlgorithm::Crc32Mef
+            | CrcAlgorithm::Crc32Mpeg2
+            | CrcAlgorithm::Crc32Xfer => Some("crc32"),
+            CrcAlgorithm::Crc64Ecma182
+            | CrcAlgorithm::Crc64GoIso
+            | CrcAlgorithm::Crc64Ms
+            | CrcAlgorithm::Crc64Nvme
+            | CrcAlgorithm::Crc64Redis
+            | CrcAlgorithm::Crc64We
+            | CrcAlgorithm::Crc64Xz => Some("crc64"),
+        }
+    }
+}
+
 impl FromStr for CrcAlgorithm {
     type Err = ();
 
@@ -128,14 +318,24 @@ impl Display for CrcAlgorithm {
     }
 }
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
 #[derive(Debug, Copy, Clone)]
 pub(crate) enum Reflector<T> {
     NoReflector,
     ForwardReflector { smask: T },
 }
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
 /// Different processing strategies based on data length
 pub(crate) enum DataChunkProcessor {
     From0To15,   // 0-15 bytes
@@ -144,7 +344,12 @@ pub(crate) enum DataChunkProcessor {
     From32To255, // 32-255 bytes
 }
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
 impl DataChunkProcessor {
     /// Select the appropriate processor based on data length
     pub fn for_length(len: usize) -> Self {
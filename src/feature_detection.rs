@@ -5,66 +5,319 @@
 
 #[cfg(all(
     not(feature = "std"),
-    any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
 ))]
 use spin::Once;
 #[cfg(all(
     feature = "std",
-    any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
 ))]
 use std::sync::OnceLock;
 
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "riscv64"
+))]
+use core::sync::atomic::{AtomicU8, Ordering};
+
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 extern crate alloc;
 #[cfg(all(
     feature = "alloc",
     not(feature = "std"),
-    any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
 ))]
 use alloc::string::{String, ToString};
 #[cfg(any(
     test,
     all(
         feature = "std",
-        any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+        any(
+            target_arch = "aarch64",
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv64"
+        )
     )
 ))]
 use std::string::{String, ToString};
 
-/// Global ArchOps instance cache - initialized once based on feature detection results
+/// Global capabilities cache - detected once
 #[cfg(all(
     feature = "std",
-    any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
 ))]
-static ARCH_OPS_INSTANCE: OnceLock<ArchOpsInstance> = OnceLock::new();
+static ARCH_CAPABILITIES: OnceLock<ArchCapabilities> = OnceLock::new();
 #[cfg(all(
     not(feature = "std"),
-    any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
 ))]
-static ARCH_OPS_INSTANCE: Once<ArchOpsInstance> = Once::new();
+static ARCH_CAPABILITIES: Once<ArchCapabilities> = Once::new();
 
-/// Performance tiers representing different hardware capability levels
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[allow(dead_code)] // Some variants may not be constructed on all target architectures
+/// Global ArchOps instance cache - initialized once based on feature detection results, along with
+/// whether [FORCE_TIER_ENV] chose it
+#[cfg(all(
+    feature = "std",
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
+))]
+static ARCH_OPS_INSTANCE: OnceLock<(ArchOpsInstance, bool)> = OnceLock::new();
+#[cfg(all(
+    not(feature = "std"),
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
+))]
+static ARCH_OPS_INSTANCE: Once<(ArchOpsInstance, bool)> = Once::new();
+
+/// Process-wide tier override set through [set_tier_override], stored as the index into
+/// [PerformanceTier::ALL] plus one, or zero when there's no override
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "riscv64"
+))]
+static TIER_OVERRIDE: AtomicU8 = AtomicU8::new(0);
+
+/// Environment variable which forces a performance tier, by [name](PerformanceTier::name), when
+/// the library initializes (`std` only)
+pub const FORCE_TIER_ENV: &str = "CRC_FAST_FORCE_TIER";
+
+/// Performance tiers (backends) representing different hardware capability levels.
+///
+/// Each tier is a distinct implementation, and every tier produces identical results. Use
+/// [available_backends](crate::available_backends) to see which tiers this CPU supports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum PerformanceTier {
     // AArch64 tiers
+    /// AArch64 with NEON, PMULL and SHA3 (EOR3)
     AArch64AesSha3,
+    /// AArch64 with NEON and PMULL
     AArch64Aes,
 
     // x86_64 tiers
+    /// x86_64 with AVX-512 and 512-bit VPCLMULQDQ
     X86_64Avx512Vpclmulqdq,
+    /// x86_64 with AVX2 and 256-bit VPCLMULQDQ
+    X86_64Avx2Vpclmulqdq,
+    /// x86_64 with AVX-512 ternary logic and PCLMULQDQ
     X86_64Avx512Pclmulqdq,
+    /// x86_64 with SSE4.1 and PCLMULQDQ
     X86_64SsePclmulqdq,
 
     // x86 tiers
+    /// 32-bit x86 with SSE4.1 and PCLMULQDQ
     X86SsePclmulqdq,
 
+    // riscv64 tiers
+    /// RISC-V 64-bit with the V and Zvbc vector carry-less multiplication extensions
+    Riscv64Zvbc,
+    /// RISC-V 64-bit with the Zbc scalar carry-less multiplication extension
+    Riscv64Zbc,
+
     // Fallback
+    /// Table-driven software implementation, available everywhere
     SoftwareTable,
 }
 
-/// Architecture-specific capabilities
-#[derive(Debug, Clone, Copy)]
+impl PerformanceTier {
+    /// Every tier, in order of preference within each architecture
+    const ALL: [PerformanceTier; 10] = [
+        PerformanceTier::AArch64AesSha3,
+        PerformanceTier::AArch64Aes,
+        PerformanceTier::X86_64Avx512Vpclmulqdq,
+        PerformanceTier::X86_64Avx2Vpclmulqdq,
+        PerformanceTier::X86_64Avx512Pclmulqdq,
+        PerformanceTier::X86_64SsePclmulqdq,
+        PerformanceTier::X86SsePclmulqdq,
+        PerformanceTier::Riscv64Zvbc,
+        PerformanceTier::Riscv64Zbc,
+        PerformanceTier::SoftwareTable,
+    ];
+
+    /// Returns the stable name of this tier, such as `x86_64-avx512-vpclmulqdq`.
+    ///
+    /// The format is `{architecture}-{intrinsics-family}-{intrinsics-features}`, and these names
+    /// are accepted by [from_name](PerformanceTier::from_name) and [FORCE_TIER_ENV].
+    pub const fn name(self) -> &'static str {
+        match self {
+            PerformanceTier::AArch64AesSha3 => "aarch64-neon-pmull-sha3",
+            PerformanceTier::AArch64Aes => "aarch64-neon-pmull",
+            PerformanceTier::X86_64Avx512Vpclmulqdq => "x86_64-avx512-vpclmulqdq",
+            PerformanceTier::X86_64Avx2Vpclmulqdq => "x86_64-avx2-vpclmulqdq",
+            PerformanceTier::X86_64Avx512Pclmulqdq => "x86_64-avx512-pclmulqdq",
+            PerformanceTier::X86_64SsePclmulqdq => "x86_64-sse-pclmulqdq",
+            PerformanceTier::X86SsePclmulqdq => "x86-sse-pclmulqdq",
+            PerformanceTier::Riscv64Zvbc => "riscv64-rvv-zvbc",
+            PerformanceTier::Riscv64Zbc => "riscv64-zbc-clmul",
+            PerformanceTier::SoftwareTable => "software-fallback-tables",
+        }
+    }
+
+    /// Looks up a tier by its [name](PerformanceTier::name).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|tier| tier.name() == name)
+    }
+
+    /// Returns whether this CPU (and the current build) supports this tier.
+    pub fn is_available(self) -> bool {
+        if self == PerformanceTier::SoftwareTable {
+            return true;
+        }
+
+        #[cfg(any(
+            target_arch = "aarch64",
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv64"
+        ))]
+        {
+            tier_supported(get_arch_capabilities(), self)
+        }
+
+        #[cfg(not(any(
+            target_arch = "aarch64",
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv64"
+        )))]
+        {
+            false
+        }
+    }
+
+    /// Explains why this CPU (or the current build) doesn't support this tier, such as
+    /// `avx512vl missing`, or returns `None` if it's available.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::PerformanceTier;
+    ///
+    /// assert_eq!(PerformanceTier::SoftwareTable.unavailable_reason(), None);
+    /// ```
+    pub fn unavailable_reason(self) -> Option<&'static str> {
+        if self.is_available() {
+            return None;
+        }
+
+        if !self.is_native() {
+            return Some("not supported on this architecture");
+        }
+
+        #[cfg(any(
+            target_arch = "aarch64",
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv64"
+        ))]
+        {
+            Some(missing_feature(get_arch_capabilities(), self))
+        }
+
+        #[cfg(not(any(
+            target_arch = "aarch64",
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv64"
+        )))]
+        {
+            Some("not supported on this architecture")
+        }
+    }
+
+    /// Returns the tiers this CPU supports, in order of preference, ending with
+    /// [SoftwareTable](PerformanceTier::SoftwareTable).
+    pub(crate) fn available() -> impl Iterator<Item = PerformanceTier> {
+        Self::ALL.into_iter().filter(|tier| tier.is_available())
+    }
+
+    /// Returns the tiers for the architecture this was built for, whether or not this CPU
+    /// supports them, in order of preference.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn native() -> impl Iterator<Item = PerformanceTier> {
+        Self::ALL.into_iter().filter(|tier| tier.is_native())
+    }
+
+    /// Whether this tier is for the architecture this was built for, which the software tables
+    /// always are
+    const fn is_native(self) -> bool {
+        match self {
+            PerformanceTier::AArch64AesSha3 | PerformanceTier::AArch64Aes => {
+                cfg!(target_arch = "aarch64")
+            }
+            PerformanceTier::X86_64Avx512Vpclmulqdq
+            | PerformanceTier::X86_64Avx2Vpclmulqdq
+            | PerformanceTier::X86_64Avx512Pclmulqdq
+            | PerformanceTier::X86_64SsePclmulqdq => cfg!(target_arch = "x86_64"),
+            PerformanceTier::X86SsePclmulqdq => cfg!(target_arch = "x86"),
+            PerformanceTier::Riscv64Zvbc | PerformanceTier::Riscv64Zbc => {
+                cfg!(target_arch = "riscv64")
+            }
+            PerformanceTier::SoftwareTable => true,
+        }
+    }
+}
+
+impl core::fmt::Display for PerformanceTier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Error returned when a requested [PerformanceTier] isn't supported by this CPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackendUnavailable(pub PerformanceTier);
+
+impl core::fmt::Display for BackendUnavailable {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CRC backend {} is not available on this CPU", self.0)
+    }
+}
+
+impl core::error::Error for BackendUnavailable {}
+
+/// Architecture-specific capabilities, as detected on this CPU.
+///
+/// Only the fields for the architecture this was built for can be `true`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 #[allow(dead_code)] // Some fields may not be read on all target architectures
 pub struct ArchCapabilities {
     // AArch64 features
@@ -76,8 +329,49 @@ pub struct ArchCapabilities {
     pub has_sse41: bool,
     pub has_sse42: bool, // provides native CRC32C instructions for fusion techniques
     pub has_pclmulqdq: bool,
+    pub has_avx2: bool, // provides the 256-bit integer operations for VPCLMULQDQ without AVX-512
     pub has_avx512vl: bool, // implicitly enables avx512f, has XOR3 operations
-    pub has_vpclmulqdq: bool,
+    pub has_vpclmulqdq: bool, // requires 'avx512vl' or 'avx2'
+
+    // riscv64 features
+    pub has_zbc: bool,  // provides the scalar clmul/clmulh instructions
+    pub has_zvbc: bool, // requires 'zbc' and the V extension, provides vclmul/vclmulh
+}
+
+impl ArchCapabilities {
+    /// No capabilities, as on architectures without hardware acceleration
+    #[allow(dead_code)]
+    const NONE: ArchCapabilities = ArchCapabilities {
+        has_aes: false,
+        has_crc: false,
+        has_sha3: false,
+        has_sse41: false,
+        has_sse42: false,
+        has_pclmulqdq: false,
+        has_avx2: false,
+        has_avx512vl: false,
+        has_vpclmulqdq: false,
+        has_zbc: false,
+        has_zvbc: false,
+    };
+
+    /// Returns every capability by its feature name, such as `avx512vl`, along with whether it
+    /// was detected.
+    pub fn features(&self) -> [(&'static str, bool); 11] {
+        [
+            ("aes", self.has_aes),
+            ("crc", self.has_crc),
+            ("sha3", self.has_sha3),
+            ("sse4.1", self.has_sse41),
+            ("sse4.2", self.has_sse42),
+            ("pclmulqdq", self.has_pclmulqdq),
+            ("avx2", self.has_avx2),
+            ("avx512vl", self.has_avx512vl),
+            ("vpclmulqdq", self.has_vpclmulqdq),
+            ("zbc", self.has_zbc),
+            ("zvbc", self.has_zvbc),
+        ]
+    }
 }
 
 /// Helper function to convert a performance tier to a human-readable target string
@@ -86,27 +380,29 @@ pub struct ArchCapabilities {
     test,
     all(
         feature = "alloc",
-        any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+        any(
+            target_arch = "aarch64",
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv64"
+        )
     )
 ))]
 #[inline(always)]
 fn tier_to_target_string(tier: PerformanceTier) -> String {
-    match tier {
-        PerformanceTier::AArch64AesSha3 => "aarch64-neon-pmull-sha3".to_string(),
-        PerformanceTier::AArch64Aes => "aarch64-neon-pmull".to_string(),
-        PerformanceTier::X86_64Avx512Vpclmulqdq => "x86_64-avx512-vpclmulqdq".to_string(),
-        PerformanceTier::X86_64Avx512Pclmulqdq => "x86_64-avx512-pclmulqdq".to_string(),
-        PerformanceTier::X86_64SsePclmulqdq => "x86_64-sse-pclmulqdq".to_string(),
-        PerformanceTier::X86SsePclmulqdq => "x86-sse-pclmulqdq".to_string(),
-        PerformanceTier::SoftwareTable => "software-fallback-tables".to_string(),
-    }
+    tier.name().to_string()
 }
 
 /// Detect architecture-specific capabilities combining compile-time and runtime checks
 ///
 /// # Safety
 /// Uses runtime feature detection which may access CPU-specific registers
-#[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "riscv64"
+))]
 unsafe fn detect_arch_capabilities() -> ArchCapabilities {
     #[cfg(target_arch = "aarch64")]
     {
@@ -118,7 +414,17 @@ unsafe fn detect_arch_capabilities() -> ArchCapabilities {
         detect_x86_features()
     }
 
-    #[cfg(not(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")))]
+    #[cfg(target_arch = "riscv64")]
+    {
+        detect_riscv64_features()
+    }
+
+    #[cfg(not(any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )))]
     {
         // Other architectures use software fallback
         ArchCapabilities {
@@ -128,8 +434,11 @@ unsafe fn detect_arch_capabilities() -> ArchCapabilities {
             has_sse41: false,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_zbc: false,
+            has_zvbc: false,
         }
     }
 }
@@ -160,8 +469,11 @@ unsafe fn detect_aarch64_features() -> ArchCapabilities {
         has_sse41: false,
         has_sse42: false,
         has_pclmulqdq: false,
+        has_avx2: false,
         has_avx512vl: false,
         has_vpclmulqdq: false,
+        has_zbc: false,
+        has_zvbc: false,
     }
 }
 
@@ -175,8 +487,11 @@ unsafe fn detect_aarch64_features() -> ArchCapabilities {
         has_sse41: false,
         has_sse42: false,
         has_pclmulqdq: false,
+        has_avx2: false,
         has_avx512vl: false,
         has_vpclmulqdq: false,
+        has_zbc: false,
+        has_zvbc: false,
     }
 }
 
@@ -190,8 +505,9 @@ unsafe fn detect_x86_features() -> ArchCapabilities {
     let has_sse41 = is_x86_feature_detected!("sse4.1");
     let has_pclmulqdq = has_sse41 && is_x86_feature_detected!("pclmulqdq");
 
+    let has_avx2 = has_pclmulqdq && is_x86_feature_detected!("avx2");
     let has_avx512vl = has_pclmulqdq && is_x86_feature_detected!("avx512vl");
-    let has_vpclmulqdq = has_avx512vl && is_x86_feature_detected!("vpclmulqdq");
+    let has_vpclmulqdq = (has_avx512vl || has_avx2) && is_x86_feature_detected!("vpclmulqdq");
     // SSE 4.2 provides native CRC32C instructions for fusion techniques
     let has_sse42 = is_x86_feature_detected!("sse4.2");
 
@@ -202,19 +518,27 @@ unsafe fn detect_x86_features() -> ArchCapabilities {
         has_sse41,
         has_sse42,
         has_pclmulqdq,
+        has_avx2,
         has_avx512vl,
         has_vpclmulqdq,
+        has_zbc: false,
+        has_zvbc: false,
     }
 }
 
 #[inline(always)]
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "std")))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "std"),
+    not(feature = "cpuid")
+))]
 unsafe fn detect_x86_features() -> ArchCapabilities {
     let has_sse41 = cfg!(target_feature = "sse4.1");
     let has_sse42 = cfg!(target_feature = "sse4.2");
     let has_pclmulqdq = has_sse41 && cfg!(target_feature = "pclmulqdq");
+    let has_avx2 = has_pclmulqdq && cfg!(target_feature = "avx2");
     let has_avx512vl = has_pclmulqdq && cfg!(target_feature = "avx512vl");
-    let has_vpclmulqdq = has_avx512vl && cfg!(target_feature = "vpclmulqdq");
+    let has_vpclmulqdq = (has_avx512vl || has_avx2) && cfg!(target_feature = "vpclmulqdq");
 
     ArchCapabilities {
         has_aes: false,
@@ -223,8 +547,191 @@ unsafe fn detect_x86_features() -> ArchCapabilities {
         has_sse41,
         has_sse42,
         has_pclmulqdq,
+        has_avx2,
+        has_avx512vl,
+        has_vpclmulqdq,
+        has_zbc: false,
+        has_zvbc: false,
+    }
+}
+
+/// x86/x86_64 runtime feature detection for `no_std` builds, using CPUID and XGETBV directly
+#[inline(always)]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "std"),
+    feature = "cpuid"
+))]
+unsafe fn detect_x86_features() -> ArchCapabilities {
+    detect_x86_features_cpuid()
+}
+
+/// XCR0 bits for the SSE and AVX state
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const XCR0_AVX: u64 = 0b110;
+
+/// XCR0 bits for the SSE and AVX state, and the AVX-512 opmask and upper ZMM state
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const XCR0_AVX512: u64 = 0b1110_0110;
+
+/// The raw CPUID feature registers, and XCR0 once the OS has enabled XSAVE
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[derive(Default)]
+struct X86Registers {
+    leaf1_ecx: u32,
+    leaf7_ebx: u32,
+    leaf7_ecx: u32,
+    xcr0: u64,
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(dead_code)]
+impl X86Registers {
+    fn bit(register: u32, bit: u32) -> bool {
+        register & (1 << bit) != 0
+    }
+
+    /// Whether the OS saves the YMM register state
+    fn os_avx(&self) -> bool {
+        Self::bit(self.leaf1_ecx, 28) && self.xcr0 & XCR0_AVX == XCR0_AVX
+    }
+
+    /// Whether the OS saves the AVX-512 opmask and ZMM register state
+    fn os_avx512(&self) -> bool {
+        self.os_avx() && self.xcr0 & XCR0_AVX512 == XCR0_AVX512
+    }
+
+    fn cpu_avx2(&self) -> bool {
+        Self::bit(self.leaf7_ebx, 5)
+    }
+
+    /// AVX-512 Foundation and Vector Length extensions
+    fn cpu_avx512vl(&self) -> bool {
+        Self::bit(self.leaf7_ebx, 16) && Self::bit(self.leaf7_ebx, 31)
+    }
+}
+
+/// Reads the CPUID feature leaves, and XCR0 via XGETBV
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(dead_code)]
+unsafe fn read_x86_registers() -> X86Registers {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{__cpuid, __cpuid_count, _xgetbv, has_cpuid};
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{__cpuid, __cpuid_count, _xgetbv};
+
+    #[target_feature(enable = "xsave")]
+    unsafe fn read_xcr0() -> u64 {
+        _xgetbv(0)
+    }
+
+    let mut registers = X86Registers::default();
+
+    // very old 32-bit CPUs may not support CPUID at all
+    #[cfg(target_arch = "x86")]
+    if !has_cpuid() {
+        return registers;
+    }
+
+    let max_leaf = __cpuid(0).eax;
+    if max_leaf < 1 {
+        return registers;
+    }
+
+    registers.leaf1_ecx = __cpuid(1).ecx;
+
+    // XGETBV is only available once the OS has enabled XSAVE
+    if X86Registers::bit(registers.leaf1_ecx, 27) {
+        registers.xcr0 = read_xcr0();
+    }
+
+    if max_leaf >= 7 {
+        let leaf7 = __cpuid_count(7, 0);
+        registers.leaf7_ebx = leaf7.ebx;
+        registers.leaf7_ecx = leaf7.ecx;
+    }
+
+    registers
+}
+
+/// Reads the x86/x86_64 capabilities from CPUID, only trusting AVX and AVX-512 when XGETBV
+/// reports the OS saves their register state.
+///
+/// This matches what `is_x86_feature_detected!` checks, for environments (kernels, bootloaders)
+/// which have no `std` but still run on a wide range of hardware.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    any(test, all(not(feature = "std"), feature = "cpuid"))
+))]
+unsafe fn detect_x86_features_cpuid() -> ArchCapabilities {
+    let registers = read_x86_registers();
+    let bit = X86Registers::bit;
+
+    let has_sse41 = bit(registers.leaf1_ecx, 19);
+    let has_sse42 = bit(registers.leaf1_ecx, 20);
+    let has_pclmulqdq = has_sse41 && bit(registers.leaf1_ecx, 1);
+
+    let has_avx2 = has_pclmulqdq && registers.os_avx() && registers.cpu_avx2();
+    let has_avx512vl = has_pclmulqdq && registers.os_avx512() && registers.cpu_avx512vl();
+    let has_vpclmulqdq = (has_avx512vl || has_avx2) && bit(registers.leaf7_ecx, 10);
+
+    ArchCapabilities {
+        has_sse41,
+        has_sse42,
+        has_pclmulqdq,
+        has_avx2,
         has_avx512vl,
         has_vpclmulqdq,
+        ..ArchCapabilities::NONE
+    }
+}
+
+/// RISC-V 64-bit feature detection
+///
+/// Zbc is detected at runtime, but detecting the vector extensions isn't stable yet, so the
+/// Zvbc tier is only used when the build targets both V and Zvbc (such as with
+/// `-C target-feature=+v,+zvbc`).
+#[inline(always)]
+#[cfg(all(target_arch = "riscv64", feature = "std"))]
+unsafe fn detect_riscv64_features() -> ArchCapabilities {
+    use std::arch::is_riscv_feature_detected;
+
+    let has_zbc = is_riscv_feature_detected!("zbc");
+    let has_zvbc = has_zbc && cfg!(all(target_feature = "v", target_feature = "zvbc"));
+
+    ArchCapabilities {
+        has_aes: false,
+        has_crc: false,
+        has_sha3: false,
+        has_sse41: false,
+        has_sse42: false,
+        has_pclmulqdq: false,
+        has_avx2: false,
+        has_avx512vl: false,
+        has_vpclmulqdq: false,
+        has_zbc,
+        has_zvbc,
+    }
+}
+
+#[inline(always)]
+#[cfg(all(target_arch = "riscv64", not(feature = "std")))]
+unsafe fn detect_riscv64_features() -> ArchCapabilities {
+    let has_zbc = cfg!(target_feature = "zbc");
+    let has_zvbc = has_zbc && cfg!(all(target_feature = "v", target_feature = "zvbc"));
+
+    ArchCapabilities {
+        has_aes: false,
+        has_crc: false,
+        has_sha3: false,
+        has_sse41: false,
+        has_sse42: false,
+        has_pclmulqdq: false,
+        has_avx2: false,
+        has_avx512vl: false,
+        has_vpclmulqdq: false,
+        has_zbc,
+        has_zvbc,
     }
 }
 
@@ -245,9 +752,12 @@ pub(crate) fn select_performance_tier(capabilities: &ArchCapabilities) -> Perfor
 
     #[cfg(target_arch = "x86_64")]
     {
-        if capabilities.has_vpclmulqdq {
+        if capabilities.has_vpclmulqdq && capabilities.has_avx512vl {
             return PerformanceTier::X86_64Avx512Vpclmulqdq;
         }
+        if capabilities.has_vpclmulqdq && capabilities.has_avx2 {
+            return PerformanceTier::X86_64Avx2Vpclmulqdq;
+        }
         if capabilities.has_avx512vl {
             return PerformanceTier::X86_64Avx512Pclmulqdq;
         }
@@ -263,13 +773,167 @@ pub(crate) fn select_performance_tier(capabilities: &ArchCapabilities) -> Perfor
         }
     }
 
+    #[cfg(target_arch = "riscv64")]
+    {
+        if capabilities.has_zvbc {
+            return PerformanceTier::Riscv64Zvbc;
+        }
+        if capabilities.has_zbc {
+            return PerformanceTier::Riscv64Zbc;
+        }
+    }
+
     // Fallback to software implementation
     PerformanceTier::SoftwareTable
 }
 
+/// Whether the detected capabilities support a performance tier on this architecture
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "riscv64"
+))]
+fn tier_supported(capabilities: &ArchCapabilities, tier: PerformanceTier) -> bool {
+    match tier {
+        #[cfg(target_arch = "aarch64")]
+        PerformanceTier::AArch64AesSha3 => capabilities.has_aes && capabilities.has_sha3,
+        #[cfg(target_arch = "aarch64")]
+        PerformanceTier::AArch64Aes => capabilities.has_aes,
+        #[cfg(target_arch = "x86_64")]
+        PerformanceTier::X86_64Avx512Vpclmulqdq => {
+            capabilities.has_vpclmulqdq && capabilities.has_avx512vl
+        }
+        #[cfg(target_arch = "x86_64")]
+        PerformanceTier::X86_64Avx2Vpclmulqdq => {
+            capabilities.has_vpclmulqdq && capabilities.has_avx2
+        }
+        #[cfg(target_arch = "x86_64")]
+        PerformanceTier::X86_64Avx512Pclmulqdq => capabilities.has_avx512vl,
+        #[cfg(target_arch = "x86_64")]
+        PerformanceTier::X86_64SsePclmulqdq => capabilities.has_pclmulqdq,
+        #[cfg(target_arch = "x86")]
+        PerformanceTier::X86SsePclmulqdq => capabilities.has_pclmulqdq,
+        #[cfg(target_arch = "riscv64")]
+        PerformanceTier::Riscv64Zvbc => capabilities.has_zvbc,
+        #[cfg(target_arch = "riscv64")]
+        PerformanceTier::Riscv64Zbc => capabilities.has_zbc,
+        PerformanceTier::SoftwareTable => true,
+        // tiers for other architectures
+        _ => false,
+    }
+}
+
+/// The feature a tier's capability check found missing, for tiers of this architecture
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "riscv64"
+))]
+fn missing_feature(capabilities: &ArchCapabilities, tier: PerformanceTier) -> &'static str {
+    match tier {
+        PerformanceTier::AArch64AesSha3 if capabilities.has_aes => "sha3 missing",
+        PerformanceTier::AArch64AesSha3 | PerformanceTier::AArch64Aes => "aes (pmull) missing",
+        PerformanceTier::X86_64Avx512Vpclmulqdq
+        | PerformanceTier::X86_64Avx2Vpclmulqdq
+        | PerformanceTier::X86_64Avx512Pclmulqdq
+        | PerformanceTier::X86_64SsePclmulqdq
+        | PerformanceTier::X86SsePclmulqdq => {
+            if !capabilities.has_sse41 {
+                "sse4.1 missing"
+            } else if !capabilities.has_pclmulqdq {
+                "pclmulqdq missing"
+            } else {
+                match tier {
+                    PerformanceTier::X86_64Avx512Vpclmulqdq
+                    | PerformanceTier::X86_64Avx512Pclmulqdq
+                        if !capabilities.has_avx512vl =>
+                    {
+                        x86_avx_missing(true)
+                    }
+                    PerformanceTier::X86_64Avx2Vpclmulqdq if !capabilities.has_avx2 => {
+                        x86_avx_missing(false)
+                    }
+                    _ => "vpclmulqdq missing",
+                }
+            }
+        }
+        PerformanceTier::Riscv64Zvbc if capabilities.has_zbc => {
+            "v and zvbc not enabled at compile time"
+        }
+        PerformanceTier::Riscv64Zvbc | PerformanceTier::Riscv64Zbc => "zbc missing",
+        PerformanceTier::SoftwareTable => "always available",
+    }
+}
+
+/// Tells apart CPUs without AVX-512 (or AVX2) from OSes which don't save the ZMM (or YMM)
+/// register state, which feature detection also reports as the feature missing
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "riscv64"
+))]
+fn x86_avx_missing(avx512: bool) -> &'static str {
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(miri)))]
+    {
+        let registers = unsafe { read_x86_registers() };
+
+        if avx512 && registers.cpu_avx512vl() && !registers.os_avx512() {
+            return "OS has not enabled ZMM state";
+        }
+
+        if !avx512 && registers.cpu_avx2() && !registers.os_avx() {
+            return "OS has not enabled YMM state";
+        }
+    }
+
+    if avx512 {
+        "avx512vl missing"
+    } else {
+        "avx2 missing"
+    }
+}
+
+/// Returns the capabilities detected on this CPU
+#[cfg(feature = "alloc")]
+pub(crate) fn get_detected_capabilities() -> ArchCapabilities {
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    ))]
+    {
+        *get_arch_capabilities()
+    }
+
+    #[cfg(not(any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )))]
+    {
+        ArchCapabilities::NONE
+    }
+}
+
+/// Returns the tier selected from the detected capabilities, ignoring any forced tier
+#[cfg(feature = "alloc")]
+pub(crate) fn get_detected_tier() -> PerformanceTier {
+    select_performance_tier(&get_detected_capabilities())
+}
+
 /// Enum that holds the different ArchOps implementations for compile-time dispatch
 /// This avoids the need for trait objects while still providing factory-based selection
-#[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "riscv64"
+))]
 #[derive(Debug, Clone, Copy)]
 pub enum ArchOpsInstance {
     #[cfg(target_arch = "aarch64")]
@@ -282,11 +946,22 @@ pub enum ArchOpsInstance {
     X86_64Avx512Pclmulqdq(crate::arch::x86_64::avx512::X86_64Avx512PclmulqdqOps),
     #[cfg(target_arch = "x86_64")]
     X86_64Avx512Vpclmulqdq(crate::arch::x86_64::avx512_vpclmulqdq::X86_64Avx512VpclmulqdqOps),
+    #[cfg(target_arch = "x86_64")]
+    X86_64Avx2Vpclmulqdq(crate::arch::x86_64::avx2_vpclmulqdq::X86_64Avx2VpclmulqdqOps),
+    #[cfg(target_arch = "riscv64")]
+    Riscv64Zbc(crate::arch::riscv64::zbc::Riscv64ZbcOps),
+    #[cfg(target_arch = "riscv64")]
+    Riscv64Zvbc(crate::arch::riscv64::zvbc::Riscv64ZvbcOps),
     /// Software fallback - no ArchOps struct needed
     SoftwareFallback,
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "riscv64"
+))]
 impl ArchOpsInstance {
     #[inline(always)]
     pub fn get_tier(&self) -> PerformanceTier {
@@ -295,12 +970,20 @@ impl ArchOpsInstance {
             ArchOpsInstance::Aarch64Aes(_) => PerformanceTier::AArch64Aes,
             #[cfg(target_arch = "aarch64")]
             ArchOpsInstance::Aarch64AesSha3(_) => PerformanceTier::AArch64AesSha3,
-            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[cfg(target_arch = "x86")]
             ArchOpsInstance::X86SsePclmulqdq(_) => PerformanceTier::X86SsePclmulqdq,
             #[cfg(target_arch = "x86_64")]
+            ArchOpsInstance::X86SsePclmulqdq(_) => PerformanceTier::X86_64SsePclmulqdq,
+            #[cfg(target_arch = "x86_64")]
             ArchOpsInstance::X86_64Avx512Pclmulqdq(_) => PerformanceTier::X86_64Avx512Pclmulqdq,
             #[cfg(target_arch = "x86_64")]
             ArchOpsInstance::X86_64Avx512Vpclmulqdq(_) => PerformanceTier::X86_64Avx512Vpclmulqdq,
+            #[cfg(target_arch = "x86_64")]
+            ArchOpsInstance::X86_64Avx2Vpclmulqdq(_) => PerformanceTier::X86_64Avx2Vpclmulqdq,
+            #[cfg(target_arch = "riscv64")]
+            ArchOpsInstance::Riscv64Zbc(_) => PerformanceTier::Riscv64Zbc,
+            #[cfg(target_arch = "riscv64")]
+            ArchOpsInstance::Riscv64Zvbc(_) => PerformanceTier::Riscv64Zvbc,
             ArchOpsInstance::SoftwareFallback => PerformanceTier::SoftwareTable,
         }
     }
@@ -308,7 +991,12 @@ impl ArchOpsInstance {
     /// Get a human-readable target string describing the active configuration
     #[cfg(all(
         feature = "alloc",
-        any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+        any(
+            target_arch = "aarch64",
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv64"
+        )
     ))]
     #[inline(always)]
     pub fn get_target_string(&self) -> String {
@@ -320,39 +1008,276 @@ impl ArchOpsInstance {
 ///
 /// This function provides access to the cached ArchOps instance that was selected based on
 /// feature detection results at library initialization time, eliminating runtime feature
-/// detection overhead from hot paths.
+/// detection overhead from hot paths. A tier set through [set_tier_override] takes precedence.
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "riscv64"
+))]
+#[inline(always)]
+pub fn get_arch_ops() -> ArchOpsInstance {
+    match get_tier_override() {
+        Some(tier) => create_arch_ops_from_tier(tier),
+        None => get_arch_ops_state().0,
+    }
+}
+
+/// Why the tier named by [FORCE_TIER_ENV] was ignored, such as `unknown backend "avx512"`, or
+/// `None` if it wasn't set or was used
+#[cfg(feature = "alloc")]
+pub(crate) fn get_force_tier_rejection() -> Option<&'static str> {
+    #[cfg(all(
+        feature = "std",
+        any(
+            target_arch = "aarch64",
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv64"
+        )
+    ))]
+    {
+        // the variable is only read when the library initializes
+        get_arch_ops_state();
+
+        FORCE_TIER_REJECTION.get().map(String::as_str)
+    }
+
+    #[cfg(not(all(
+        feature = "std",
+        any(
+            target_arch = "aarch64",
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv64"
+        )
+    )))]
+    {
+        None
+    }
+}
+
+/// Whether the active tier was forced through [set_tier_override] or [FORCE_TIER_ENV], rather
+/// than selected from the detected capabilities
+#[cfg(all(
+    any(feature = "crc32", feature = "alloc"),
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
+))]
+#[inline(always)]
+pub(crate) fn is_tier_forced() -> bool {
+    get_tier_override().is_some() || get_arch_ops_state().1
+}
+
 #[cfg(all(
     feature = "std",
-    any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
 ))]
-pub fn get_arch_ops() -> &'static ArchOpsInstance {
+fn get_arch_ops_state() -> &'static (ArchOpsInstance, bool) {
     ARCH_OPS_INSTANCE.get_or_init(create_arch_ops)
 }
 
 #[cfg(all(
     not(feature = "std"),
-    any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
 ))]
-pub fn get_arch_ops() -> &'static ArchOpsInstance {
+fn get_arch_ops_state() -> &'static (ArchOpsInstance, bool) {
     ARCH_OPS_INSTANCE.call_once(create_arch_ops)
 }
 
+/// Get the detected architecture capabilities (thread-safe, detected once)
+#[cfg(all(
+    feature = "std",
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
+))]
+fn get_arch_capabilities() -> &'static ArchCapabilities {
+    ARCH_CAPABILITIES.get_or_init(|| unsafe { detect_arch_capabilities() })
+}
+
+#[cfg(all(
+    not(feature = "std"),
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
+))]
+fn get_arch_capabilities() -> &'static ArchCapabilities {
+    ARCH_CAPABILITIES.call_once(|| unsafe { detect_arch_capabilities() })
+}
+
+/// Get the process-wide tier override, if one is set
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "riscv64"
+))]
+#[inline(always)]
+fn get_tier_override() -> Option<PerformanceTier> {
+    match TIER_OVERRIDE.load(Ordering::Relaxed) {
+        0 => None,
+        index => Some(PerformanceTier::ALL[index as usize - 1]),
+    }
+}
+
+/// Sets (or with `None`, clears) the process-wide tier override
+///
+/// Returns an error, leaving the current tier in place, if the tier isn't available.
+pub(crate) fn set_tier_override(tier: Option<PerformanceTier>) -> Result<(), BackendUnavailable> {
+    if let Some(tier) = tier {
+        if !tier.is_available() {
+            return Err(BackendUnavailable(tier));
+        }
+    }
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    ))]
+    {
+        let index = tier.map_or(0, |tier| {
+            PerformanceTier::ALL
+                .iter()
+                .position(|candidate| *candidate == tier)
+                .expect("every tier is listed")
+                + 1
+        });
+
+        TIER_OVERRIDE.store(index as u8, Ordering::Relaxed);
+    }
+
+    Ok(())
+}
+
+/// Get the tier currently used by the dispatching APIs
+pub(crate) fn get_active_tier() -> PerformanceTier {
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    ))]
+    {
+        get_arch_ops().get_tier()
+    }
+
+    #[cfg(not(any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )))]
+    {
+        PerformanceTier::SoftwareTable
+    }
+}
+
 /// Factory function that creates the appropriate ArchOps struct based on cached feature detection
 ///
 /// This function uses the cached feature detection results to select the optimal
 /// architecture-specific implementation at library initialization time, eliminating
-/// runtime feature detection overhead from hot paths.
-#[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
-fn create_arch_ops() -> ArchOpsInstance {
-    let capabilities = unsafe { detect_arch_capabilities() };
-    let tier = select_performance_tier(&capabilities);
+/// runtime feature detection overhead from hot paths. An available tier named by
+/// [FORCE_TIER_ENV] is used instead, if set.
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "riscv64"
+))]
+fn create_arch_ops() -> (ArchOpsInstance, bool) {
+    if let Some(tier) = get_env_forced_tier() {
+        return (create_arch_ops_from_tier(tier), true);
+    }
+
+    let tier = select_performance_tier(get_arch_capabilities());
 
-    create_arch_ops_from_tier(tier)
+    (create_arch_ops_from_tier(tier), false)
+}
+
+/// Why the tier named by [FORCE_TIER_ENV] was ignored when the library initialized, if it was
+#[cfg(all(
+    feature = "std",
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
+))]
+static FORCE_TIER_REJECTION: OnceLock<String> = OnceLock::new();
+
+/// Reads the tier named by [FORCE_TIER_ENV], ignoring unknown or unavailable tiers, and recording
+/// why for [get_force_tier_rejection]
+#[cfg(all(
+    feature = "std",
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
+))]
+fn get_env_forced_tier() -> Option<PerformanceTier> {
+    let name = std::env::var(FORCE_TIER_ENV).ok()?;
+    let name = name.trim();
+
+    let rejection = match PerformanceTier::from_name(name) {
+        Some(tier) => match tier.unavailable_reason() {
+            None => return Some(tier),
+            Some(reason) => format!("{tier} is unavailable, {reason}"),
+        },
+        None => format!("unknown backend {name:?}"),
+    };
+
+    let _ = FORCE_TIER_REJECTION.set(rejection);
+
+    None
+}
+
+#[cfg(all(
+    not(feature = "std"),
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
+))]
+fn get_env_forced_tier() -> Option<PerformanceTier> {
+    None
 }
 
 /// Helper function to create ArchOpsInstance from a performance tier
-#[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
-fn create_arch_ops_from_tier(tier: PerformanceTier) -> ArchOpsInstance {
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "riscv64"
+))]
+pub(crate) fn create_arch_ops_from_tier(tier: PerformanceTier) -> ArchOpsInstance {
     match tier {
         #[cfg(target_arch = "aarch64")]
         PerformanceTier::AArch64AesSha3 => {
@@ -370,6 +1295,11 @@ fn create_arch_ops_from_tier(tier: PerformanceTier) -> ArchOpsInstance {
             ArchOpsInstance::X86_64Avx512Vpclmulqdq(X86_64Avx512VpclmulqdqOps::new())
         }
         #[cfg(target_arch = "x86_64")]
+        PerformanceTier::X86_64Avx2Vpclmulqdq => {
+            use crate::arch::x86_64::avx2_vpclmulqdq::X86_64Avx2VpclmulqdqOps;
+            ArchOpsInstance::X86_64Avx2Vpclmulqdq(X86_64Avx2VpclmulqdqOps::new())
+        }
+        #[cfg(target_arch = "x86_64")]
         PerformanceTier::X86_64Avx512Pclmulqdq => {
             use crate::arch::x86_64::avx512::X86_64Avx512PclmulqdqOps;
             ArchOpsInstance::X86_64Avx512Pclmulqdq(X86_64Avx512PclmulqdqOps::new())
@@ -378,6 +1308,16 @@ fn create_arch_ops_from_tier(tier: PerformanceTier) -> ArchOpsInstance {
         PerformanceTier::X86_64SsePclmulqdq | PerformanceTier::X86SsePclmulqdq => {
             create_x86_sse_pclmulqdq_ops()
         }
+        #[cfg(target_arch = "riscv64")]
+        PerformanceTier::Riscv64Zvbc => {
+            use crate::arch::riscv64::zvbc::Riscv64ZvbcOps;
+            ArchOpsInstance::Riscv64Zvbc(Riscv64ZvbcOps::new())
+        }
+        #[cfg(target_arch = "riscv64")]
+        PerformanceTier::Riscv64Zbc => {
+            use crate::arch::riscv64::zbc::Riscv64ZbcOps;
+            ArchOpsInstance::Riscv64Zbc(Riscv64ZbcOps)
+        }
         PerformanceTier::SoftwareTable => {
             // Use software fallback
             ArchOpsInstance::SoftwareFallback
@@ -408,11 +1348,15 @@ pub fn select_performance_tier_for_test(capabilities: &ArchCapabilities) -> Perf
         return PerformanceTier::AArch64Aes;
     }
 
-    // x86_64 tier selection - VPCLMULQDQ requires AVX512VL
+    // x86_64 tier selection - VPCLMULQDQ requires AVX512VL or AVX2
     if capabilities.has_vpclmulqdq && capabilities.has_avx512vl {
         return PerformanceTier::X86_64Avx512Vpclmulqdq;
     }
 
+    if capabilities.has_vpclmulqdq && capabilities.has_avx2 {
+        return PerformanceTier::X86_64Avx2Vpclmulqdq;
+    }
+
     // AVX512VL requires PCLMULQDQ and SSE4.1
     if capabilities.has_avx512vl && capabilities.has_pclmulqdq {
         return PerformanceTier::X86_64Avx512Pclmulqdq;
@@ -423,6 +1367,15 @@ pub fn select_performance_tier_for_test(capabilities: &ArchCapabilities) -> Perf
         return PerformanceTier::X86_64SsePclmulqdq;
     }
 
+    // riscv64 tier selection - Zvbc requires Zbc
+    if capabilities.has_zvbc && capabilities.has_zbc {
+        return PerformanceTier::Riscv64Zvbc;
+    }
+
+    if capabilities.has_zbc {
+        return PerformanceTier::Riscv64Zbc;
+    }
+
     // Fallback to software implementation
     PerformanceTier::SoftwareTable
 }
@@ -443,8 +1396,11 @@ mod tests {
             has_sse41: false,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_zbc: false,
+            has_zvbc: false,
         };
         assert_eq!(
             select_performance_tier_for_test(&capabilities_sha3),
@@ -459,8 +1415,11 @@ mod tests {
             has_sse41: false,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_zbc: false,
+            has_zvbc: false,
         };
         assert_eq!(
             select_performance_tier_for_test(&capabilities_aes),
@@ -475,8 +1434,11 @@ mod tests {
             has_sse41: false,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_zbc: false,
+            has_zvbc: false,
         };
         assert_eq!(
             select_performance_tier_for_test(&capabilities_no_aes),
@@ -498,8 +1460,11 @@ mod tests {
             has_sse41: false,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_zbc: false,
+            has_zvbc: false,
         };
 
         // AES support means we have PMULL instructions available for CRC calculations
@@ -513,8 +1478,11 @@ mod tests {
             has_sse41: false,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_zbc: false,
+            has_zvbc: false,
         };
 
         assert!(capabilities_with_sha3.has_aes);
@@ -534,14 +1502,36 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: true,
+            has_avx2: false,
             has_avx512vl: true,
             has_vpclmulqdq: true,
+            has_zbc: false,
+            has_zvbc: false,
         };
         assert_eq!(
             select_performance_tier_for_test(&capabilities_vpclmulqdq),
             PerformanceTier::X86_64Avx512Vpclmulqdq
         );
 
+        // Test VPCLMULQDQ + AVX2 without AVX512 (such as Zen 3 and Alder Lake)
+        let capabilities_avx2_vpclmulqdq = ArchCapabilities {
+            has_aes: false,
+            has_crc: false,
+            has_sha3: false,
+            has_sse41: true,
+            has_sse42: false,
+            has_pclmulqdq: true,
+            has_avx2: true,
+            has_avx512vl: false,
+            has_vpclmulqdq: true,
+            has_zbc: false,
+            has_zvbc: false,
+        };
+        assert_eq!(
+            select_performance_tier_for_test(&capabilities_avx2_vpclmulqdq),
+            PerformanceTier::X86_64Avx2Vpclmulqdq
+        );
+
         // Test AVX512 + PCLMULQDQ (mid-tier)
         let capabilities_avx512 = ArchCapabilities {
             has_aes: false,
@@ -550,8 +1540,11 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: true,
+            has_avx2: false,
             has_avx512vl: true,
             has_vpclmulqdq: false,
+            has_zbc: false,
+            has_zvbc: false,
         };
         assert_eq!(
             select_performance_tier_for_test(&capabilities_avx512),
@@ -566,8 +1559,11 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: true,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_zbc: false,
+            has_zvbc: false,
         };
         assert_eq!(
             select_performance_tier_for_test(&capabilities_sse),
@@ -582,8 +1578,11 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_zbc: false,
+            has_zvbc: false,
         };
         assert_eq!(
             select_performance_tier_for_test(&capabilities_no_pclmul),
@@ -604,8 +1603,11 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: true,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_zbc: false,
+            has_zvbc: false,
         };
         assert_eq!(
             select_performance_tier_for_test(&capabilities_sse),
@@ -621,8 +1623,11 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: true,
+            has_avx2: false,
             has_avx512vl: false, // No AVX512 on 32-bit x86
             has_vpclmulqdq: false,
+            has_zbc: false,
+            has_zvbc: false,
         };
         // This should select x86_64 tier since we're testing the general case
         assert_eq!(
@@ -638,8 +1643,11 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_zbc: false,
+            has_zvbc: false,
         };
         assert_eq!(
             select_performance_tier_for_test(&capabilities_no_pclmul),
@@ -651,8 +1659,8 @@ mod tests {
     fn test_x86_feature_hierarchy() {
         // Test that x86 feature hierarchy is properly maintained
         // SSE4.1 is required for PCLMULQDQ
-        // AVX512VL requires PCLMULQDQ
-        // VPCLMULQDQ requires AVX512VL
+        // AVX2 and AVX512VL require PCLMULQDQ
+        // VPCLMULQDQ requires AVX512VL or AVX2
 
         // Test feature dependencies are enforced
         let capabilities_full = ArchCapabilities {
@@ -662,8 +1670,11 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: true,
+            has_avx2: false,
             has_avx512vl: true,
             has_vpclmulqdq: true,
+            has_zbc: false,
+            has_zvbc: false,
         };
 
         // All x86 features should be available when hierarchy is satisfied
@@ -673,6 +1684,47 @@ mod tests {
         assert!(capabilities_full.has_vpclmulqdq);
     }
 
+    #[test]
+    fn test_riscv64_tier_selection() {
+        // Test that riscv64 tier selection follows the expected hierarchy
+        let capabilities_zvbc = ArchCapabilities {
+            has_aes: false,
+            has_crc: false,
+            has_sha3: false,
+            has_sse41: false,
+            has_sse42: false,
+            has_pclmulqdq: false,
+            has_avx2: false,
+            has_avx512vl: false,
+            has_vpclmulqdq: false,
+            has_zbc: true,
+            has_zvbc: true,
+        };
+        assert_eq!(
+            select_performance_tier_for_test(&capabilities_zvbc),
+            PerformanceTier::Riscv64Zvbc
+        );
+
+        let capabilities_zbc = ArchCapabilities {
+            has_zvbc: false,
+            ..capabilities_zvbc
+        };
+        assert_eq!(
+            select_performance_tier_for_test(&capabilities_zbc),
+            PerformanceTier::Riscv64Zbc
+        );
+
+        // Zvbc without Zbc should not be possible, and falls back to software
+        let capabilities_invalid = ArchCapabilities {
+            has_zbc: false,
+            ..capabilities_zvbc
+        };
+        assert_eq!(
+            select_performance_tier_for_test(&capabilities_invalid),
+            PerformanceTier::SoftwareTable
+        );
+    }
+
     // Mock tests for compile-time and runtime feature agreement scenarios
     mod mock_feature_agreement_tests {
         use super::*;
@@ -689,8 +1741,11 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_zbc: false,
+                has_zvbc: false,
             };
 
             // Should fall back to software since AES is required for SHA3
@@ -707,11 +1762,14 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: false,
                 has_avx512vl: false,  // Missing required dependency
                 has_vpclmulqdq: true, // This should be impossible in real detection
+                has_zbc: false,
+                has_zvbc: false,
             };
 
-            // Should fall back to SSE tier since AVX512VL is required for VPCLMULQDQ
+            // Should fall back to SSE tier since AVX512VL or AVX2 is required for VPCLMULQDQ
             assert_eq!(
                 select_performance_tier_for_test(&invalid_vpclmul_caps),
                 PerformanceTier::X86_64SsePclmulqdq
@@ -735,8 +1793,11 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_zbc: false,
+                has_zvbc: false,
             };
             assert_eq!(
                 select_performance_tier_for_test(&no_features),
@@ -751,8 +1812,11 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_zbc: false,
+                has_zvbc: false,
             };
             assert_eq!(
                 select_performance_tier_for_test(&aes_only),
@@ -767,8 +1831,11 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_zbc: false,
+                has_zvbc: false,
             };
             assert_eq!(
                 select_performance_tier_for_test(&aes_sha3),
@@ -788,8 +1855,11 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_zbc: false,
+                has_zvbc: false,
             };
             assert_eq!(
                 select_performance_tier_for_test(&no_features),
@@ -804,8 +1874,11 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_zbc: false,
+                has_zvbc: false,
             };
             assert_eq!(
                 select_performance_tier_for_test(&sse_only),
@@ -820,8 +1893,11 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_zbc: false,
+                has_zvbc: false,
             };
             assert_eq!(
                 select_performance_tier_for_test(&sse_pclmul),
@@ -836,8 +1912,11 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: false,
                 has_avx512vl: true,
                 has_vpclmulqdq: false,
+                has_zbc: false,
+                has_zvbc: false,
             };
             assert_eq!(
                 select_performance_tier_for_test(&avx512_pclmul_new_rust),
@@ -852,13 +1931,45 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: false,
                 has_avx512vl: true,
                 has_vpclmulqdq: true,
+                has_zbc: false,
+                has_zvbc: false,
             };
             assert_eq!(
                 select_performance_tier_for_test(&all_features_new_rust),
                 PerformanceTier::X86_64Avx512Vpclmulqdq
             );
+
+            // SSE4.1 + PCLMULQDQ + AVX2 + VPCLMULQDQ - 256-bit tier for CPUs without AVX512
+            let avx2_vpclmul = ArchCapabilities {
+                has_aes: false,
+                has_crc: false,
+                has_sha3: false,
+                has_sse41: true,
+                has_sse42: false,
+                has_pclmulqdq: true,
+                has_avx2: true,
+                has_avx512vl: false,
+                has_vpclmulqdq: true,
+                has_zbc: false,
+                has_zvbc: false,
+            };
+            assert_eq!(
+                select_performance_tier_for_test(&avx2_vpclmul),
+                PerformanceTier::X86_64Avx2Vpclmulqdq
+            );
+
+            // AVX2 without VPCLMULQDQ - baseline x86_64 tier
+            let avx2_pclmul = ArchCapabilities {
+                has_vpclmulqdq: false,
+                ..avx2_vpclmul
+            };
+            assert_eq!(
+                select_performance_tier_for_test(&avx2_pclmul),
+                PerformanceTier::X86_64SsePclmulqdq
+            );
         }
 
         #[test]
@@ -873,8 +1984,11 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_zbc: false,
+                has_zvbc: false,
             };
             assert_eq!(
                 select_performance_tier_for_test(&no_features),
@@ -890,8 +2004,11 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: false,
                 has_avx512vl: false, // AVX512 not available on 32-bit x86
                 has_vpclmulqdq: false,
+                has_zbc: false,
+                has_zvbc: false,
             };
             // The test function will return x86_64 tier since it doesn't distinguish architectures
             assert_eq!(
@@ -911,12 +2028,18 @@ mod tests {
                     PerformanceTier::X86_64Avx512Vpclmulqdq,
                     "x86_64-avx512-vpclmulqdq",
                 ),
+                (
+                    PerformanceTier::X86_64Avx2Vpclmulqdq,
+                    "x86_64-avx2-vpclmulqdq",
+                ),
                 (
                     PerformanceTier::X86_64Avx512Pclmulqdq,
                     "x86_64-avx512-pclmulqdq",
                 ),
                 (PerformanceTier::X86_64SsePclmulqdq, "x86_64-sse-pclmulqdq"),
                 (PerformanceTier::X86SsePclmulqdq, "x86-sse-pclmulqdq"),
+                (PerformanceTier::Riscv64Zvbc, "riscv64-rvv-zvbc"),
+                (PerformanceTier::Riscv64Zbc, "riscv64-zbc-clmul"),
                 (PerformanceTier::SoftwareTable, "software-fallback-tables"),
             ];
 
@@ -942,8 +2065,11 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_zbc: false,
+                has_zvbc: false,
             };
 
             // Should select highest tier
@@ -979,8 +2105,11 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: false,
                 has_avx512vl: true,
                 has_vpclmulqdq: true,
+                has_zbc: false,
+                has_zvbc: false,
             };
 
             // Should select highest tier
@@ -1030,8 +2159,11 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_zbc: false,
+                has_zvbc: false,
             };
             // Should fall back to software since AES is required for SHA3
             assert_eq!(
@@ -1047,8 +2179,11 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: true, // This would be impossible in real detection
+                has_zbc: false,
+                has_zvbc: false,
             };
             // Should fall back to SSE tier since AVX512VL is required for VPCLMULQDQ
             assert_eq!(
@@ -1059,6 +2194,33 @@ mod tests {
     }
 }
 
+#[cfg(all(
+    test,
+    feature = "std",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+mod cpuid_detection_tests {
+    use super::*;
+
+    #[test]
+    fn test_cpuid_detection_matches_std() {
+        let std = unsafe { detect_x86_features() };
+        let cpuid = unsafe { detect_x86_features_cpuid() };
+
+        assert_eq!(cpuid.has_sse41, std.has_sse41);
+        assert_eq!(cpuid.has_sse42, std.has_sse42);
+        assert_eq!(cpuid.has_pclmulqdq, std.has_pclmulqdq);
+        assert_eq!(cpuid.has_avx2, std.has_avx2);
+        assert_eq!(cpuid.has_avx512vl, std.has_avx512vl);
+        assert_eq!(cpuid.has_vpclmulqdq, std.has_vpclmulqdq);
+
+        assert_eq!(
+            select_performance_tier(&cpuid),
+            select_performance_tier(&std)
+        );
+    }
+}
+
 #[cfg(test)]
 mod software_fallback_tests {
     use super::*;
@@ -1072,8 +2234,11 @@ mod software_fallback_tests {
             has_sse41: false,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_zbc: false,
+            has_zvbc: false,
         };
 
         let tier = select_performance_tier_for_test(&capabilities_no_aes);
@@ -1094,8 +2259,11 @@ mod software_fallback_tests {
             has_sse41: true, // SSE4.1 available
             has_sse42: false,
             has_pclmulqdq: false, // But PCLMULQDQ not available
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_zbc: false,
+            has_zvbc: false,
         };
 
         let tier = select_performance_tier_for_test(&capabilities_no_pclmul);
@@ -1113,8 +2281,11 @@ mod software_fallback_tests {
             has_sse41: false, // No SSE4.1 support
             has_sse42: false,
             has_pclmulqdq: false, // PCLMULQDQ requires SSE4.1
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_zbc: false,
+            has_zvbc: false,
         };
 
         let tier = select_performance_tier_for_test(&capabilities_no_sse);
@@ -1147,3 +2318,102 @@ mod software_fallback_tests {
         // But it may still be compiled for testing purposes
     }
 }
+
+#[cfg(test)]
+mod public_tier_tests {
+    use super::*;
+
+    #[test]
+    fn test_names_round_trip() {
+        for tier in PerformanceTier::ALL {
+            assert_eq!(PerformanceTier::from_name(tier.name()), Some(tier));
+            assert_eq!(tier.to_string(), tier.name());
+        }
+
+        assert_eq!(
+            PerformanceTier::from_name("x86_64-avx1024-vpclmulqdq"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_available_tiers() {
+        let available: Vec<_> = PerformanceTier::available().collect();
+
+        assert_eq!(available.last(), Some(&PerformanceTier::SoftwareTable));
+
+        #[cfg(any(
+            target_arch = "aarch64",
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv64"
+        ))]
+        {
+            // the detected tier is the most preferred available one
+            let detected = select_performance_tier(get_arch_capabilities());
+            assert_eq!(available.first(), Some(&detected));
+        }
+    }
+
+    #[test]
+    fn test_override_rejects_unavailable_tiers() {
+        let unavailable = PerformanceTier::ALL
+            .into_iter()
+            .find(|tier| !tier.is_available())
+            .expect("no CPU supports every architecture's tiers");
+
+        assert_eq!(
+            set_tier_override(Some(unavailable)),
+            Err(BackendUnavailable(unavailable))
+        );
+    }
+    #[test]
+    fn test_unavailable_reasons() {
+        for tier in PerformanceTier::ALL {
+            assert_eq!(tier.unavailable_reason().is_none(), tier.is_available());
+
+            if !tier.is_native() {
+                assert_eq!(
+                    tier.unavailable_reason(),
+                    Some("not supported on this architecture")
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn test_missing_x86_64_features() {
+        let sse_only = ArchCapabilities {
+            has_sse41: true,
+            has_sse42: true,
+            has_pclmulqdq: true,
+            ..ArchCapabilities::NONE
+        };
+
+        assert_eq!(
+            missing_feature(&ArchCapabilities::NONE, PerformanceTier::X86_64SsePclmulqdq),
+            "sse4.1 missing"
+        );
+        assert_eq!(
+            missing_feature(
+                &ArchCapabilities {
+                    has_avx512vl: true,
+                    ..sse_only
+                },
+                PerformanceTier::X86_64Avx512Vpclmulqdq
+            ),
+            "vpclmulqdq missing"
+        );
+
+        // which of these depends on the CPU and OS running the test
+        assert!(matches!(
+            missing_feature(&sse_only, PerformanceTier::X86_64Avx512Pclmulqdq),
+            "avx512vl missing" | "OS has not enabled ZMM state"
+        ));
+        assert!(matches!(
+            missing_feature(&sse_only, PerformanceTier::X86_64Avx2Vpclmulqdq),
+            "avx2 missing" | "OS has not enabled YMM state"
+        ));
+    }
+}
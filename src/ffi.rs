@@ -7,16 +7,23 @@
 
 #![cfg(all(
     feature = "ffi",
-    any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86")
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "riscv64"
+    )
 ))]
 
 use crate::CrcAlgorithm;
 use crate::CrcParams;
-use crate::{get_calculator_target, Digest};
+use crate::{get_calculator_target, Calculator, Digest, FileChecksumOptions, FileReadMode};
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::ffi::CStr;
+use std::ops::Range;
 use std::os::raw::c_char;
+use std::path::PathBuf;
 use std::slice;
 use std::sync::{Mutex, OnceLock};
 
@@ -42,6 +49,11 @@ pub enum CrcFastError {
     IoError = 6,
     /// Internal string conversion error
     StringConversionError = 7,
+    /// The algorithm's family (crc16, crc32 or crc64) isn't compiled into this library
+    UnsupportedAlgorithm = 8,
+    /// A masked range is outside the data, or the ranges aren't in ascending order without
+    /// overlapping
+    InvalidRange = 9,
 }
 
 impl CrcFastError {
@@ -56,6 +68,12 @@ impl CrcFastError {
             CrcFastError::InvalidUtf8 => "Invalid UTF-8 string",
             CrcFastError::IoError => "File I/O error",
             CrcFastError::StringConversionError => "Internal string conversion error",
+            CrcFastError::UnsupportedAlgorithm => {
+                "Algorithm's family (crc16, crc32 or crc64) isn't compiled into this library"
+            }
+            CrcFastError::InvalidRange => {
+                "Masked range is outside the data, or the ranges overlap or are out of order"
+            }
         }
     }
 }
@@ -134,6 +152,10 @@ fn create_stable_key_pointer(keys: &crate::CrcKeysStorage) -> (*const u64, u32)
 #[repr(C)]
 pub struct CrcFastDigestHandle(*mut Digest);
 
+/// A handle to the Calculator object
+#[repr(C)]
+pub struct CrcFastCalculatorHandle(*mut Calculator);
+
 /// The supported CRC algorithms
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -196,9 +218,11 @@ pub enum CrcFastAlgorithm {
 
 // Convert from FFI enum to internal enum
 #[allow(deprecated)]
-impl From<CrcFastAlgorithm> for CrcAlgorithm {
-    fn from(value: CrcFastAlgorithm) -> Self {
-        match value {
+impl TryFrom<CrcFastAlgorithm> for CrcAlgorithm {
+    type Error = CrcFastError;
+
+    fn try_from(value: CrcFastAlgorithm) -> Result<Self, Self::Error> {
+        let algorithm = match value {
             CrcFastAlgorithm::Crc16Arc => CrcAlgorithm::Crc16Arc,
             CrcFastAlgorithm::Crc16Cdma2000 => CrcAlgorithm::Crc16Cdma2000,
             CrcFastAlgorithm::Crc16Cms => CrcAlgorithm::Crc16Cms,
@@ -252,6 +276,24 @@ impl From<CrcFastAlgorithm> for CrcAlgorithm {
             CrcFastAlgorithm::Crc64Redis => CrcAlgorithm::Crc64Redis,
             CrcFastAlgorithm::Crc64We => CrcAlgorithm::Crc64We,
             CrcFastAlgorithm::Crc64Xz => CrcAlgorithm::Crc64Xz,
+        };
+
+        if algorithm.is_supported() {
+            Ok(algorithm)
+        } else {
+            Err(CrcFastError::UnsupportedAlgorithm)
+        }
+    }
+}
+
+/// Converts an FFI algorithm, setting CrcFastError::UnsupportedAlgorithm if its family isn't
+/// compiled into this library
+fn try_algorithm_from_ffi(value: CrcFastAlgorithm) -> Option<CrcAlgorithm> {
+    match CrcAlgorithm::try_from(value) {
+        Ok(algorithm) => Some(algorithm),
+        Err(error) => {
+            set_last_error(error);
+            None
         }
     }
 }
@@ -301,6 +343,92 @@ pub struct CrcFastParams {
     pub keys: *const u64,
 }
 
+/// A byte range [start, end) of the data, treated as zeros by the masked checksum functions
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CrcFastRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Converts the masked ranges, setting the last error if they're null or invalid for `len` bytes
+/// of data
+fn try_ranges_from_ffi(
+    ranges: *const CrcFastRange,
+    range_count: usize,
+    len: usize,
+) -> Option<Vec<Range<usize>>> {
+    if range_count == 0 {
+        return Some(Vec::new());
+    }
+    if ranges.is_null() {
+        set_last_error(CrcFastError::NullPointer);
+        return None;
+    }
+
+    let ranges = unsafe { slice::from_raw_parts(ranges, range_count) };
+
+    let mut position = 0;
+    for range in ranges {
+        if range.start < position || range.start > range.end || range.end > len {
+            set_last_error(CrcFastError::InvalidRange);
+            return None;
+        }
+        position = range.end;
+    }
+
+    Some(ranges.iter().map(|range| range.start..range.end).collect())
+}
+
+/// Strategy used to read files
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub enum CrcFastFileReadMode {
+    /// Regular buffered reads
+    #[default]
+    Buffered,
+    /// Memory-mapped reads (requires the library to be built with the mmap feature)
+    Mmap,
+    /// O_DIRECT reads which bypass the page cache (requires the library to be built with the
+    /// direct-io feature, Linux and Android only)
+    Direct,
+}
+
+impl From<CrcFastFileReadMode> for FileReadMode {
+    fn from(value: CrcFastFileReadMode) -> Self {
+        match value {
+            CrcFastFileReadMode::Buffered => FileReadMode::Buffered,
+            CrcFastFileReadMode::Mmap => FileReadMode::Mmap,
+            CrcFastFileReadMode::Direct => FileReadMode::Direct,
+        }
+    }
+}
+
+/// Options for checksumming a file, zero-initialize for the defaults (whole file, buffered)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CrcFastFileOptions {
+    /// Read size in bytes, or 0 for the default
+    pub chunk_size: usize,
+    /// Byte offset to start reading from
+    pub offset: u64,
+    /// Number of bytes to read, only used when has_len is true
+    pub len: u64,
+    /// Whether len limits the range, otherwise it extends to the end of the file
+    pub has_len: bool,
+    /// Strategy used to read the file
+    pub mode: CrcFastFileReadMode,
+}
+
+impl From<CrcFastFileOptions> for FileChecksumOptions {
+    fn from(value: CrcFastFileOptions) -> Self {
+        FileChecksumOptions::new()
+            .with_chunk_size((value.chunk_size != 0).then_some(value.chunk_size))
+            .with_range(value.offset, value.has_len.then_some(value.len))
+            .with_mode(value.mode.into())
+    }
+}
+
 /// Fallible conversion from FFI struct to internal struct
 /// Returns None if the parameters are invalid (unsupported key count)
 fn try_params_from_ffi(value: &CrcFastParams) -> Option<CrcParams> {
@@ -332,7 +460,8 @@ fn try_params_from_ffi(value: &CrcFastParams) -> Option<CrcParams> {
     };
 
     Some(CrcParams {
-        algorithm: value.algorithm.into(),
+        // the parameters are complete, so they still work when the family isn't compiled in
+        algorithm: CrcAlgorithm::try_from(value.algorithm).unwrap_or(CrcAlgorithm::CrcCustom),
         name: "custom", // C interface doesn't need the name field
         width: value.width,
         poly: value.poly,
@@ -432,22 +561,30 @@ impl From<CrcParams> for CrcFastParams {
 }
 
 /// Creates a new Digest to compute CRC checksums using algorithm
+/// Returns NULL if the algorithm's family isn't compiled into this library
 #[no_mangle]
 pub extern "C" fn crc_fast_digest_new(algorithm: CrcFastAlgorithm) -> *mut CrcFastDigestHandle {
     clear_last_error();
-    let digest = Box::new(Digest::new(algorithm.into()));
+    let Some(algorithm) = try_algorithm_from_ffi(algorithm) else {
+        return std::ptr::null_mut();
+    };
+    let digest = Box::new(Digest::new(algorithm));
     let handle = Box::new(CrcFastDigestHandle(Box::into_raw(digest)));
     Box::into_raw(handle)
 }
 
 /// Creates a new Digest with a custom initial state
+/// Returns NULL if the algorithm's family isn't compiled into this library
 #[no_mangle]
 pub extern "C" fn crc_fast_digest_new_with_init_state(
     algorithm: CrcFastAlgorithm,
     init_state: u64,
 ) -> *mut CrcFastDigestHandle {
     clear_last_error();
-    let digest = Box::new(Digest::new_with_init_state(algorithm.into(), init_state));
+    let Some(algorithm) = try_algorithm_from_ffi(algorithm) else {
+        return std::ptr::null_mut();
+    };
+    let digest = Box::new(Digest::new_with_init_state(algorithm, init_state));
     let handle = Box::new(CrcFastDigestHandle(Box::into_raw(digest)));
     Box::into_raw(handle)
 }
@@ -504,6 +641,53 @@ pub extern "C" fn crc_fast_digest_update(
     }
 }
 
+/// Updates the Digest with data, treating the bytes in each of the masked ranges as zeros
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_update_with_masked_ranges(
+    handle: *mut CrcFastDigestHandle,
+    data: *const c_char,
+    len: usize,
+    ranges: *const CrcFastRange,
+    range_count: usize,
+) {
+    if handle.is_null() {
+        set_last_error(CrcFastError::NullPointer);
+        return;
+    }
+    if data.is_null() {
+        set_last_error(CrcFastError::NullPointer);
+        return;
+    }
+    let Some(ranges) = try_ranges_from_ffi(ranges, range_count, len) else {
+        return;
+    };
+
+    clear_last_error();
+    unsafe {
+        let digest = &mut *(*handle).0;
+
+        #[allow(clippy::unnecessary_cast)]
+        let bytes = slice::from_raw_parts(data as *const u8, len);
+        digest.update_with_masked_ranges(bytes, &ranges);
+    }
+}
+
+/// Updates the Digest as if len zero bytes were written
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_update_zeros(handle: *mut CrcFastDigestHandle, len: u64) {
+    if handle.is_null() {
+        set_last_error(CrcFastError::NullPointer);
+        return;
+    }
+
+    clear_last_error();
+    unsafe {
+        let digest = &mut *(*handle).0;
+
+        digest.update_zeros(len);
+    }
+}
+
 /// Calculates the CRC checksum for data that's been written to the Digest
 /// Returns 0 on error (e.g. null handle)
 #[no_mangle]
@@ -618,8 +802,130 @@ pub extern "C" fn crc_fast_digest_get_state(handle: *mut CrcFastDigestHandle) ->
     }
 }
 
+/// Creates a new Calculator, resolved once for the algorithm and the detected CPU
+/// Returns NULL if the algorithm's family isn't compiled into this library
+#[no_mangle]
+pub extern "C" fn crc_fast_calculator_new(
+    algorithm: CrcFastAlgorithm,
+) -> *mut CrcFastCalculatorHandle {
+    clear_last_error();
+    let Some(algorithm) = try_algorithm_from_ffi(algorithm) else {
+        return std::ptr::null_mut();
+    };
+    let calculator = Box::new(Calculator::new(algorithm));
+    let handle = Box::new(CrcFastCalculatorHandle(Box::into_raw(calculator)));
+    Box::into_raw(handle)
+}
+
+/// Creates a new Calculator, resolved once for custom parameters and the detected CPU
+/// Returns NULL if parameters are invalid (invalid key count or null pointer)
+/// Call crc_fast_get_last_error() to get the specific error code
+#[no_mangle]
+pub extern "C" fn crc_fast_calculator_new_with_params(
+    params: CrcFastParams,
+) -> *mut CrcFastCalculatorHandle {
+    clear_last_error();
+    match try_params_from_ffi(&params) {
+        Some(crc_params) => {
+            let calculator = Box::new(Calculator::new_with_params(crc_params));
+            let handle = Box::new(CrcFastCalculatorHandle(Box::into_raw(calculator)));
+            Box::into_raw(handle)
+        }
+        None => {
+            if params.keys.is_null() {
+                set_last_error(CrcFastError::NullPointer);
+            } else {
+                set_last_error(CrcFastError::InvalidKeyCount);
+            }
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Calculates a CRC checksum for data using the Calculator
+/// Returns 0 on error (e.g. null handle or data pointer)
+#[no_mangle]
+pub extern "C" fn crc_fast_calculator_checksum(
+    handle: *const CrcFastCalculatorHandle,
+    data: *const c_char,
+    len: usize,
+) -> u64 {
+    if handle.is_null() || data.is_null() {
+        set_last_error(CrcFastError::NullPointer);
+        return 0;
+    }
+
+    clear_last_error();
+    unsafe {
+        let calculator = &*(*handle).0;
+
+        #[allow(clippy::unnecessary_cast)]
+        let bytes = slice::from_raw_parts(data as *const u8, len);
+        calculator.checksum(bytes)
+    }
+}
+
+/// Updates a non-finalized CRC state with data using the Calculator, returning the new state
+/// Returns 0 on error (e.g. null handle or data pointer)
+#[no_mangle]
+pub extern "C" fn crc_fast_calculator_update(
+    handle: *const CrcFastCalculatorHandle,
+    state: u64,
+    data: *const c_char,
+    len: usize,
+) -> u64 {
+    if handle.is_null() || data.is_null() {
+        set_last_error(CrcFastError::NullPointer);
+        return 0;
+    }
+
+    clear_last_error();
+    unsafe {
+        let calculator = &*(*handle).0;
+
+        #[allow(clippy::unnecessary_cast)]
+        let bytes = slice::from_raw_parts(data as *const u8, len);
+        calculator.update(state, bytes)
+    }
+}
+
+/// Creates a new Digest which uses the Calculator's resolved routine
+/// Returns NULL on error (e.g. null handle)
+#[no_mangle]
+pub extern "C" fn crc_fast_calculator_digest(
+    handle: *const CrcFastCalculatorHandle,
+) -> *mut CrcFastDigestHandle {
+    if handle.is_null() {
+        set_last_error(CrcFastError::NullPointer);
+        return std::ptr::null_mut();
+    }
+
+    clear_last_error();
+    unsafe {
+        let calculator = &*(*handle).0;
+        let digest = Box::new(calculator.digest());
+        let handle = Box::new(CrcFastDigestHandle(Box::into_raw(digest)));
+        Box::into_raw(handle)
+    }
+}
+
+/// Free the Calculator resources
+#[no_mangle]
+pub extern "C" fn crc_fast_calculator_free(handle: *mut CrcFastCalculatorHandle) {
+    if handle.is_null() {
+        set_last_error(CrcFastError::NullPointer);
+        return;
+    }
+
+    clear_last_error();
+    unsafe {
+        let handle = Box::from_raw(handle);
+        let _ = Box::from_raw(handle.0); // This drops the calculator
+    }
+}
+
 /// Helper method to calculate a CRC checksum directly for a string using algorithm
-/// Returns 0 on error (e.g. null data pointer)
+/// Returns 0 on error (e.g. null data pointer, or the algorithm's family isn't compiled in)
 #[no_mangle]
 pub extern "C" fn crc_fast_checksum(
     algorithm: CrcFastAlgorithm,
@@ -631,10 +937,43 @@ pub extern "C" fn crc_fast_checksum(
         return 0;
     }
     clear_last_error();
+    let Some(algorithm) = try_algorithm_from_ffi(algorithm) else {
+        return 0;
+    };
     unsafe {
         #[allow(clippy::unnecessary_cast)]
         let bytes = slice::from_raw_parts(data as *const u8, len);
-        crate::checksum(algorithm.into(), bytes)
+        crate::checksum(algorithm, bytes)
+    }
+}
+
+/// Helper method to calculate a CRC checksum directly for data using algorithm, treating the bytes
+/// in each of the masked ranges as zeros, such as an embedded CRC field
+/// Returns 0 on error (e.g. null pointer, invalid ranges, or the algorithm's family isn't compiled
+/// in)
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_with_masked_ranges(
+    algorithm: CrcFastAlgorithm,
+    data: *const c_char,
+    len: usize,
+    ranges: *const CrcFastRange,
+    range_count: usize,
+) -> u64 {
+    if data.is_null() {
+        set_last_error(CrcFastError::NullPointer);
+        return 0;
+    }
+    let Some(ranges) = try_ranges_from_ffi(ranges, range_count, len) else {
+        return 0;
+    };
+    clear_last_error();
+    let Some(algorithm) = try_algorithm_from_ffi(algorithm) else {
+        return 0;
+    };
+    unsafe {
+        #[allow(clippy::unnecessary_cast)]
+        let bytes = slice::from_raw_parts(data as *const u8, len);
+        crate::checksum_with_masked_ranges(algorithm, bytes, &ranges)
     }
 }
 
@@ -672,7 +1011,7 @@ pub extern "C" fn crc_fast_checksum_with_params(
 }
 
 /// Helper method to just calculate a CRC checksum directly for a file using algorithm
-/// Returns 0 if path is null or file I/O fails
+/// Returns 0 if path is null, file I/O fails, or the algorithm's family isn't compiled in
 /// Call crc_fast_get_last_error() to get the specific error code
 #[no_mangle]
 pub extern "C" fn crc_fast_checksum_file(
@@ -680,27 +1019,12 @@ pub extern "C" fn crc_fast_checksum_file(
     path_ptr: *const u8,
     path_len: usize,
 ) -> u64 {
-    if path_ptr.is_null() {
-        set_last_error(CrcFastError::NullPointer);
-        return 0;
-    }
-
-    unsafe {
-        match crate::checksum_file(
-            algorithm.into(),
-            &convert_to_string(path_ptr, path_len),
-            None,
-        ) {
-            Ok(result) => {
-                clear_last_error();
-                result
-            }
-            Err(_) => {
-                set_last_error(CrcFastError::IoError);
-                0
-            }
-        }
-    }
+    crc_fast_checksum_file_with_options(
+        algorithm,
+        path_ptr,
+        path_len,
+        CrcFastFileOptions::default(),
+    )
 }
 
 /// Helper method to calculate a CRC checksum directly for a file using custom parameters
@@ -712,28 +1036,49 @@ pub extern "C" fn crc_fast_checksum_file_with_params(
     path_ptr: *const u8,
     path_len: usize,
 ) -> u64 {
-    if path_ptr.is_null() {
-        set_last_error(CrcFastError::NullPointer);
+    crc_fast_checksum_file_with_params_and_options(
+        params,
+        path_ptr,
+        path_len,
+        CrcFastFileOptions::default(),
+    )
+}
+
+/// Calculates a CRC checksum for a file, or a byte range of it, using algorithm
+/// Returns 0 if path is null, file I/O fails (including ranges past the end of the file), or the
+/// algorithm's family isn't compiled in
+/// Call crc_fast_get_last_error() to get the specific error code
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_file_with_options(
+    algorithm: CrcFastAlgorithm,
+    path_ptr: *const u8,
+    path_len: usize,
+    options: CrcFastFileOptions,
+) -> u64 {
+    let Some(algorithm) = try_algorithm_from_ffi(algorithm) else {
         return 0;
-    }
+    };
+    checksum_file_ffi(Digest::new(algorithm), path_ptr, path_len, &options.into())
+}
 
+/// Calculates a CRC checksum for a file, or a byte range of it, using custom parameters
+/// Returns 0 if parameters are invalid, path is null, or file I/O fails (including ranges past
+/// the end of the file)
+/// Call crc_fast_get_last_error() to get the specific error code
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_file_with_params_and_options(
+    params: CrcFastParams,
+    path_ptr: *const u8,
+    path_len: usize,
+    options: CrcFastFileOptions,
+) -> u64 {
     match try_params_from_ffi(&params) {
-        Some(crc_params) => unsafe {
-            match crate::checksum_file_with_params(
-                crc_params,
-                &convert_to_string(path_ptr, path_len),
-                None,
-            ) {
-                Ok(result) => {
-                    clear_last_error();
-                    result
-                }
-                Err(_) => {
-                    set_last_error(CrcFastError::IoError);
-                    0
-                }
-            }
-        },
+        Some(crc_params) => checksum_file_ffi(
+            Digest::new_with_params(crc_params),
+            path_ptr,
+            path_len,
+            &options.into(),
+        ),
         None => {
             if params.keys.is_null() {
                 set_last_error(CrcFastError::NullPointer);
@@ -745,7 +1090,43 @@ pub extern "C" fn crc_fast_checksum_file_with_params(
     }
 }
 
+/// Shared implementation of the file checksum functions, setting the last error on failure
+fn checksum_file_ffi(
+    mut digest: Digest,
+    path_ptr: *const u8,
+    path_len: usize,
+    options: &FileChecksumOptions,
+) -> u64 {
+    if path_ptr.is_null() {
+        set_last_error(CrcFastError::NullPointer);
+        return 0;
+    }
+
+    let path = match unsafe { convert_to_path(path_ptr, path_len) } {
+        Some(path) => path,
+        None => {
+            set_last_error(CrcFastError::InvalidUtf8);
+            return 0;
+        }
+    };
+
+    let result =
+        crate::file::open(&path, options.mode).and_then(|file| digest.update_file(&file, options));
+
+    match result {
+        Ok(_) => {
+            clear_last_error();
+            digest.finalize()
+        }
+        Err(_) => {
+            set_last_error(CrcFastError::IoError);
+            0
+        }
+    }
+}
+
 /// Combine two CRC checksums using algorithm
+/// Returns 0 if the algorithm's family isn't compiled into this library
 #[no_mangle]
 pub extern "C" fn crc_fast_checksum_combine(
     algorithm: CrcFastAlgorithm,
@@ -754,7 +1135,10 @@ pub extern "C" fn crc_fast_checksum_combine(
     checksum2_len: u64,
 ) -> u64 {
     clear_last_error();
-    crate::checksum_combine(algorithm.into(), checksum1, checksum2, checksum2_len)
+    match try_algorithm_from_ffi(algorithm) {
+        Some(algorithm) => crate::checksum_combine(algorithm, checksum1, checksum2, checksum2_len),
+        None => 0,
+    }
 }
 
 /// Combine two CRC checksums using custom parameters
@@ -846,11 +1230,14 @@ pub extern "C" fn crc_fast_get_custom_params(
 }
 
 /// Gets the target build properties (CPU architecture and fine-tuning parameters) for this algorithm
-/// Returns NULL if string conversion fails
+/// Returns NULL if string conversion fails or the algorithm's family isn't compiled in
 /// Call crc_fast_get_last_error() to get the specific error code
 #[no_mangle]
 pub extern "C" fn crc_fast_get_calculator_target(algorithm: CrcFastAlgorithm) -> *const c_char {
-    let target = get_calculator_target(algorithm.into());
+    let Some(algorithm) = try_algorithm_from_ffi(algorithm) else {
+        return std::ptr::null_mut();
+    };
+    let target = get_calculator_target(algorithm);
 
     match std::ffi::CString::new(target) {
         Ok(s) => {
@@ -864,6 +1251,154 @@ pub extern "C" fn crc_fast_get_calculator_target(algorithm: CrcFastAlgorithm) ->
     }
 }
 
+/// Summary of a runtime self-test
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CrcFastSelfTestReport {
+    /// Whether every checksum matched its expected value
+    pub passed: bool,
+    /// Number of backends (performance tiers) which were tested
+    pub backends: u32,
+    /// Number of checksums which were computed and compared
+    pub checks: u32,
+    /// Number of checksums which didn't match
+    pub failures: u32,
+}
+
+/// Runs a self-test of every algorithm across every backend this CPU supports, including the
+/// fusion paths and the software tables, against check values and known-answer vectors
+#[no_mangle]
+pub extern "C" fn crc_fast_self_test() -> CrcFastSelfTestReport {
+    let report = crate::self_test();
+
+    clear_last_error();
+
+    CrcFastSelfTestReport {
+        passed: report.passed(),
+        backends: report.backends.len() as u32,
+        checks: report.checks as u32,
+        failures: report.failures.len() as u32,
+    }
+}
+
+/// The predefined algorithm families compiled into this library
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CrcFastFamilies {
+    /// Whether the CRC-16 algorithms are available
+    pub crc16: bool,
+    /// Whether the CRC-32 algorithms are available
+    pub crc32: bool,
+    /// Whether the CRC-64 algorithms are available
+    pub crc64: bool,
+}
+
+/// Gets the predefined algorithm families compiled into this library. Custom parameters work
+/// with every width regardless.
+#[no_mangle]
+pub extern "C" fn crc_fast_get_families() -> CrcFastFamilies {
+    clear_last_error();
+
+    CrcFastFamilies {
+        crc16: cfg!(feature = "crc16"),
+        crc32: cfg!(feature = "crc32"),
+        crc64: cfg!(feature = "crc64"),
+    }
+}
+
+/// Counters and sizes of the custom parameter caches
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CrcFastCacheStats {
+    /// Lookups served from a cache
+    pub hits: u64,
+    /// Lookups that had to generate keys or a table
+    pub misses: u64,
+    /// Entries removed to stay within the capacity
+    pub evictions: u64,
+    /// Sets of folding keys currently cached
+    pub key_entries: usize,
+    /// Software lookup tables currently cached, across all widths
+    pub table_entries: usize,
+    /// Software fold plans currently cached, including searches which found no plan
+    pub plan_entries: usize,
+    /// The maximum number of entries in each cache
+    pub capacity: usize,
+    /// Whether caching is enabled
+    pub enabled: bool,
+}
+
+impl From<crate::cache::CacheStats> for CrcFastCacheStats {
+    fn from(value: crate::cache::CacheStats) -> Self {
+        CrcFastCacheStats {
+            hits: value.hits,
+            misses: value.misses,
+            evictions: value.evictions,
+            key_entries: value.key_entries,
+            table_entries: value.table_entries,
+            plan_entries: value.plan_entries,
+            capacity: value.capacity,
+            enabled: value.enabled,
+        }
+    }
+}
+
+/// Gets the counters and sizes of the custom parameter caches
+#[no_mangle]
+pub extern "C" fn crc_fast_cache_stats() -> CrcFastCacheStats {
+    clear_last_error();
+
+    crate::cache::stats().into()
+}
+
+/// Sets the maximum number of entries in each custom parameter cache, evicting the least
+/// recently used entries beyond it
+#[no_mangle]
+pub extern "C" fn crc_fast_cache_set_capacity(capacity: usize) {
+    clear_last_error();
+
+    crate::cache::set_capacity(capacity);
+}
+
+/// Enables or disables the custom parameter caches, clearing them when disabled
+#[no_mangle]
+pub extern "C" fn crc_fast_cache_set_enabled(enabled: bool) {
+    clear_last_error();
+
+    crate::cache::set_enabled(enabled);
+}
+
+/// Caches the keys and, when the software backend is active, the lookup table for custom
+/// parameters ahead of first use
+/// Returns false if the parameters are invalid
+/// Call crc_fast_get_last_error() to get the specific error code
+#[no_mangle]
+pub extern "C" fn crc_fast_cache_prewarm(params: CrcFastParams) -> bool {
+    match try_params_from_ffi(&params) {
+        Some(crc_params) => {
+            clear_last_error();
+            crate::cache::prewarm(&crc_params);
+            true
+        }
+        None => {
+            if params.keys.is_null() {
+                set_last_error(CrcFastError::NullPointer);
+            } else {
+                set_last_error(CrcFastError::InvalidKeyCount);
+            }
+            false
+        }
+    }
+}
+
+/// Removes every cached set of keys and lookup table for custom parameters
+#[no_mangle]
+pub extern "C" fn crc_fast_cache_clear() {
+    clear_last_error();
+
+    crate::cache::clear();
+}
+
 /// Gets the version of this library
 /// Returns a pointer to "unknown" if version string is invalid
 #[no_mangle]
@@ -883,18 +1418,27 @@ pub extern "C" fn crc_fast_get_version() -> *const c_char {
 ///
 /// https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-iscsi
 ///
-/// Returns 0 on error (e.g. null data pointer)
+/// Returns 0 on error (e.g. null data pointer, or the crc32 family isn't compiled in)
 #[no_mangle]
 pub extern "C" fn crc_fast_crc32_iscsi(data: *const c_char, len: usize) -> u32 {
     if data.is_null() {
         set_last_error(CrcFastError::NullPointer);
         return 0;
     }
-    clear_last_error();
-    unsafe {
-        #[allow(clippy::unnecessary_cast)]
-        let bytes = slice::from_raw_parts(data as *const u8, len);
-        crate::crc32_iscsi(bytes)
+    #[cfg(feature = "crc32")]
+    {
+        clear_last_error();
+        unsafe {
+            #[allow(clippy::unnecessary_cast)]
+            let bytes = slice::from_raw_parts(data as *const u8, len);
+            crate::crc32_iscsi(bytes)
+        }
+    }
+    #[cfg(not(feature = "crc32"))]
+    {
+        let _ = len;
+        set_last_error(CrcFastError::UnsupportedAlgorithm);
+        0
     }
 }
 
@@ -903,18 +1447,27 @@ pub extern "C" fn crc_fast_crc32_iscsi(data: *const c_char, len: usize) -> u32 {
 ///
 /// https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-iso-hdlc
 ///
-/// Returns 0 on error (e.g. null data pointer)
+/// Returns 0 on error (e.g. null data pointer, or the crc32 family isn't compiled in)
 #[no_mangle]
 pub extern "C" fn crc_fast_crc32_iso_hdlc(data: *const c_char, len: usize) -> u32 {
     if data.is_null() {
         set_last_error(CrcFastError::NullPointer);
         return 0;
     }
-    clear_last_error();
-    unsafe {
-        #[allow(clippy::unnecessary_cast)]
-        let bytes = slice::from_raw_parts(data as *const u8, len);
-        crate::crc32_iso_hdlc(bytes)
+    #[cfg(feature = "crc32")]
+    {
+        clear_last_error();
+        unsafe {
+            #[allow(clippy::unnecessary_cast)]
+            let bytes = slice::from_raw_parts(data as *const u8, len);
+            crate::crc32_iso_hdlc(bytes)
+        }
+    }
+    #[cfg(not(feature = "crc32"))]
+    {
+        let _ = len;
+        set_last_error(CrcFastError::UnsupportedAlgorithm);
+        0
     }
 }
 
@@ -922,30 +1475,45 @@ pub extern "C" fn crc_fast_crc32_iso_hdlc(data: *const c_char, len: usize) -> u3
 ///
 /// https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-64-nvme
 ///
-/// Returns 0 on error (e.g. null data pointer)
+/// Returns 0 on error (e.g. null data pointer, or the crc64 family isn't compiled in)
 #[no_mangle]
 pub extern "C" fn crc_fast_crc64_nvme(data: *const c_char, len: usize) -> u64 {
     if data.is_null() {
         set_last_error(CrcFastError::NullPointer);
         return 0;
     }
-    clear_last_error();
-    unsafe {
-        #[allow(clippy::unnecessary_cast)]
-        let bytes = slice::from_raw_parts(data as *const u8, len);
-        crate::crc64_nvme(bytes)
+    #[cfg(feature = "crc64")]
+    {
+        clear_last_error();
+        unsafe {
+            #[allow(clippy::unnecessary_cast)]
+            let bytes = slice::from_raw_parts(data as *const u8, len);
+            crate::crc64_nvme(bytes)
+        }
+    }
+    #[cfg(not(feature = "crc64"))]
+    {
+        let _ = len;
+        set_last_error(CrcFastError::UnsupportedAlgorithm);
+        0
     }
 }
 
-unsafe fn convert_to_string(data: *const u8, len: usize) -> String {
-    if data.is_null() {
-        return String::new();
+/// Converts a C path to a native path. Paths are raw bytes on Unix, so they don't need to be
+/// valid UTF-8 there, but must be elsewhere. Returns None for invalid UTF-8.
+unsafe fn convert_to_path(data: *const u8, len: usize) -> Option<PathBuf> {
+    let bytes = slice::from_raw_parts(data, len);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+
+        Some(PathBuf::from(std::ffi::OsStr::from_bytes(bytes)))
     }
 
-    // Safely construct string slice from raw parts
-    match std::str::from_utf8(slice::from_raw_parts(data, len)) {
-        Ok(s) => s.to_string(),
-        Err(_) => String::new(), // Return empty string for invalid UTF-8
+    #[cfg(not(unix))]
+    {
+        std::str::from_utf8(bytes).ok().map(PathBuf::from)
     }
 }
 
@@ -0,0 +1,523 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! File checksumming, with support for byte ranges, memory mapping and direct I/O.
+//!
+//! Every read error is propagated to the caller, so a failing device or a file truncated while
+//! it's being read results in an error rather than the checksum of whatever prefix was read.
+
+#![cfg(feature = "std")]
+
+use crate::{Digest, DEFAULT_FILE_CHUNK_SIZE};
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// Strategy used to read file contents.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FileReadMode {
+    /// Regular buffered reads into a reusable chunk-sized buffer.
+    #[default]
+    Buffered,
+
+    /// Memory-maps the file (or range) and advises the kernel that it will be read sequentially.
+    ///
+    /// Requires the `mmap` feature. The file must not be truncated by another process while it's
+    /// mapped, which would fault the process.
+    Mmap,
+
+    /// Bypasses the page cache using `O_DIRECT` reads into aligned buffers, which avoids evicting
+    /// hot data when checksumming very large files.
+    ///
+    /// Requires the `direct-io` feature and Linux or Android. Files passed to
+    /// [`Digest::update_file`] must already have been opened with `O_DIRECT`.
+    Direct,
+}
+
+/// Options for checksumming a file.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum_file_with_options, FileChecksumOptions, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// // only "3456" from "123456789"
+/// let options = FileChecksumOptions::new().with_range(2, Some(4));
+///
+/// let checksum = checksum_file_with_options(Crc32IsoHdlc, "crc-check.txt", &options);
+///
+/// assert_eq!(checksum.unwrap(), crc_fast::checksum(Crc32IsoHdlc, b"3456"));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FileChecksumOptions {
+    pub(crate) chunk_size: Option<usize>,
+    pub(crate) offset: u64,
+    pub(crate) len: Option<u64>,
+    pub(crate) mode: FileReadMode,
+}
+
+impl FileChecksumOptions {
+    /// Creates options which checksum the whole file using buffered reads.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the read size, defaulting to a size tuned for throughput when `None`.
+    #[inline]
+    pub fn with_chunk_size(mut self, chunk_size: Option<usize>) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Restricts the checksum to `len` bytes starting at `offset`, or to everything from `offset`
+    /// to the end of the file when `len` is `None`.
+    ///
+    /// A range which extends past the end of the file results in an
+    /// [`io::ErrorKind::UnexpectedEof`] error.
+    #[inline]
+    pub fn with_range(mut self, offset: u64, len: Option<u64>) -> Self {
+        self.offset = offset;
+        self.len = len;
+        self
+    }
+
+    /// Sets the strategy used to read the file.
+    #[inline]
+    pub fn with_mode(mut self, mode: FileReadMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    #[inline(always)]
+    fn chunk_size(&self) -> usize {
+        // a zero-sized buffer would never make progress
+        self.chunk_size.unwrap_or(DEFAULT_FILE_CHUNK_SIZE).max(1)
+    }
+}
+
+/// Opens `path` for reading in a way that suits `mode`.
+pub(crate) fn open(path: &Path, mode: FileReadMode) -> io::Result<File> {
+    match mode {
+        FileReadMode::Direct => open_direct(path),
+        FileReadMode::Buffered | FileReadMode::Mmap => File::open(path),
+    }
+}
+
+/// Updates `digest` with the range of `file` described by `options`, returning the number of
+/// bytes checksummed.
+pub(crate) fn update_digest(
+    digest: &mut Digest,
+    file: &File,
+    options: &FileChecksumOptions,
+) -> io::Result<u64> {
+    match options.mode {
+        FileReadMode::Buffered => update_buffered(digest, file, options),
+        FileReadMode::Mmap => update_mmap(digest, file, options),
+        FileReadMode::Direct => update_direct(digest, file, options),
+    }
+}
+
+fn unexpected_eof() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::UnexpectedEof,
+        "file ended before the requested range",
+    )
+}
+
+#[cfg(any(
+    not(feature = "mmap"),
+    not(all(feature = "direct-io", any(target_os = "linux", target_os = "android")))
+))]
+fn unsupported(what: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::Unsupported, what)
+}
+
+/// Reads into `buf`, retrying reads that were interrupted by a signal.
+fn read_retrying(mut file: &File, buf: &mut [u8]) -> io::Result<usize> {
+    loop {
+        match file.read(buf) {
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            result => return result,
+        }
+    }
+}
+
+fn update_buffered(
+    digest: &mut Digest,
+    mut file: &File,
+    options: &FileChecksumOptions,
+) -> io::Result<u64> {
+    if let Err(e) = file.seek(SeekFrom::Start(options.offset)) {
+        // pipes and other streams can't seek, but can still be read from the start
+        if options.offset != 0 {
+            return Err(e);
+        }
+    }
+
+    let mut buf = vec![0; options.chunk_size()];
+    let mut total = 0u64;
+
+    loop {
+        let want = match options.len {
+            Some(len) if len == total => break,
+            Some(len) => buf
+                .len()
+                .min((len - total).try_into().unwrap_or(usize::MAX)),
+            None => buf.len(),
+        };
+
+        let n = read_retrying(file, &mut buf[..want])?;
+        if n == 0 {
+            if options.len.is_some() {
+                return Err(unexpected_eof());
+            }
+            break;
+        }
+
+        digest.update(&buf[..n]);
+        total += n as u64;
+    }
+
+    Ok(total)
+}
+
+#[cfg(feature = "mmap")]
+fn update_mmap(digest: &mut Digest, file: &File, options: &FileChecksumOptions) -> io::Result<u64> {
+    let available = file.metadata()?.len().saturating_sub(options.offset);
+    let len = match options.len {
+        Some(len) if len > available => return Err(unexpected_eof()),
+        Some(len) => len,
+        None => available,
+    };
+
+    // mapping zero bytes is an error on most platforms
+    if len == 0 {
+        return Ok(0);
+    }
+
+    let map_len = usize::try_from(len).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "range is too large to map on this platform",
+        )
+    })?;
+
+    // SAFETY: the mapping is read-only and dropped before returning. As with any file mapping,
+    // another process truncating the file concurrently would fault, which is documented on
+    // FileReadMode::Mmap.
+    let map = unsafe {
+        memmap2::MmapOptions::new()
+            .offset(options.offset)
+            .len(map_len)
+            .map(file)?
+    };
+
+    #[cfg(unix)]
+    map.advise(memmap2::Advice::Sequential)?;
+
+    digest.update(&map);
+
+    Ok(len)
+}
+
+#[cfg(not(feature = "mmap"))]
+fn update_mmap(_: &mut Digest, _: &File, _: &FileChecksumOptions) -> io::Result<u64> {
+    Err(unsupported(
+        "memory-mapped reads require the 'mmap' feature",
+    ))
+}
+
+/// O_DIRECT requires the buffer address, file offset and read length to be aligned to the
+/// logical block size, which is at most 4KiB on all common devices.
+#[cfg(all(feature = "direct-io", any(target_os = "linux", target_os = "android")))]
+const DIRECT_IO_ALIGNMENT: usize = 4096;
+
+#[cfg(all(feature = "direct-io", any(target_os = "linux", target_os = "android")))]
+fn open_direct(path: &Path) -> io::Result<File> {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    std::fs::OpenOptions::new()
+        .read(true)
+        .custom_flags(libc::O_DIRECT)
+        .open(path)
+}
+
+#[cfg(not(all(feature = "direct-io", any(target_os = "linux", target_os = "android"))))]
+fn open_direct(_: &Path) -> io::Result<File> {
+    Err(unsupported(
+        "direct I/O requires the 'direct-io' feature on Linux or Android",
+    ))
+}
+
+#[cfg(all(feature = "direct-io", any(target_os = "linux", target_os = "android")))]
+fn update_direct(
+    digest: &mut Digest,
+    file: &File,
+    options: &FileChecksumOptions,
+) -> io::Result<u64> {
+    use std::os::unix::fs::FileExt;
+
+    const ALIGN: usize = DIRECT_IO_ALIGNMENT;
+
+    let chunk_size = options.chunk_size().div_ceil(ALIGN) * ALIGN;
+
+    // over-allocate so an aligned window can be carved out without a custom allocator
+    let mut raw = vec![0u8; chunk_size + ALIGN];
+    let pad = raw.as_ptr().align_offset(ALIGN);
+    let buf = &mut raw[pad..pad + chunk_size];
+
+    // start at the block containing the offset and discard the leading bytes
+    let mut pos = options.offset - options.offset % ALIGN as u64;
+    let mut skip = (options.offset - pos) as usize;
+    let end = options.len.map(|len| options.offset.saturating_add(len));
+    let mut total = 0u64;
+
+    loop {
+        let n = loop {
+            match file.read_at(buf, pos) {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                result => break result?,
+            }
+        };
+        if n == 0 {
+            break;
+        }
+
+        let mut data = &buf[skip.min(n)..n];
+        if let Some(end) = end {
+            let remaining = end - (options.offset + total);
+            data = &data[..data.len().min(remaining.try_into().unwrap_or(usize::MAX))];
+        }
+
+        digest.update(data);
+        total += data.len() as u64;
+        pos += n as u64;
+        skip = 0;
+
+        // an unaligned short read only happens at the end of the file
+        if end == Some(options.offset + total) || n % ALIGN != 0 {
+            break;
+        }
+    }
+
+    if let Some(len) = options.len {
+        if total != len {
+            return Err(unexpected_eof());
+        }
+    }
+
+    Ok(total)
+}
+
+#[cfg(not(all(feature = "direct-io", any(target_os = "linux", target_os = "android"))))]
+fn update_direct(_: &mut Digest, _: &File, _: &FileChecksumOptions) -> io::Result<u64> {
+    Err(unsupported(
+        "direct I/O requires the 'direct-io' feature on Linux or Android",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    #[cfg(feature = "crc32")]
+    use crate::CrcAlgorithm::Crc32IsoHdlc;
+    #[cfg(feature = "crc32")]
+    use crate::{checksum, checksum_file, checksum_file_with_options};
+    use std::path::PathBuf;
+
+    /// Writes a uniquely named temp file which is removed when dropped.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str, data: &[u8]) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "crc_fast_test_{}_{}.bin",
+                name,
+                std::process::id()
+            ));
+            std::fs::write(&path, data).unwrap();
+
+            Self(path)
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn test_data() -> Vec<u8> {
+        (0..(256 * 1024 + 13)).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn available_modes() -> Vec<FileReadMode> {
+        let mut modes = vec![FileReadMode::Buffered];
+        if cfg!(feature = "mmap") {
+            modes.push(FileReadMode::Mmap);
+        }
+        if cfg!(all(
+            feature = "direct-io",
+            any(target_os = "linux", target_os = "android")
+        )) {
+            modes.push(FileReadMode::Direct);
+        }
+
+        modes
+    }
+
+    /// Opens the file for `mode`, returning None when the filesystem doesn't support it (e.g.
+    /// O_DIRECT on tmpfs).
+    fn try_open(path: &Path, mode: FileReadMode) -> Option<File> {
+        match open(path, mode) {
+            Ok(file) => Some(file),
+            Err(e) if mode == FileReadMode::Direct => {
+                eprintln!("Skipping direct I/O due to open error: {}", e);
+                None
+            }
+            Err(e) => panic!("failed to open {}: {}", path.display(), e),
+        }
+    }
+
+    /// Skipping for Miri runs due to isolation constraints, underlying code other than I/O
+    /// already covered by other tests.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_ranges_across_modes() {
+        let data = test_data();
+        let file = TempFile::new("ranges", &data);
+
+        let ranges: [(u64, Option<u64>); 8] = [
+            (0, None),
+            (0, Some(0)),
+            (0, Some(data.len() as u64)),
+            (1, Some(4095)),
+            (4095, Some(4098)),
+            (8192, None),
+            (12345, Some(100_000)),
+            (data.len() as u64, None),
+        ];
+
+        for mode in available_modes() {
+            for chunk_size in [None, Some(1000), Some(4096)] {
+                for (offset, len) in ranges {
+                    let options = FileChecksumOptions::new()
+                        .with_mode(mode)
+                        .with_chunk_size(chunk_size)
+                        .with_range(offset, len);
+                    let start = offset as usize;
+                    let end = len.map_or(data.len(), |len| start + len as usize);
+
+                    let Some(handle) = try_open(&file.0, mode) else {
+                        continue;
+                    };
+
+                    for config in TEST_ALL_CONFIGS {
+                        let mut digest = Digest::new(config.get_algorithm());
+                        let read = update_digest(&mut digest, &handle, &options).unwrap();
+
+                        assert_eq!(read, (end - start) as u64, "{mode:?} {offset} {len:?}");
+                        assert_eq!(
+                            digest.finalize(),
+                            config.checksum_with_reference(&data[start..end]),
+                            "{mode:?} {chunk_size:?} {offset} {len:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[cfg(feature = "crc32")]
+    fn test_range_past_eof_is_an_error() {
+        let data = test_data();
+        let file = TempFile::new("past_eof", &data);
+
+        for mode in available_modes() {
+            for (offset, len) in [
+                (0, data.len() as u64 + 1),
+                (data.len() as u64 - 10, 11),
+                (data.len() as u64 + 4096, 1),
+            ] {
+                let options = FileChecksumOptions::new()
+                    .with_mode(mode)
+                    .with_range(offset, Some(len));
+
+                let Some(handle) = try_open(&file.0, mode) else {
+                    continue;
+                };
+                let error =
+                    update_digest(&mut Digest::new(Crc32IsoHdlc), &handle, &options).unwrap_err();
+
+                assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof, "{mode:?}");
+            }
+        }
+    }
+
+    /// Reading a directory fails after a successful open, which used to be silently treated as
+    /// the end of the file.
+    #[test]
+    #[cfg(unix)]
+    #[cfg_attr(miri, ignore)]
+    #[cfg(feature = "crc32")]
+    fn test_read_errors_are_propagated() {
+        let dir = std::env::temp_dir();
+
+        assert!(checksum_file(Crc32IsoHdlc, &dir, None).is_err());
+        assert!(checksum_file(Crc32IsoHdlc, dir.join("does-not-exist.bin"), None).is_err());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    #[cfg_attr(miri, ignore)]
+    #[cfg(feature = "crc32")]
+    fn test_non_utf8_path() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let mut name = b"crc_fast_test_non_utf8_\x80_".to_vec();
+        name.extend_from_slice(std::process::id().to_string().as_bytes());
+        let path = std::env::temp_dir().join(OsStr::from_bytes(&name));
+        assert!(path.to_str().is_none());
+
+        std::fs::write(&path, b"123456789").unwrap();
+        let result = checksum_file(Crc32IsoHdlc, &path, None);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(result.unwrap(), 0xcbf43926);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[cfg(feature = "crc32")]
+    fn test_open_file_and_path_agree() {
+        let data = test_data();
+        let file = TempFile::new("open_file", &data);
+        let options = FileChecksumOptions::new().with_range(7, Some(70_000));
+
+        let mut digest = Digest::new(Crc32IsoHdlc);
+        let handle = File::open(&file.0).unwrap();
+        assert_eq!(digest.update_file(&handle, &options).unwrap(), 70_000);
+
+        let expected = checksum(Crc32IsoHdlc, &data[7..70_007]);
+        assert_eq!(digest.finalize(), expected);
+        assert_eq!(
+            checksum_file_with_options(Crc32IsoHdlc, &file.0, &options).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "mmap"))]
+    #[cfg_attr(miri, ignore)]
+    #[cfg(feature = "crc32")]
+    fn test_mmap_unsupported_without_feature() {
+        let options = FileChecksumOptions::new().with_mode(FileReadMode::Mmap);
+        let error = checksum_file_with_options(Crc32IsoHdlc, "crc-check.txt", &options);
+
+        assert_eq!(error.unwrap_err().kind(), io::ErrorKind::Unsupported);
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Forging data with a chosen CRC.
+//!
+//! A CRC is affine over GF(2): flipping a bit of the input flips a fixed set of bits of the CRC,
+//! depending only on how many bytes follow it. When the polynomial has an `x^0` term, as every
+//! catalogued one does, any `width` consecutive bits of input can flip every CRC bit
+//! independently, so for `width / 8` bytes at some offset there's exactly one value giving the
+//! whole buffer any chosen CRC, found by solving the `width` linear equations.
+
+use crate::combine;
+use crate::{checksum_with_params, ChecksumBytes, CrcParams};
+
+/// Returns the `width / 8` bytes which, written at `offset` in `data`, give the whole buffer the
+/// CRC `target_crc`.
+///
+/// Useful for keeping a checksum stable after patching a field in a firmware image, or for
+/// building protocol test vectors with a specific CRC. The bytes currently at `offset` are
+/// ignored.
+///
+/// # Panics
+///
+/// Panics if `offset + width / 8` is beyond the end of `data`, or if `target_crc` is wider than
+/// the CRC. Also panics if `target_crc` can't be reached, which only happens for custom
+/// parameters whose polynomial has no `x^0` term (an even `poly`), since their CRC bits can't all
+/// be flipped independently.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum, forge, Calculator, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// let mut image = *b"firmware v2 ....";
+/// let params = *Calculator::new(Crc32IsoHdlc).params();
+///
+/// let patch = forge(params, &image, 12, 0xcafef00d);
+/// image[12..].copy_from_slice(&patch);
+///
+/// assert_eq!(checksum(Crc32IsoHdlc, &image), 0xcafef00d);
+/// ```
+pub fn forge(params: CrcParams, data: &[u8], offset: usize, target_crc: u64) -> ChecksumBytes {
+    let len = params.width as usize / 8;
+
+    assert!(
+        offset.checked_add(len).is_some_and(|end| end <= data.len()),
+        "{len} bytes at offset {offset} don't fit in {} bytes of data",
+        data.len()
+    );
+    assert!(
+        params.width == 64 || target_crc >> params.width == 0,
+        "target CRC {target_crc:#x} is wider than {} bits",
+        params.width
+    );
+
+    let suffix = &data[offset + len..];
+
+    // the CRC with zeros at the offset, which the forged bytes then flip bits of
+    let crc = combine::checksums(
+        checksum_with_params(params, &data[..offset]),
+        checksum_with_params(params, &[0; 8][..len]),
+        len as u64,
+        &params,
+    );
+    let crc = combine::checksums(
+        crc,
+        checksum_with_params(params, suffix),
+        suffix.len() as u64,
+        &params,
+    );
+
+    // the CRC bits flipped by each bit of the last forged byte, shifted through the suffix, and
+    // then back through each earlier forged byte
+    let zero = checksum_with_params(params, &[0]);
+    let mut columns = [0u64; 8];
+    for (bit, column) in columns.iter_mut().enumerate() {
+        let flipped = checksum_with_params(params, &[1 << bit]) ^ zero;
+        *column = combine::zeros(flipped, suffix.len() as u64, &params);
+    }
+
+    // Gaussian elimination, keeping a row for each leading bit along with the forged bits
+    // combined into it
+    let operator = combine::zero_byte_operator(&params);
+    let mut rows = [(0u64, 0u64); 64];
+    for byte in (0..len).rev() {
+        for (bit, column) in columns.iter_mut().enumerate() {
+            let mut row = (*column, 1u64 << (byte * 8 + bit));
+
+            while row.0 != 0 {
+                let lead = 63 - row.0.leading_zeros() as usize;
+                if rows[lead].0 == 0 {
+                    rows[lead] = row;
+                    break;
+                }
+                row = (row.0 ^ rows[lead].0, row.1 ^ rows[lead].1);
+            }
+
+            *column = combine::apply_operator(&operator, *column);
+        }
+    }
+
+    let mut remaining = crc ^ target_crc;
+    let mut forged = 0u64;
+    while remaining != 0 {
+        let lead = 63 - remaining.leading_zeros() as usize;
+        assert!(
+            rows[lead].0 != 0,
+            "{} can't reach every CRC value from {len} bytes",
+            params.name
+        );
+        remaining ^= rows[lead].0;
+        forged ^= rows[lead].1;
+    }
+
+    ChecksumBytes::from_le_u64(forged, len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use crate::test::miri_compatible_proptest_config;
+    use proptest::prelude::*;
+
+    proptest! {
+        #![proptest_config(miri_compatible_proptest_config())]
+
+        #[test]
+        fn prop_forge_reaches_target(
+            data in proptest::collection::vec(any::<u8>(), 8..512),
+            offset in any::<usize>(),
+            target in any::<u64>(),
+        ) {
+            for config in TEST_ALL_CONFIGS {
+                let params = *config.get_params();
+                let len = params.width as usize / 8;
+                let offset = offset % (data.len() - len + 1);
+                let target = target & (u64::MAX >> (64 - params.width));
+
+                let mut forged = data.clone();
+                let bytes = forge(params, &data, offset, target);
+                forged[offset..offset + len].copy_from_slice(&bytes);
+
+                prop_assert_eq!(
+                    checksum_with_params(params, &forged),
+                    target,
+                    "{} at offset {} of {} bytes",
+                    config.get_name(),
+                    offset,
+                    data.len()
+                );
+            }
+        }
+
+        #[test]
+        fn prop_forge_existing_checksum_keeps_bytes(
+            data in proptest::collection::vec(any::<u8>(), 8..512),
+            offset in any::<usize>(),
+        ) {
+            // only one value of the bytes reaches each CRC, so it has to be the current one
+            for config in TEST_ALL_CONFIGS {
+                let params = *config.get_params();
+                let len = params.width as usize / 8;
+                let offset = offset % (data.len() - len + 1);
+
+                let bytes = forge(params, &data, offset, checksum_with_params(params, &data));
+
+                prop_assert_eq!(&*bytes, &data[offset..offset + len], "{}", config.get_name());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "don't fit")]
+    fn test_forge_out_of_range() {
+        let params = CrcParams::new(
+            "CRC-32/ISCSI",
+            32,
+            0x1edc6f41,
+            0xffffffff,
+            true,
+            0xffffffff,
+            0xe3069283,
+        );
+
+        forge(params, b"1", 0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't reach every CRC value")]
+    fn test_forge_even_polynomial() {
+        // without an x^0 term, some CRC values are out of reach, such as 1 here
+        let params = CrcParams::new("CRC-16/EVEN", 16, 0x8004, 0x0, false, 0x0, 0x0);
+
+        forge(params, &[0; 8], 2, 0x1);
+    }
+}
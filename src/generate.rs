@@ -109,8 +109,6 @@
 
 #![allow(dead_code)]
 
-use core::ops::{BitAnd, BitOr, Shl, Shr};
-
 /// Exponents (bit distances) for CRC-16 key generation.
 ///
 /// CRC-16 uses the same exponents as CRC-32 because the folding algorithm operates on
@@ -241,7 +239,7 @@ const CRC64_EXPONENTS: [u64; 23] = [
 
 /// Generates the 23 keys needed to calculate CRCs for a given polynomial using PCLMULQDQ when
 /// folding by 8.
-pub fn keys(width: u8, poly: u64, reflected: bool) -> [u64; 23] {
+pub const fn keys(width: u8, poly: u64, reflected: bool) -> [u64; 23] {
     let mut keys: [u64; 23] = [0; 23];
 
     let exponents = if 16 == width {
@@ -251,7 +249,7 @@ pub fn keys(width: u8, poly: u64, reflected: bool) -> [u64; 23] {
     } else if 64 == width {
         CRC64_EXPONENTS
     } else {
-        panic!("Unsupported width: {width}",);
+        panic!("Unsupported width, must be 16, 32 or 64");
     };
 
     let poly = if 16 == width {
@@ -263,8 +261,10 @@ pub fn keys(width: u8, poly: u64, reflected: bool) -> [u64; 23] {
         poly
     };
 
-    for i in 1..23 {
+    let mut i = 1;
+    while i < 23 {
         keys[i] = key(width, poly, reflected, exponents[i]);
+        i += 1;
     }
 
     keys[7] = mu(width, poly, reflected);
@@ -273,7 +273,7 @@ pub fn keys(width: u8, poly: u64, reflected: bool) -> [u64; 23] {
     keys
 }
 
-fn key(width: u8, poly: u64, reflected: bool, exponent: u64) -> u64 {
+const fn key(width: u8, poly: u64, reflected: bool, exponent: u64) -> u64 {
     if width == 16 {
         crc16_key(exponent, reflected, poly)
     } else if width == 32 {
@@ -281,7 +281,7 @@ fn key(width: u8, poly: u64, reflected: bool, exponent: u64) -> u64 {
     } else if width == 64 {
         crc64_key(exponent, reflected, poly)
     } else {
-        panic!("Unsupported width: {width}",);
+        panic!("Unsupported width, must be 16, 32 or 64");
     }
 }
 
@@ -301,7 +301,7 @@ fn key(width: u8, poly: u64, reflected: bool, exponent: u64) -> u64 {
 ///
 /// For reflected CRC-16, we bit-reverse the 16-bit result and shift right by 31 bits
 /// to align it properly for PCLMULQDQ operations.
-fn crc16_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
+const fn crc16_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
     if exponent < 32 {
         return 0;
     }
@@ -309,7 +309,9 @@ fn crc16_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
     let mut n: u64 = 0x080000000;
     let e = exponent - 31;
 
-    for _ in 0..e {
+    let mut i = 0;
+    while i < e {
+        i += 1;
         n <<= 1;
         if (n & 0x100000000) != 0 {
             n ^= polynomial;
@@ -317,7 +319,7 @@ fn crc16_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
     }
 
     if reflected {
-        bit_reverse(n) >> 31
+        n.reverse_bits() >> 31
     } else {
         n << 32
     }
@@ -349,7 +351,7 @@ fn crc16_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
 ///
 /// If the CRC is reflected (LSB-first), we bit-reverse the result and shift right
 /// by 31 bits to align it properly for PCLMULQDQ operations.
-fn crc32_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
+const fn crc32_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
     if exponent < 32 {
         return 0;
     }
@@ -357,7 +359,9 @@ fn crc32_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
     let mut n: u64 = 0x080000000;
     let e = exponent - 31;
 
-    for _ in 0..e {
+    let mut i = 0;
+    while i < e {
+        i += 1;
         n <<= 1;
         if (n & 0x100000000) != 0 {
             n ^= polynomial;
@@ -365,7 +369,7 @@ fn crc32_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
     }
 
     if reflected {
-        bit_reverse(n) >> 31
+        n.reverse_bits() >> 31
     } else {
         n << 32
     }
@@ -399,7 +403,7 @@ fn crc32_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
 /// 4. **No additional shift in result**: The result is already properly aligned
 ///    - CRC-32 needs `>> 31` adjustment for reflected case
 ///    - CRC-64 doesn't need this because it operates on full 64-bit values
-fn crc64_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
+const fn crc64_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
     if exponent <= 64 {
         return 0;
     }
@@ -411,18 +415,20 @@ fn crc64_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
         exponent - 63
     };
 
-    for _ in 0..e {
+    let mut i = 0;
+    while i < e {
+        i += 1;
         n = (n << 1) ^ ((0_u64.wrapping_sub(n >> 63)) & polynomial);
     }
 
     if reflected {
-        bit_reverse(n)
+        n.reverse_bits()
     } else {
         n
     }
 }
 
-fn polynomial(width: u8, polynomial: u64, reflected: bool) -> u64 {
+const fn polynomial(width: u8, polynomial: u64, reflected: bool) -> u64 {
     if width == 16 {
         crc16_polynomial(polynomial, reflected)
     } else if width == 32 {
@@ -430,7 +436,7 @@ fn polynomial(width: u8, polynomial: u64, reflected: bool) -> u64 {
     } else if width == 64 {
         crc64_polynomial(polynomial, reflected)
     } else {
-        panic!("Unsupported width: {width}",);
+        panic!("Unsupported width, must be 16, 32 or 64");
     }
 }
 
@@ -452,14 +458,14 @@ fn polynomial(width: u8, polynomial: u64, reflected: bool) -> u64 {
 /// 1. Extract the original 16-bit polynomial from the scaled value
 /// 2. Bit-reverse the 16-bit polynomial
 /// 3. Shift left by 1 bit and set the LSB to 1
-fn crc16_polynomial(polynomial: u64, reflected: bool) -> u64 {
+const fn crc16_polynomial(polynomial: u64, reflected: bool) -> u64 {
     if !reflected {
         return polynomial;
     }
 
     // Extract original 16-bit poly from scaled polynomial (poly << 16 | 1 << 32)
     let original_poly = ((polynomial >> 16) & 0xFFFF) as u16;
-    let reversed = bit_reverse(original_poly);
+    let reversed = original_poly.reverse_bits();
     ((reversed as u64) << 1) | 1
 }
 
@@ -490,13 +496,13 @@ fn crc16_polynomial(polynomial: u64, reflected: bool) -> u64 {
 /// - Original: 0x04C11DB7
 /// - Bit-reversed: 0xEDB88320
 /// - Shifted and ORed: 0x1DB710641
-fn crc32_polynomial(polynomial: u64, reflected: bool) -> u64 {
+const fn crc32_polynomial(polynomial: u64, reflected: bool) -> u64 {
     if !reflected {
         return polynomial | (1u64 << 32);
     };
 
     // For 32-bit polynomials, operate on full 33 bits including leading 1
-    let reversed = bit_reverse((polynomial & 0xFFFFFFFF) as u32);
+    let reversed = ((polynomial & 0xFFFFFFFF) as u32).reverse_bits();
     // Need to set bit 32 (33rd bit) to get the 1 in the right position after reflection
     ((reversed as u64) << 1) | 1
 }
@@ -517,16 +523,16 @@ fn crc32_polynomial(polynomial: u64, reflected: bool) -> u64 {
 /// 3. Set LSB to 1
 ///
 /// Unlike CRC-32 which only reverses 32 bits, this reverses the full 64-bit value.
-fn crc64_polynomial(polynomial: u64, reflected: bool) -> u64 {
+const fn crc64_polynomial(polynomial: u64, reflected: bool) -> u64 {
     if !reflected {
         return polynomial;
     };
 
     // For 64-bit polynomials, operate on all 64 bits
-    (bit_reverse(polynomial) << 1) | 1
+    (polynomial.reverse_bits() << 1) | 1
 }
 
-fn mu(width: u8, polynomial: u64, reflected: bool) -> u64 {
+const fn mu(width: u8, polynomial: u64, reflected: bool) -> u64 {
     if width == 16 {
         crc16_mu(polynomial, reflected)
     } else if width == 32 {
@@ -534,7 +540,7 @@ fn mu(width: u8, polynomial: u64, reflected: bool) -> u64 {
     } else if width == 64 {
         crc64_mu(polynomial, reflected)
     } else {
-        panic!("Unsupported width: {width}",);
+        panic!("Unsupported width, must be 16, 32 or 64");
     }
 }
 
@@ -556,11 +562,13 @@ fn mu(width: u8, polynomial: u64, reflected: bool) -> u64 {
 ///    - XOR the dividend with the polynomial
 ///    - Shift dividend left
 /// 3. After 33 iterations, q contains μ
-fn crc16_mu(polynomial: u64, reflected: bool) -> u64 {
+const fn crc16_mu(polynomial: u64, reflected: bool) -> u64 {
     let mut n: u64 = 0x100000000;
     let mut q: u64 = 0;
 
-    for _ in 0..33 {
+    let mut i = 0;
+    while i < 33 {
+        i += 1;
         q <<= 1;
         if n & 0x100000000 != 0 {
             q |= 1;
@@ -570,7 +578,7 @@ fn crc16_mu(polynomial: u64, reflected: bool) -> u64 {
     }
 
     if reflected {
-        bit_reverse(q) >> 31
+        q.reverse_bits() >> 31
     } else {
         q
     }
@@ -600,11 +608,13 @@ fn crc16_mu(polynomial: u64, reflected: bool) -> u64 {
 /// # Reflection
 ///
 /// If reflected, the result is bit-reversed and shifted right by 31 to align properly.
-fn crc32_mu(polynomial: u64, reflected: bool) -> u64 {
+const fn crc32_mu(polynomial: u64, reflected: bool) -> u64 {
     let mut n: u64 = 0x100000000;
     let mut q: u64 = 0;
 
-    for _ in 0..33 {
+    let mut i = 0;
+    while i < 33 {
+        i += 1;
         q <<= 1;
         if n & 0x100000000 != 0 {
             q |= 1;
@@ -614,7 +624,7 @@ fn crc32_mu(polynomial: u64, reflected: bool) -> u64 {
     }
 
     if reflected {
-        bit_reverse(q) >> 31
+        q.reverse_bits() >> 31
     } else {
         q
     }
@@ -654,14 +664,16 @@ fn crc32_mu(polynomial: u64, reflected: bool) -> u64 {
 ///
 /// Unlike CRC-32's `>> 31`, CRC-64 doesn't need an extra shift in the reflected
 /// case because the 64-bit result is already properly aligned.
-fn crc64_mu(polynomial: u64, reflected: bool) -> u64 {
+const fn crc64_mu(polynomial: u64, reflected: bool) -> u64 {
     let mut n_hi: u64 = 0x0000000000000001;
     let mut n_lo: u64 = 0x0000000000000000;
     let mut q: u64 = 0;
 
     let max = if reflected { 64 } else { 65 };
 
-    for _ in 0..max {
+    let mut i = 0;
+    while i < max {
+        i += 1;
         q <<= 1;
         if n_hi != 0 {
             q |= 1;
@@ -672,61 +684,12 @@ fn crc64_mu(polynomial: u64, reflected: bool) -> u64 {
     }
 
     if reflected {
-        bit_reverse(q)
+        q.reverse_bits()
     } else {
         q
     }
 }
 
-/// Reverses the bits of a value (MSB becomes LSB and vice versa).
-///
-/// # Purpose in CRC Calculations
-///
-/// Reflected (LSB-first) CRCs process data in reverse bit order. This function
-/// converts between normal and reflected representations.
-///
-/// # Why Generic?
-///
-/// This works with any unsigned integer type (u32, u64) through trait bounds,
-/// allowing the same code to handle both CRC-32 (which reverses 32 bits) and
-/// CRC-64 (which reverses 64 bits).
-///
-/// # Example
-///
-/// For u32: 0x12345678 → 0x1E6A2C48
-/// - Binary: 0001 0010 0011 0100 0101 0110 0111 1000
-/// - Reversed: 0001 1110 0110 1010 0010 1100 0100 1000
-fn bit_reverse<T>(mut value: T) -> T
-where
-    T: Copy
-        + Default
-        + PartialEq
-        + BitAnd<Output = T>
-        + BitOr<Output = T>
-        + Shl<usize, Output = T>
-        + Shr<usize, Output = T>
-        + From<u8>,
-{
-    let one = T::from(1u8);
-    let mut result = T::default(); // Zero value
-
-    // Get the bit size of type T
-    let bit_size = core::mem::size_of::<T>() * 8;
-
-    for _ in 0..bit_size {
-        // Shift result left by 1
-        result = result << 1;
-
-        // OR with least significant bit of value
-        result = result | (value & one);
-
-        // Shift value right by 1
-        value = value >> 1;
-    }
-
-    result
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -188,6 +188,7 @@ unsafe impl core::alloc::GlobalAlloc for StubAllocator {
     unsafe fn dealloc(&self, _ptr: *mut u8, _layout: core::alloc::Layout) {}
 }
 
+#[cfg(feature = "crc16")]
 use crate::crc16::consts::{
     CRC16_ARC, CRC16_CDMA2000, CRC16_CMS, CRC16_DDS_110, CRC16_DECT_R, CRC16_DECT_X, CRC16_DNP,
     CRC16_EN_13757, CRC16_GENIBUS, CRC16_GSM, CRC16_IBM_3740, CRC16_IBM_SDLC,
@@ -197,31 +198,41 @@ use crate::crc16::consts::{
     CRC16_USB, CRC16_XMODEM,
 };
 
+#[cfg(feature = "crc32")]
 use crate::crc32::consts::{
     CRC32_AIXM, CRC32_AUTOSAR, CRC32_BASE91_D, CRC32_BZIP2, CRC32_CD_ROM_EDC, CRC32_CKSUM,
     CRC32_ISCSI, CRC32_ISO_HDLC, CRC32_JAMCRC, CRC32_MEF, CRC32_MPEG_2, CRC32_XFER,
 };
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
-#[cfg(feature = "std")]
+#[cfg(all(feature = "std", feature = "crc32"))]
 use crate::crc32::fusion;
 
+#[cfg(feature = "crc64")]
 use crate::crc64::consts::{
     CRC64_ECMA_182, CRC64_GO_ISO, CRC64_MS, CRC64_NVME, CRC64_REDIS, CRC64_WE, CRC64_XZ,
 };
-use crate::structs::Calculator;
+use crate::structs::ArchCalculator;
 use crate::traits::CrcCalculator;
+use core::ops::Range;
 #[cfg(feature = "alloc")]
 use digest::DynDigest;
 #[cfg(feature = "alloc")]
 use digest::InvalidBufferSize;
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
 use crate::feature_detection::get_arch_ops;
 #[cfg(feature = "std")]
 use std::fs::File;
 #[cfg(feature = "std")]
-use std::io::{Read, Write};
+use std::io::Write;
+#[cfg(feature = "std")]
+use std::path::Path;
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 extern crate alloc;
@@ -232,22 +243,72 @@ use alloc::string::String;
 
 mod algorithm;
 pub mod arch;
-mod cache;
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+mod async_io;
+#[cfg(feature = "alloc")]
+mod backend_report;
+#[cfg(feature = "bytes")]
+mod buf;
+pub mod cache;
+mod calculator;
+#[cfg(feature = "crc32")]
+mod cksum;
 mod combine;
 mod consts;
 mod crc16;
 mod crc32;
 mod crc64;
+mod custom_tables;
+#[cfg(feature = "alloc")]
+pub mod ecc;
+mod encoding;
 mod enums;
 mod feature_detection;
 #[cfg(feature = "ffi")]
 mod ffi;
+#[cfg(feature = "std")]
+mod file;
+mod forge;
 mod generate;
+mod rolling;
+#[cfg(feature = "alloc")]
+mod self_test;
 mod structs;
 mod tables;
 mod test;
 mod traits;
 
+#[cfg(feature = "tokio")]
+pub use crate::async_io::{checksum_file_async, checksum_file_with_params_async};
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+pub use crate::async_io::{AsyncChecksumReader, AsyncChecksumWriter};
+#[cfg(feature = "bytes")]
+pub use crate::buf::CrcBufMutExt;
+#[cfg(feature = "std")]
+pub use crate::file::{FileChecksumOptions, FileReadMode};
+
+#[cfg(feature = "alloc")]
+pub use crate::backend_report::{backend_report, AlgorithmBackend, BackendDecision, BackendReport};
+pub use crate::calculator::Calculator;
+#[cfg(feature = "crc32")]
+pub use crate::cksum::{posix_cksum, PosixCksum};
+pub use crate::custom_tables::CustomTables;
+pub use crate::encoding::{
+    checksum_from_base64, checksum_from_base64_with_params, checksum_to_be_bytes,
+    checksum_to_be_bytes_with_params, ChecksumBytes, ChecksumDecodeError,
+};
+#[cfg(feature = "alloc")]
+pub use crate::encoding::{checksum_to_base64, checksum_to_base64_with_params};
+pub use crate::feature_detection::{
+    ArchCapabilities, BackendUnavailable, PerformanceTier, FORCE_TIER_ENV,
+};
+pub use crate::forge::forge;
+#[cfg(feature = "alloc")]
+pub use crate::rolling::Chunker;
+pub use crate::rolling::RollingCrc;
+#[cfg(feature = "alloc")]
+pub use crate::self_test::{self_test, SelfTestFailure, SelfTestPath, SelfTestReport};
+
 /// Supported CRC-16, CRC-32, and CRC-64 variants
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CrcAlgorithm {
@@ -438,12 +499,20 @@ pub struct CrcParams {
 ///
 /// Note: CrcParams is passed by reference to avoid copying the large struct (200+ bytes)
 /// which causes significant overhead for small data sizes.
-type CalculatorFn = fn(
+pub(crate) type CalculatorFn = fn(
     u64,        // state
     &[u8],      // data
     &CrcParams, // CRC implementation parameters
 ) -> u64;
 
+/// Zeros fed through directly by [Digest::update_zeros], rather than with the zero-extension
+/// operator.
+static ZEROS: [u8; 4096] = [0; 4096];
+
+/// Building the zero-extension operator costs tens of microseconds, so runs of zeros up to this
+/// long are quicker to feed through the accelerated calculator.
+const ZEROS_FEED_LIMIT: u64 = 64 * 1024;
+
 /// Represents a CRC Digest, which is used to compute CRC checksums.
 ///
 /// The `Digest` struct maintains the state of the CRC computation, including
@@ -462,6 +531,12 @@ pub struct Digest {
 
     /// The function used to perform the CRC calculation.
     calculator: CalculatorFn,
+
+    /// The static table for short inputs, resolved alongside `calculator` by a [Calculator].
+    small: Option<arch::SmallTable>,
+
+    /// The caller-owned lookup table for custom parameters, used instead of `calculator`.
+    tables: Option<&'static CustomTables>,
 }
 
 #[cfg(feature = "alloc")]
@@ -536,6 +611,8 @@ impl Digest {
             amount: 0,
             params,
             calculator,
+            small: None,
+            tables: None,
         }
     }
 
@@ -571,6 +648,8 @@ impl Digest {
             amount: 0,
             params,
             calculator,
+            small: None,
+            tables: None,
         }
     }
 
@@ -600,20 +679,88 @@ impl Digest {
     /// ```
     #[inline(always)]
     pub fn new_with_params(params: CrcParams) -> Self {
-        let calculator = Calculator::calculate as CalculatorFn;
+        let calculator = ArchCalculator::calculate as CalculatorFn;
 
         Self {
             state: params.init_algorithm,
             amount: 0,
             params,
             calculator,
+            small: None,
+            tables: None,
+        }
+    }
+
+    /// Creates a new `Digest` instance with custom CRC parameters and a caller-owned lookup table,
+    /// which works without the `alloc` feature. See [CustomTables].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{CrcParams, CustomTables, Digest};
+    ///
+    /// // CRC-32/ISO-HDLC, as custom parameters, with the table generated at compile time
+    /// static TABLES: CustomTables = CustomTables::new(CrcParams::const_new(
+    ///     "CRC-32/CUSTOM", 32, 0x04c11db7, 0xffffffff, true, 0xffffffff, 0xcbf43926,
+    /// ));
+    ///
+    /// let mut digest = Digest::new_with_tables(&TABLES);
+    /// digest.update(b"123456789");
+    ///
+    /// assert_eq!(digest.finalize(), 0xcbf43926);
+    /// ```
+    #[inline(always)]
+    pub fn new_with_tables(tables: &'static CustomTables) -> Self {
+        let params = *tables.params();
+
+        Self {
+            state: params.init_algorithm,
+            amount: 0,
+            params,
+            calculator: ArchCalculator::calculate as CalculatorFn,
+            small: None,
+            tables: Some(tables),
         }
     }
 
+    /// Creates a new `Digest` instance for the specified CRC algorithm which always uses the given
+    /// backend, regardless of the detected or overridden performance tier.
+    ///
+    /// Returns an error if this CPU doesn't support the backend.
+    ///
+    /// # Panics
+    ///
+    /// Panics for the custom algorithms, which require parameters via
+    /// [new_with_params](Digest::new_with_params).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{Digest, PerformanceTier, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let mut digest = Digest::new_with_backend(Crc32IsoHdlc, PerformanceTier::SoftwareTable)?;
+    /// digest.update(b"123456789");
+    ///
+    /// assert_eq!(digest.finalize(), 0xcbf43926);
+    /// # Ok::<(), crc_fast::BackendUnavailable>(())
+    /// ```
+    pub fn new_with_backend(
+        algorithm: CrcAlgorithm,
+        backend: PerformanceTier,
+    ) -> Result<Self, BackendUnavailable> {
+        Ok(Calculator::new_with_backend(algorithm, backend)?.digest())
+    }
+
     /// Updates the CRC state with the given data.
     #[inline(always)]
     pub fn update(&mut self, data: &[u8]) {
-        self.state = (self.calculator)(self.state, data, &self.params);
+        self.state = match (self.tables, self.small) {
+            (Some(tables), _) => tables.update(self.state, data),
+            (None, Some(table)) if data.len() < arch::SMALL_INPUT_THRESHOLD => {
+                table.update(self.state, data)
+            }
+            _ => (self.calculator)(self.state, data, &self.params),
+        };
         self.amount += data.len() as u64;
     }
 
@@ -655,6 +802,78 @@ impl Digest {
         ) ^ self.params.xorout;
     }
 
+    /// Updates the CRC state as if `len` zero bytes were written, without needing them in memory.
+    ///
+    /// Long runs are skipped with the zero-extension operator, in O(log(len)) time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{checksum, Digest, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let mut digest = Digest::new(Crc32IsoHdlc);
+    /// digest.update(b"header");
+    /// digest.update_zeros(1 << 20);
+    ///
+    /// let mut expected = b"header".to_vec();
+    /// expected.resize(6 + (1 << 20), 0);
+    ///
+    /// assert_eq!(digest.finalize(), checksum(Crc32IsoHdlc, &expected));
+    /// ```
+    pub fn update_zeros(&mut self, len: u64) {
+        if len <= ZEROS_FEED_LIMIT {
+            let mut remaining = len as usize;
+            while remaining > 0 {
+                let chunk = remaining.min(ZEROS.len());
+                self.update(&ZEROS[..chunk]);
+                remaining -= chunk;
+            }
+
+            return;
+        }
+
+        self.state = combine::zeros(self.state, len, &self.params);
+        self.amount += len;
+    }
+
+    /// Updates the CRC state with `data`, treating the bytes in each of `ranges` as zeros, without
+    /// copying it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a range is outside `data`, or the ranges aren't in ascending order without
+    /// overlapping.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{checksum, Digest, CrcAlgorithm::Crc32Iscsi};
+    ///
+    /// let block = b"data\x12\x34\x56\x78more data";
+    ///
+    /// let mut digest = Digest::new(Crc32Iscsi);
+    /// digest.update_with_masked_ranges(block, &[4..8]);
+    ///
+    /// assert_eq!(digest.finalize(), checksum(Crc32Iscsi, b"data\0\0\0\0more data"));
+    /// ```
+    pub fn update_with_masked_ranges(&mut self, data: &[u8], ranges: &[Range<usize>]) {
+        let mut position = 0;
+
+        for range in ranges {
+            assert!(
+                position <= range.start && range.start <= range.end && range.end <= data.len(),
+                "masked range {range:?} must be within {} bytes of data, after any earlier ranges",
+                data.len()
+            );
+
+            self.update(&data[position..range.start]);
+            self.update_zeros((range.end - range.start) as u64);
+            position = range.end;
+        }
+
+        self.update(&data[position..]);
+    }
+
     /// Gets the amount of data processed so far
     #[inline(always)]
     pub fn get_amount(&self) -> u64 {
@@ -681,6 +900,42 @@ impl Digest {
     pub fn get_state(&self) -> u64 {
         self.state
     }
+
+    /// Updates the CRC state with the contents of an already open file, returning the number of
+    /// bytes read.
+    ///
+    /// The range in `options` is relative to the start of the file. Buffered reads seek to the
+    /// start of the range (non-seekable files, such as pipes, are read from their current
+    /// position when the range starts at zero) and leave the cursor after the last byte read.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the file cannot be read, if the range extends past
+    /// the end of the file, or if the read mode isn't supported by this build.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::fs::File;
+    /// use crc_fast::{Digest, FileChecksumOptions, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// // for example/test purposes only, use your own file
+    /// let file = File::open("crc-check.txt").unwrap();
+    ///
+    /// let mut digest = Digest::new(Crc32IsoHdlc);
+    /// digest.update(b"1234");
+    /// digest.update_file(&file, &FileChecksumOptions::new().with_range(4, None)).unwrap();
+    ///
+    /// assert_eq!(digest.finalize(), 0xcbf43926);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn update_file(
+        &mut self,
+        file: &File,
+        options: &FileChecksumOptions,
+    ) -> Result<u64, std::io::Error> {
+        file::update_digest(self, file, options)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -727,152 +982,211 @@ impl Write for Digest {
 /// ```
 #[inline]
 #[allow(deprecated)]
+#[cfg_attr(
+    not(any(feature = "crc16", feature = "crc32", feature = "crc64")),
+    allow(unused_variables)
+)]
 pub fn checksum(algorithm: CrcAlgorithm, buf: &[u8]) -> u64 {
     // avoid using get_calculator_params() here to reduce overhead for small data sizes
     match algorithm {
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Arc => {
-            Calculator::calculate(CRC16_ARC.init, buf, &CRC16_ARC) ^ CRC16_ARC.xorout
+            ArchCalculator::calculate(CRC16_ARC.init, buf, &CRC16_ARC) ^ CRC16_ARC.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Cdma2000 => {
-            Calculator::calculate(CRC16_CDMA2000.init, buf, &CRC16_CDMA2000) ^ CRC16_CDMA2000.xorout
+            ArchCalculator::calculate(CRC16_CDMA2000.init, buf, &CRC16_CDMA2000)
+                ^ CRC16_CDMA2000.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Cms => {
-            Calculator::calculate(CRC16_CMS.init, buf, &CRC16_CMS) ^ CRC16_CMS.xorout
+            ArchCalculator::calculate(CRC16_CMS.init, buf, &CRC16_CMS) ^ CRC16_CMS.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Dds110 => {
-            Calculator::calculate(CRC16_DDS_110.init, buf, &CRC16_DDS_110) ^ CRC16_DDS_110.xorout
+            ArchCalculator::calculate(CRC16_DDS_110.init, buf, &CRC16_DDS_110)
+                ^ CRC16_DDS_110.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16DectR => {
-            Calculator::calculate(CRC16_DECT_R.init, buf, &CRC16_DECT_R) ^ CRC16_DECT_R.xorout
+            ArchCalculator::calculate(CRC16_DECT_R.init, buf, &CRC16_DECT_R) ^ CRC16_DECT_R.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16DectX => {
-            Calculator::calculate(CRC16_DECT_X.init, buf, &CRC16_DECT_X) ^ CRC16_DECT_X.xorout
+            ArchCalculator::calculate(CRC16_DECT_X.init, buf, &CRC16_DECT_X) ^ CRC16_DECT_X.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Dnp => {
-            Calculator::calculate(CRC16_DNP.init, buf, &CRC16_DNP) ^ CRC16_DNP.xorout
+            ArchCalculator::calculate(CRC16_DNP.init, buf, &CRC16_DNP) ^ CRC16_DNP.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16En13757 => {
-            Calculator::calculate(CRC16_EN_13757.init, buf, &CRC16_EN_13757) ^ CRC16_EN_13757.xorout
+            ArchCalculator::calculate(CRC16_EN_13757.init, buf, &CRC16_EN_13757)
+                ^ CRC16_EN_13757.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Genibus => {
-            Calculator::calculate(CRC16_GENIBUS.init, buf, &CRC16_GENIBUS) ^ CRC16_GENIBUS.xorout
+            ArchCalculator::calculate(CRC16_GENIBUS.init, buf, &CRC16_GENIBUS)
+                ^ CRC16_GENIBUS.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Gsm => {
-            Calculator::calculate(CRC16_GSM.init, buf, &CRC16_GSM) ^ CRC16_GSM.xorout
+            ArchCalculator::calculate(CRC16_GSM.init, buf, &CRC16_GSM) ^ CRC16_GSM.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Ibm3740 => {
-            Calculator::calculate(CRC16_IBM_3740.init, buf, &CRC16_IBM_3740) ^ CRC16_IBM_3740.xorout
+            ArchCalculator::calculate(CRC16_IBM_3740.init, buf, &CRC16_IBM_3740)
+                ^ CRC16_IBM_3740.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16IbmSdlc => {
-            Calculator::calculate(CRC16_IBM_SDLC.init, buf, &CRC16_IBM_SDLC) ^ CRC16_IBM_SDLC.xorout
+            ArchCalculator::calculate(CRC16_IBM_SDLC.init, buf, &CRC16_IBM_SDLC)
+                ^ CRC16_IBM_SDLC.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16IsoIec144433A => {
-            Calculator::calculate(
+            ArchCalculator::calculate(
                 CRC16_ISO_IEC_14443_3_A.init_algorithm,
                 buf,
                 &CRC16_ISO_IEC_14443_3_A,
             ) ^ CRC16_ISO_IEC_14443_3_A.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Kermit => {
-            Calculator::calculate(CRC16_KERMIT.init, buf, &CRC16_KERMIT) ^ CRC16_KERMIT.xorout
+            ArchCalculator::calculate(CRC16_KERMIT.init, buf, &CRC16_KERMIT) ^ CRC16_KERMIT.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Lj1200 => {
-            Calculator::calculate(CRC16_LJ1200.init, buf, &CRC16_LJ1200) ^ CRC16_LJ1200.xorout
+            ArchCalculator::calculate(CRC16_LJ1200.init, buf, &CRC16_LJ1200) ^ CRC16_LJ1200.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16M17 => {
-            Calculator::calculate(CRC16_M17.init, buf, &CRC16_M17) ^ CRC16_M17.xorout
+            ArchCalculator::calculate(CRC16_M17.init, buf, &CRC16_M17) ^ CRC16_M17.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16MaximDow => {
-            Calculator::calculate(CRC16_MAXIM_DOW.init, buf, &CRC16_MAXIM_DOW)
+            ArchCalculator::calculate(CRC16_MAXIM_DOW.init, buf, &CRC16_MAXIM_DOW)
                 ^ CRC16_MAXIM_DOW.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Mcrf4xx => {
-            Calculator::calculate(CRC16_MCRF4XX.init, buf, &CRC16_MCRF4XX) ^ CRC16_MCRF4XX.xorout
+            ArchCalculator::calculate(CRC16_MCRF4XX.init, buf, &CRC16_MCRF4XX)
+                ^ CRC16_MCRF4XX.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Modbus => {
-            Calculator::calculate(CRC16_MODBUS.init, buf, &CRC16_MODBUS) ^ CRC16_MODBUS.xorout
+            ArchCalculator::calculate(CRC16_MODBUS.init, buf, &CRC16_MODBUS) ^ CRC16_MODBUS.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Nrsc5 => {
-            Calculator::calculate(CRC16_NRSC_5.init, buf, &CRC16_NRSC_5) ^ CRC16_NRSC_5.xorout
+            ArchCalculator::calculate(CRC16_NRSC_5.init, buf, &CRC16_NRSC_5) ^ CRC16_NRSC_5.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16OpensafetyA => {
-            Calculator::calculate(CRC16_OPENSAFETY_A.init, buf, &CRC16_OPENSAFETY_A)
+            ArchCalculator::calculate(CRC16_OPENSAFETY_A.init, buf, &CRC16_OPENSAFETY_A)
                 ^ CRC16_OPENSAFETY_A.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16OpensafetyB => {
-            Calculator::calculate(CRC16_OPENSAFETY_B.init, buf, &CRC16_OPENSAFETY_B)
+            ArchCalculator::calculate(CRC16_OPENSAFETY_B.init, buf, &CRC16_OPENSAFETY_B)
                 ^ CRC16_OPENSAFETY_B.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Profibus => {
-            Calculator::calculate(CRC16_PROFIBUS.init, buf, &CRC16_PROFIBUS) ^ CRC16_PROFIBUS.xorout
+            ArchCalculator::calculate(CRC16_PROFIBUS.init, buf, &CRC16_PROFIBUS)
+                ^ CRC16_PROFIBUS.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Riello => {
-            Calculator::calculate(CRC16_RIELLO.init_algorithm, buf, &CRC16_RIELLO)
+            ArchCalculator::calculate(CRC16_RIELLO.init_algorithm, buf, &CRC16_RIELLO)
                 ^ CRC16_RIELLO.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16SpiFujitsu => {
-            Calculator::calculate(CRC16_SPI_FUJITSU.init, buf, &CRC16_SPI_FUJITSU)
+            ArchCalculator::calculate(CRC16_SPI_FUJITSU.init, buf, &CRC16_SPI_FUJITSU)
                 ^ CRC16_SPI_FUJITSU.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16T10Dif => {
-            Calculator::calculate(CRC16_T10_DIF.init, buf, &CRC16_T10_DIF) ^ CRC16_T10_DIF.xorout
+            ArchCalculator::calculate(CRC16_T10_DIF.init, buf, &CRC16_T10_DIF)
+                ^ CRC16_T10_DIF.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Teledisk => {
-            Calculator::calculate(CRC16_TELEDISK.init, buf, &CRC16_TELEDISK) ^ CRC16_TELEDISK.xorout
+            ArchCalculator::calculate(CRC16_TELEDISK.init, buf, &CRC16_TELEDISK)
+                ^ CRC16_TELEDISK.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Tms37157 => {
-            Calculator::calculate(CRC16_TMS37157.init_algorithm, buf, &CRC16_TMS37157)
+            ArchCalculator::calculate(CRC16_TMS37157.init_algorithm, buf, &CRC16_TMS37157)
                 ^ CRC16_TMS37157.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Umts => {
-            Calculator::calculate(CRC16_UMTS.init, buf, &CRC16_UMTS) ^ CRC16_UMTS.xorout
+            ArchCalculator::calculate(CRC16_UMTS.init, buf, &CRC16_UMTS) ^ CRC16_UMTS.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Usb => {
-            Calculator::calculate(CRC16_USB.init, buf, &CRC16_USB) ^ CRC16_USB.xorout
+            ArchCalculator::calculate(CRC16_USB.init, buf, &CRC16_USB) ^ CRC16_USB.xorout
         }
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16Xmodem => {
-            Calculator::calculate(CRC16_XMODEM.init, buf, &CRC16_XMODEM) ^ CRC16_XMODEM.xorout
+            ArchCalculator::calculate(CRC16_XMODEM.init, buf, &CRC16_XMODEM) ^ CRC16_XMODEM.xorout
         }
+        #[cfg(feature = "crc32")]
         CrcAlgorithm::Crc32Aixm => {
-            Calculator::calculate(CRC32_AIXM.init, buf, &CRC32_AIXM) ^ CRC32_AIXM.xorout
+            ArchCalculator::calculate(CRC32_AIXM.init, buf, &CRC32_AIXM) ^ CRC32_AIXM.xorout
         }
+        #[cfg(feature = "crc32")]
         CrcAlgorithm::Crc32Autosar => {
-            Calculator::calculate(CRC32_AUTOSAR.init, buf, &CRC32_AUTOSAR) ^ CRC32_AUTOSAR.xorout
+            ArchCalculator::calculate(CRC32_AUTOSAR.init, buf, &CRC32_AUTOSAR)
+                ^ CRC32_AUTOSAR.xorout
         }
+        #[cfg(feature = "crc32")]
         CrcAlgorithm::Crc32Base91D => {
-            Calculator::calculate(CRC32_BASE91_D.init, buf, &CRC32_BASE91_D) ^ CRC32_BASE91_D.xorout
+            ArchCalculator::calculate(CRC32_BASE91_D.init, buf, &CRC32_BASE91_D)
+                ^ CRC32_BASE91_D.xorout
         }
+        #[cfg(feature = "crc32")]
         CrcAlgorithm::Crc32Bzip2 => {
-            Calculator::calculate(CRC32_BZIP2.init, buf, &CRC32_BZIP2) ^ CRC32_BZIP2.xorout
+            ArchCalculator::calculate(CRC32_BZIP2.init, buf, &CRC32_BZIP2) ^ CRC32_BZIP2.xorout
         }
+        #[cfg(feature = "crc32")]
         CrcAlgorithm::Crc32CdRomEdc => {
-            Calculator::calculate(CRC32_CD_ROM_EDC.init, buf, &CRC32_CD_ROM_EDC)
+            ArchCalculator::calculate(CRC32_CD_ROM_EDC.init, buf, &CRC32_CD_ROM_EDC)
                 ^ CRC32_CD_ROM_EDC.xorout
         }
+        #[cfg(feature = "crc32")]
         CrcAlgorithm::Crc32Cksum => {
-            Calculator::calculate(CRC32_CKSUM.init, buf, &CRC32_CKSUM) ^ CRC32_CKSUM.xorout
+            ArchCalculator::calculate(CRC32_CKSUM.init, buf, &CRC32_CKSUM) ^ CRC32_CKSUM.xorout
         }
         CrcAlgorithm::Crc32Custom => {
             panic!("Custom CRC-32 requires parameters via CrcParams::new()")
         }
+        #[cfg(feature = "crc32")]
         CrcAlgorithm::Crc32Iscsi => {
             crc32_iscsi_calculator(CRC32_ISCSI.init, buf, &CRC32_ISCSI) ^ CRC32_ISCSI.xorout
         }
+        #[cfg(feature = "crc32")]
         CrcAlgorithm::Crc32IsoHdlc => {
             crc32_iso_hdlc_calculator(CRC32_ISO_HDLC.init, buf, &CRC32_ISO_HDLC)
                 ^ CRC32_ISO_HDLC.xorout
         }
+        #[cfg(feature = "crc32")]
         CrcAlgorithm::Crc32Jamcrc => {
-            Calculator::calculate(CRC32_JAMCRC.init, buf, &CRC32_JAMCRC) ^ CRC32_JAMCRC.xorout
+            ArchCalculator::calculate(CRC32_JAMCRC.init, buf, &CRC32_JAMCRC) ^ CRC32_JAMCRC.xorout
         }
+        #[cfg(feature = "crc32")]
         CrcAlgorithm::Crc32Mef => {
-            Calculator::calculate(CRC32_MEF.init, buf, &CRC32_MEF) ^ CRC32_MEF.xorout
+            ArchCalculator::calculate(CRC32_MEF.init, buf, &CRC32_MEF) ^ CRC32_MEF.xorout
         }
+        #[cfg(feature = "crc32")]
         CrcAlgorithm::Crc32Mpeg2 => {
-            Calculator::calculate(CRC32_MPEG_2.init, buf, &CRC32_MPEG_2) ^ CRC32_MPEG_2.xorout
+            ArchCalculator::calculate(CRC32_MPEG_2.init, buf, &CRC32_MPEG_2) ^ CRC32_MPEG_2.xorout
         }
+        #[cfg(feature = "crc32")]
         CrcAlgorithm::Crc32Xfer => {
-            Calculator::calculate(CRC32_XFER.init, buf, &CRC32_XFER) ^ CRC32_XFER.xorout
+            ArchCalculator::calculate(CRC32_XFER.init, buf, &CRC32_XFER) ^ CRC32_XFER.xorout
         }
         CrcAlgorithm::CrcCustom => {
             panic!("Custom CRC requires parameters via CrcParams::new()")
@@ -880,27 +1194,37 @@ pub fn checksum(algorithm: CrcAlgorithm, buf: &[u8]) -> u64 {
         CrcAlgorithm::Crc64Custom => {
             panic!("Custom CRC-64 requires parameters via CrcParams::new()")
         }
+        #[cfg(feature = "crc64")]
         CrcAlgorithm::Crc64Ecma182 => {
-            Calculator::calculate(CRC64_ECMA_182.init, buf, &CRC64_ECMA_182) ^ CRC64_ECMA_182.xorout
+            ArchCalculator::calculate(CRC64_ECMA_182.init, buf, &CRC64_ECMA_182)
+                ^ CRC64_ECMA_182.xorout
         }
+        #[cfg(feature = "crc64")]
         CrcAlgorithm::Crc64GoIso => {
-            Calculator::calculate(CRC64_GO_ISO.init, buf, &CRC64_GO_ISO) ^ CRC64_GO_ISO.xorout
+            ArchCalculator::calculate(CRC64_GO_ISO.init, buf, &CRC64_GO_ISO) ^ CRC64_GO_ISO.xorout
         }
+        #[cfg(feature = "crc64")]
         CrcAlgorithm::Crc64Ms => {
-            Calculator::calculate(CRC64_MS.init, buf, &CRC64_MS) ^ CRC64_MS.xorout
+            ArchCalculator::calculate(CRC64_MS.init, buf, &CRC64_MS) ^ CRC64_MS.xorout
         }
+        #[cfg(feature = "crc64")]
         CrcAlgorithm::Crc64Nvme => {
-            Calculator::calculate(CRC64_NVME.init, buf, &CRC64_NVME) ^ CRC64_NVME.xorout
+            ArchCalculator::calculate(CRC64_NVME.init, buf, &CRC64_NVME) ^ CRC64_NVME.xorout
         }
+        #[cfg(feature = "crc64")]
         CrcAlgorithm::Crc64Redis => {
-            Calculator::calculate(CRC64_REDIS.init, buf, &CRC64_REDIS) ^ CRC64_REDIS.xorout
+            ArchCalculator::calculate(CRC64_REDIS.init, buf, &CRC64_REDIS) ^ CRC64_REDIS.xorout
         }
+        #[cfg(feature = "crc64")]
         CrcAlgorithm::Crc64We => {
-            Calculator::calculate(CRC64_WE.init, buf, &CRC64_WE) ^ CRC64_WE.xorout
+            ArchCalculator::calculate(CRC64_WE.init, buf, &CRC64_WE) ^ CRC64_WE.xorout
         }
+        #[cfg(feature = "crc64")]
         CrcAlgorithm::Crc64Xz => {
-            Calculator::calculate(CRC64_XZ.init, buf, &CRC64_XZ) ^ CRC64_XZ.xorout
+            ArchCalculator::calculate(CRC64_XZ.init, buf, &CRC64_XZ) ^ CRC64_XZ.xorout
         }
+        #[cfg(not(all(feature = "crc16", feature = "crc32", feature = "crc64")))]
+        _ => unsupported_algorithm(algorithm),
     }
 }
 
@@ -927,11 +1251,40 @@ pub fn checksum(algorithm: CrcAlgorithm, buf: &[u8]) -> u64 {
 /// assert_eq!(checksum, 0xcbf43926);
 /// ```
 pub fn checksum_with_params(params: CrcParams, buf: &[u8]) -> u64 {
-    let calculator = Calculator::calculate as CalculatorFn;
+    let calculator = ArchCalculator::calculate as CalculatorFn;
 
     calculator(params.init_algorithm, buf, &params) ^ params.xorout
 }
 
+/// Computes the CRC checksum for the given data using custom CRC parameters and a caller-owned
+/// lookup table, which works without the `alloc` feature. See [CustomTables].
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum_with_tables, CrcParams, CustomTables};
+///
+/// // Define custom CRC-32 parameters (equivalent to CRC-32/ISO-HDLC)
+/// let custom_params = CrcParams::new(
+///     "CRC-32/CUSTOM",
+///     32,
+///     0x04c11db7,
+///     0xffffffff,
+///     true,
+///     0xffffffff,
+///     0xcbf43926,
+/// );
+///
+/// let tables = CustomTables::new(custom_params);
+///
+/// assert_eq!(checksum_with_tables(&tables, b"123456789"), 0xcbf43926);
+/// ```
+pub fn checksum_with_tables(tables: &CustomTables, buf: &[u8]) -> u64 {
+    let params = tables.params();
+
+    tables.update(params.init_algorithm, buf) ^ params.xorout
+}
+
 /// Computes the CRC checksum for the given file using the specified algorithm.
 ///
 /// Appears to be much faster (~2X) than using Writer and io::*, at least on Apple M2 Ultra
@@ -958,10 +1311,14 @@ pub fn checksum_with_params(params: CrcParams, buf: &[u8]) -> u64 {
 #[inline(always)]
 pub fn checksum_file(
     algorithm: CrcAlgorithm,
-    path: &str,
+    path: impl AsRef<Path>,
     chunk_size: Option<usize>,
 ) -> Result<u64, std::io::Error> {
-    checksum_file_with_digest(Digest::new(algorithm), path, chunk_size)
+    checksum_file_with_digest(
+        Digest::new(algorithm),
+        path.as_ref(),
+        &FileChecksumOptions::new().with_chunk_size(chunk_size),
+    )
 }
 
 /// Computes the CRC checksum for the given file using custom CRC parameters.
@@ -1000,12 +1357,71 @@ pub fn checksum_file(
 #[cfg(feature = "std")]
 pub fn checksum_file_with_params(
     params: CrcParams,
-    path: &str,
+    path: impl AsRef<Path>,
     chunk_size: Option<usize>,
 ) -> Result<u64, std::io::Error> {
-    checksum_file_with_digest(Digest::new_with_params(params), path, chunk_size)
+    checksum_file_with_digest(
+        Digest::new_with_params(params),
+        path.as_ref(),
+        &FileChecksumOptions::new().with_chunk_size(chunk_size),
+    )
+}
+
+/// Computes the CRC checksum for a file, or a byte range of it, using the specified algorithm
+/// and read strategy.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be opened or read, if the range
+/// extends past the end of the file, or if the read mode isn't supported by this build.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum_file_with_options, FileChecksumOptions, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// // for example/test purposes only, use your own file path
+/// let options = FileChecksumOptions::new().with_range(0, Some(9));
+///
+/// let checksum = checksum_file_with_options(Crc32IsoHdlc, "crc-check.txt", &options);
+///
+/// assert_eq!(checksum.unwrap(), 0xcbf43926);
+/// ```
+#[cfg(feature = "std")]
+pub fn checksum_file_with_options(
+    algorithm: CrcAlgorithm,
+    path: impl AsRef<Path>,
+    options: &FileChecksumOptions,
+) -> Result<u64, std::io::Error> {
+    checksum_file_with_digest(Digest::new(algorithm), path.as_ref(), options)
 }
 
+/// Computes the CRC checksum for a file, or a byte range of it, using custom CRC parameters and
+/// the specified read strategy.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be opened or read, if the range
+/// extends past the end of the file, or if the read mode isn't supported by this build.
+#[cfg(feature = "std")]
+pub fn checksum_file_with_params_and_options(
+    params: CrcParams,
+    path: impl AsRef<Path>,
+    options: &FileChecksumOptions,
+) -> Result<u64, std::io::Error> {
+    checksum_file_with_digest(Digest::new_with_params(params), path.as_ref(), options)
+}
+
+/// Default read size when checksumming files.
+///
+/// 512KiB was fastest in my benchmarks on an Apple M2 Ultra
+///
+/// 4KiB ~7GiB/s
+/// 64KiB ~22 GiB/s
+/// 512KiB ~24 GiB/s
+#[cfg(feature = "std")]
+pub(crate) const DEFAULT_FILE_CHUNK_SIZE: usize = 524288;
+
 /// Computes the CRC checksum for the given file using the specified Digest.
 ///
 /// # Errors
@@ -1014,26 +1430,12 @@ pub fn checksum_file_with_params(
 #[cfg(feature = "std")]
 fn checksum_file_with_digest(
     mut digest: Digest,
-    path: &str,
-    chunk_size: Option<usize>,
+    path: &Path,
+    options: &FileChecksumOptions,
 ) -> Result<u64, std::io::Error> {
-    let mut file = File::open(path)?;
-
-    // 512KiB KiB was fastest in my benchmarks on an Apple M2 Ultra
-    //
-    // 4KiB ~7GiB/s
-    // 64KiB ~22 GiB/s
-    // 512KiB ~24 GiB/s
-    let chunk_size = chunk_size.unwrap_or(524288);
+    let file = file::open(path, options.mode)?;
 
-    let mut buf = vec![0; chunk_size];
-
-    while let Ok(n) = file.read(&mut buf) {
-        if n == 0 {
-            break;
-        }
-        digest.update(&buf[..n]);
-    }
+    file::update_digest(&mut digest, &file, options)?;
 
     Ok(digest.finalize())
 }
@@ -1095,6 +1497,54 @@ pub fn checksum_combine_with_params(
     combine::checksums(checksum1, checksum2, checksum2_len, &params)
 }
 
+/// Computes the CRC checksum for the given data as if the bytes in `range` were zeros, without
+/// copying it.
+///
+/// Many formats store their own CRC inside the region it covers, such as ext4 group descriptors
+/// or ZIP local headers, and define it as computed with that field zeroed.
+///
+/// # Panics
+///
+/// Panics if `range` is outside `data`, or its start is after its end.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum, checksum_with_masked_range, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// let header = b"HDR1\xde\xad\xbe\xefpayload";
+///
+/// assert_eq!(
+///     checksum_with_masked_range(Crc32IsoHdlc, header, 4..8),
+///     checksum(Crc32IsoHdlc, b"HDR1\0\0\0\0payload")
+/// );
+/// ```
+pub fn checksum_with_masked_range(
+    algorithm: CrcAlgorithm,
+    data: &[u8],
+    range: Range<usize>,
+) -> u64 {
+    checksum_with_masked_ranges(algorithm, data, &[range])
+}
+
+/// Computes the CRC checksum for the given data as if the bytes in each of `ranges` were zeros,
+/// without copying it.
+///
+/// # Panics
+///
+/// Panics if a range is outside `data`, or the ranges aren't in ascending order without
+/// overlapping.
+pub fn checksum_with_masked_ranges(
+    algorithm: CrcAlgorithm,
+    data: &[u8],
+    ranges: &[Range<usize>],
+) -> u64 {
+    let mut digest = Digest::new(algorithm);
+    digest.update_with_masked_ranges(data, ranges);
+
+    digest.finalize()
+}
+
 /// Returns the target used to calculate the CRC checksum for the specified algorithm.
 ///
 /// This function provides visibility into the active performance tier being used for CRC calculations.
@@ -1103,8 +1553,9 @@ pub fn checksum_combine_with_params(
 ///
 /// The performance tier system provides graceful degradation across different hardware capabilities:
 /// - **AArch64**: `aarch64-aes-sha3` (highest) → `aarch64-aes-pmull` (baseline)
-/// - **x86_64**: `x86_64-avx512-vpclmulqdq` (highest) → `x86_64-avx512-pclmulqdq` (mid) → `x86_64-sse-pclmulqdq` (baseline)
+/// - **x86_64**: `x86_64-avx512-vpclmulqdq` (highest) → `x86_64-avx2-vpclmulqdq` → `x86_64-avx512-pclmulqdq` (mid) → `x86_64-sse-pclmulqdq` (baseline)
 /// - **x86**: `x86-sse-pclmulqdq` (baseline) → `software-fallback-tables` (fallback)
+/// - **riscv64**: `riscv64-rvv-zvbc` (highest) → `riscv64-zbc-clmul` (baseline) → `software-fallback-tables` (fallback)
 /// - **Other architectures**: `software-fallback-tables`
 ///
 /// The tier selection is deterministic and consistent across runs on the same hardware,
@@ -1126,7 +1577,12 @@ pub fn checksum_combine_with_params(
 /// ```
 #[cfg(all(
     feature = "alloc",
-    any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    )
 ))]
 pub fn get_calculator_target(_algorithm: CrcAlgorithm) -> String {
     let arch_ops = get_arch_ops();
@@ -1148,6 +1604,7 @@ pub fn get_calculator_target(_algorithm: CrcAlgorithm) -> String {
 /// let checksum = crc32_iscsi(b"123456789");
 /// assert_eq!(checksum, 0xe3069283);
 /// ```
+#[cfg(feature = "crc32")]
 #[inline(always)]
 pub fn crc32_iscsi(data: &[u8]) -> u32 {
     crc32_iscsi_calculator(CRC32_ISCSI.init, data, &CRC32_ISCSI) as u32 ^ CRC32_ISCSI.xorout as u32
@@ -1168,6 +1625,7 @@ pub fn crc32_iscsi(data: &[u8]) -> u32 {
 /// let checksum = crc32_iso_hdlc(b"123456789");
 /// assert_eq!(checksum, 0xcbf43926);
 /// ```
+#[cfg(feature = "crc32")]
 #[inline(always)]
 pub fn crc32_iso_hdlc(data: &[u8]) -> u32 {
     crc32_iso_hdlc_calculator(CRC32_ISO_HDLC.init, data, &CRC32_ISO_HDLC) as u32
@@ -1188,15 +1646,21 @@ pub fn crc32_iso_hdlc(data: &[u8]) -> u32 {
 /// let checksum = crc64_nvme(b"123456789");
 /// assert_eq!(checksum, 0xae8b14860a799888);
 /// ```
+#[cfg(feature = "crc64")]
 #[inline(always)]
 pub fn crc64_nvme(data: &[u8]) -> u64 {
-    Calculator::calculate(CRC64_NVME.init, data, &CRC64_NVME) ^ CRC64_NVME.xorout
+    ArchCalculator::calculate(CRC64_NVME.init, data, &CRC64_NVME) ^ CRC64_NVME.xorout
 }
 
 /// Fallback version of get_calculator_target for unsupported architectures
 #[cfg(all(
     feature = "alloc",
-    not(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))
+    not(any(
+        target_arch = "aarch64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv64"
+    ))
 ))]
 pub fn get_calculator_target(_algorithm: CrcAlgorithm) -> String {
     extern crate alloc;
@@ -1204,87 +1668,254 @@ pub fn get_calculator_target(_algorithm: CrcAlgorithm) -> String {
     "software-fallback-tables".to_string()
 }
 
+/// Returns the backends (performance tiers) this CPU supports, in order of preference.
+///
+/// The last entry is always [PerformanceTier::SoftwareTable], which is available everywhere.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{available_backends, PerformanceTier};
+///
+/// for backend in available_backends() {
+///     println!("{backend}");
+/// }
+///
+/// assert_eq!(available_backends().last(), Some(PerformanceTier::SoftwareTable));
+/// ```
+pub fn available_backends() -> impl Iterator<Item = PerformanceTier> {
+    PerformanceTier::available()
+}
+
+/// Returns the backend currently used by [checksum], [Digest::new] and the other dispatching
+/// APIs.
+///
+/// This is the detected tier unless one was forced with [set_backend_override] or the
+/// [FORCE_TIER_ENV] environment variable.
+pub fn active_backend() -> PerformanceTier {
+    feature_detection::get_active_tier()
+}
+
+/// Forces every dispatching API to use the given backend, process-wide, or restores the detected
+/// backend with `None`.
+///
+/// Useful for A/B testing tiers, reproducing results from other hardware, or keeping to the
+/// software path in contexts like signal handlers. Forcing a backend also disables the CRC-32
+/// fusion paths, which otherwise pick their own instructions. [Calculator]s created earlier keep
+/// the routine they resolved.
+///
+/// The override can also be set at startup, in `std` builds, by naming a tier in the
+/// [FORCE_TIER_ENV] (`CRC_FAST_FORCE_TIER`) environment variable.
+///
+/// Returns an error, leaving the active backend unchanged, if this CPU doesn't support the backend.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{active_backend, set_backend_override, PerformanceTier};
+///
+/// set_backend_override(Some(PerformanceTier::SoftwareTable))?;
+/// assert_eq!(active_backend(), PerformanceTier::SoftwareTable);
+///
+/// set_backend_override(None)?;
+/// # Ok::<(), crc_fast::BackendUnavailable>(())
+/// ```
+pub fn set_backend_override(backend: Option<PerformanceTier>) -> Result<(), BackendUnavailable> {
+    feature_detection::set_tier_override(backend)
+}
+
+/// Computes the CRC checksum for the specified algorithm using the given backend, regardless of
+/// the detected or overridden performance tier.
+///
+/// Returns an error if this CPU doesn't support the backend.
+///
+/// # Panics
+///
+/// Panics for the custom algorithms, which require parameters via [checksum_with_params].
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum_with_backend, PerformanceTier, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// let checksum = checksum_with_backend(Crc32IsoHdlc, PerformanceTier::SoftwareTable, b"123456789");
+///
+/// assert_eq!(checksum, Ok(0xcbf43926));
+/// ```
+pub fn checksum_with_backend(
+    algorithm: CrcAlgorithm,
+    backend: PerformanceTier,
+    buf: &[u8],
+) -> Result<u64, BackendUnavailable> {
+    Ok(Calculator::new_with_backend(algorithm, backend)?.checksum(buf))
+}
+
 /// Returns the calculator function and parameters for the specified CRC algorithm.
 #[inline(always)]
 #[allow(deprecated)]
 fn get_calculator_params(algorithm: CrcAlgorithm) -> (CalculatorFn, CrcParams) {
     match algorithm {
-        CrcAlgorithm::Crc16Arc => (Calculator::calculate as CalculatorFn, CRC16_ARC),
-        CrcAlgorithm::Crc16Cdma2000 => (Calculator::calculate as CalculatorFn, CRC16_CDMA2000),
-        CrcAlgorithm::Crc16Cms => (Calculator::calculate as CalculatorFn, CRC16_CMS),
-        CrcAlgorithm::Crc16Dds110 => (Calculator::calculate as CalculatorFn, CRC16_DDS_110),
-        CrcAlgorithm::Crc16DectR => (Calculator::calculate as CalculatorFn, CRC16_DECT_R),
-        CrcAlgorithm::Crc16DectX => (Calculator::calculate as CalculatorFn, CRC16_DECT_X),
-        CrcAlgorithm::Crc16Dnp => (Calculator::calculate as CalculatorFn, CRC16_DNP),
-        CrcAlgorithm::Crc16En13757 => (Calculator::calculate as CalculatorFn, CRC16_EN_13757),
-        CrcAlgorithm::Crc16Genibus => (Calculator::calculate as CalculatorFn, CRC16_GENIBUS),
-        CrcAlgorithm::Crc16Gsm => (Calculator::calculate as CalculatorFn, CRC16_GSM),
-        CrcAlgorithm::Crc16Ibm3740 => (Calculator::calculate as CalculatorFn, CRC16_IBM_3740),
-        CrcAlgorithm::Crc16IbmSdlc => (Calculator::calculate as CalculatorFn, CRC16_IBM_SDLC),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Arc => (ArchCalculator::calculate as CalculatorFn, CRC16_ARC),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Cdma2000 => (ArchCalculator::calculate as CalculatorFn, CRC16_CDMA2000),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Cms => (ArchCalculator::calculate as CalculatorFn, CRC16_CMS),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Dds110 => (ArchCalculator::calculate as CalculatorFn, CRC16_DDS_110),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16DectR => (ArchCalculator::calculate as CalculatorFn, CRC16_DECT_R),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16DectX => (ArchCalculator::calculate as CalculatorFn, CRC16_DECT_X),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Dnp => (ArchCalculator::calculate as CalculatorFn, CRC16_DNP),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16En13757 => (ArchCalculator::calculate as CalculatorFn, CRC16_EN_13757),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Genibus => (ArchCalculator::calculate as CalculatorFn, CRC16_GENIBUS),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Gsm => (ArchCalculator::calculate as CalculatorFn, CRC16_GSM),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Ibm3740 => (ArchCalculator::calculate as CalculatorFn, CRC16_IBM_3740),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16IbmSdlc => (ArchCalculator::calculate as CalculatorFn, CRC16_IBM_SDLC),
+        #[cfg(feature = "crc16")]
         CrcAlgorithm::Crc16IsoIec144433A => (
-            Calculator::calculate as CalculatorFn,
+            ArchCalculator::calculate as CalculatorFn,
             CRC16_ISO_IEC_14443_3_A,
         ),
-        CrcAlgorithm::Crc16Kermit => (Calculator::calculate as CalculatorFn, CRC16_KERMIT),
-        CrcAlgorithm::Crc16Lj1200 => (Calculator::calculate as CalculatorFn, CRC16_LJ1200),
-        CrcAlgorithm::Crc16M17 => (Calculator::calculate as CalculatorFn, CRC16_M17),
-        CrcAlgorithm::Crc16MaximDow => (Calculator::calculate as CalculatorFn, CRC16_MAXIM_DOW),
-        CrcAlgorithm::Crc16Mcrf4xx => (Calculator::calculate as CalculatorFn, CRC16_MCRF4XX),
-        CrcAlgorithm::Crc16Modbus => (Calculator::calculate as CalculatorFn, CRC16_MODBUS),
-        CrcAlgorithm::Crc16Nrsc5 => (Calculator::calculate as CalculatorFn, CRC16_NRSC_5),
-        CrcAlgorithm::Crc16OpensafetyA => {
-            (Calculator::calculate as CalculatorFn, CRC16_OPENSAFETY_A)
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Kermit => (ArchCalculator::calculate as CalculatorFn, CRC16_KERMIT),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Lj1200 => (ArchCalculator::calculate as CalculatorFn, CRC16_LJ1200),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16M17 => (ArchCalculator::calculate as CalculatorFn, CRC16_M17),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16MaximDow => (ArchCalculator::calculate as CalculatorFn, CRC16_MAXIM_DOW),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Mcrf4xx => (ArchCalculator::calculate as CalculatorFn, CRC16_MCRF4XX),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Modbus => (ArchCalculator::calculate as CalculatorFn, CRC16_MODBUS),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Nrsc5 => (ArchCalculator::calculate as CalculatorFn, CRC16_NRSC_5),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16OpensafetyA => (
+            ArchCalculator::calculate as CalculatorFn,
+            CRC16_OPENSAFETY_A,
+        ),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16OpensafetyB => (
+            ArchCalculator::calculate as CalculatorFn,
+            CRC16_OPENSAFETY_B,
+        ),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Profibus => (ArchCalculator::calculate as CalculatorFn, CRC16_PROFIBUS),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Riello => (ArchCalculator::calculate as CalculatorFn, CRC16_RIELLO),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16SpiFujitsu => {
+            (ArchCalculator::calculate as CalculatorFn, CRC16_SPI_FUJITSU)
+        }
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16T10Dif => (ArchCalculator::calculate as CalculatorFn, CRC16_T10_DIF),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Teledisk => (ArchCalculator::calculate as CalculatorFn, CRC16_TELEDISK),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Tms37157 => (ArchCalculator::calculate as CalculatorFn, CRC16_TMS37157),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Umts => (ArchCalculator::calculate as CalculatorFn, CRC16_UMTS),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Usb => (ArchCalculator::calculate as CalculatorFn, CRC16_USB),
+        #[cfg(feature = "crc16")]
+        CrcAlgorithm::Crc16Xmodem => (ArchCalculator::calculate as CalculatorFn, CRC16_XMODEM),
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Aixm => (ArchCalculator::calculate as CalculatorFn, CRC32_AIXM),
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Autosar => (ArchCalculator::calculate as CalculatorFn, CRC32_AUTOSAR),
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Base91D => (ArchCalculator::calculate as CalculatorFn, CRC32_BASE91_D),
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Bzip2 => (ArchCalculator::calculate as CalculatorFn, CRC32_BZIP2),
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32CdRomEdc => {
+            (ArchCalculator::calculate as CalculatorFn, CRC32_CD_ROM_EDC)
         }
-        CrcAlgorithm::Crc16OpensafetyB => {
-            (Calculator::calculate as CalculatorFn, CRC16_OPENSAFETY_B)
-        }
-        CrcAlgorithm::Crc16Profibus => (Calculator::calculate as CalculatorFn, CRC16_PROFIBUS),
-        CrcAlgorithm::Crc16Riello => (Calculator::calculate as CalculatorFn, CRC16_RIELLO),
-        CrcAlgorithm::Crc16SpiFujitsu => (Calculator::calculate as CalculatorFn, CRC16_SPI_FUJITSU),
-        CrcAlgorithm::Crc16T10Dif => (Calculator::calculate as CalculatorFn, CRC16_T10_DIF),
-        CrcAlgorithm::Crc16Teledisk => (Calculator::calculate as CalculatorFn, CRC16_TELEDISK),
-        CrcAlgorithm::Crc16Tms37157 => (Calculator::calculate as CalculatorFn, CRC16_TMS37157),
-        CrcAlgorithm::Crc16Umts => (Calculator::calculate as CalculatorFn, CRC16_UMTS),
-        CrcAlgorithm::Crc16Usb => (Calculator::calculate as CalculatorFn, CRC16_USB),
-        CrcAlgorithm::Crc16Xmodem => (Calculator::calculate as CalculatorFn, CRC16_XMODEM),
-        CrcAlgorithm::Crc32Aixm => (Calculator::calculate as CalculatorFn, CRC32_AIXM),
-        CrcAlgorithm::Crc32Autosar => (Calculator::calculate as CalculatorFn, CRC32_AUTOSAR),
-        CrcAlgorithm::Crc32Base91D => (Calculator::calculate as CalculatorFn, CRC32_BASE91_D),
-        CrcAlgorithm::Crc32Bzip2 => (Calculator::calculate as CalculatorFn, CRC32_BZIP2),
-        CrcAlgorithm::Crc32CdRomEdc => (Calculator::calculate as CalculatorFn, CRC32_CD_ROM_EDC),
-        CrcAlgorithm::Crc32Cksum => (Calculator::calculate as CalculatorFn, CRC32_CKSUM),
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Cksum => (ArchCalculator::calculate as CalculatorFn, CRC32_CKSUM),
         CrcAlgorithm::Crc32Custom => {
             panic!("Custom CRC-32 requires parameters via CrcParams::new()")
         }
+        #[cfg(feature = "crc32")]
         CrcAlgorithm::Crc32Iscsi => (crc32_iscsi_calculator as CalculatorFn, CRC32_ISCSI),
+        #[cfg(feature = "crc32")]
         CrcAlgorithm::Crc32IsoHdlc => (crc32_iso_hdlc_calculator as CalculatorFn, CRC32_ISO_HDLC),
-        CrcAlgorithm::Crc32Jamcrc => (Calculator::calculate as CalculatorFn, CRC32_JAMCRC),
-        CrcAlgorithm::Crc32Mef => (Calculator::calculate as CalculatorFn, CRC32_MEF),
-        CrcAlgorithm::Crc32Mpeg2 => (Calculator::calculate as CalculatorFn, CRC32_MPEG_2),
-        CrcAlgorithm::Crc32Xfer => (Calculator::calculate as CalculatorFn, CRC32_XFER),
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Jamcrc => (ArchCalculator::calculate as CalculatorFn, CRC32_JAMCRC),
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Mef => (ArchCalculator::calculate as CalculatorFn, CRC32_MEF),
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Mpeg2 => (ArchCalculator::calculate as CalculatorFn, CRC32_MPEG_2),
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Xfer => (ArchCalculator::calculate as CalculatorFn, CRC32_XFER),
         CrcAlgorithm::CrcCustom => {
             panic!("Custom CRC requires parameters via CrcParams::new()")
         }
         CrcAlgorithm::Crc64Custom => {
             panic!("Custom CRC-64 requires parameters via CrcParams::new()")
         }
-        CrcAlgorithm::Crc64Ecma182 => (Calculator::calculate as CalculatorFn, CRC64_ECMA_182),
-        CrcAlgorithm::Crc64GoIso => (Calculator::calculate as CalculatorFn, CRC64_GO_ISO),
-        CrcAlgorithm::Crc64Ms => (Calculator::calculate as CalculatorFn, CRC64_MS),
-        CrcAlgorithm::Crc64Nvme => (Calculator::calculate as CalculatorFn, CRC64_NVME),
-        CrcAlgorithm::Crc64Redis => (Calculator::calculate as CalculatorFn, CRC64_REDIS),
-        CrcAlgorithm::Crc64We => (Calculator::calculate as CalculatorFn, CRC64_WE),
-        CrcAlgorithm::Crc64Xz => (Calculator::calculate as CalculatorFn, CRC64_XZ),
+        #[cfg(feature = "crc64")]
+        CrcAlgorithm::Crc64Ecma182 => (ArchCalculator::calculate as CalculatorFn, CRC64_ECMA_182),
+        #[cfg(feature = "crc64")]
+        CrcAlgorithm::Crc64GoIso => (ArchCalculator::calculate as CalculatorFn, CRC64_GO_ISO),
+        #[cfg(feature = "crc64")]
+        CrcAlgorithm::Crc64Ms => (ArchCalculator::calculate as CalculatorFn, CRC64_MS),
+        #[cfg(feature = "crc64")]
+        CrcAlgorithm::Crc64Nvme => (ArchCalculator::calculate as CalculatorFn, CRC64_NVME),
+        #[cfg(feature = "crc64")]
+        CrcAlgorithm::Crc64Redis => (ArchCalculator::calculate as CalculatorFn, CRC64_REDIS),
+        #[cfg(feature = "crc64")]
+        CrcAlgorithm::Crc64We => (ArchCalculator::calculate as CalculatorFn, CRC64_WE),
+        #[cfg(feature = "crc64")]
+        CrcAlgorithm::Crc64Xz => (ArchCalculator::calculate as CalculatorFn, CRC64_XZ),
+        #[cfg(not(all(feature = "crc16", feature = "crc32", feature = "crc64")))]
+        _ => unsupported_algorithm(algorithm),
     }
 }
 
+/// Panics for a predefined algorithm whose family's feature isn't enabled in this build.
+#[cfg(not(all(feature = "crc16", feature = "crc32", feature = "crc64")))]
+#[cold]
+fn unsupported_algorithm(algorithm: CrcAlgorithm) -> ! {
+    panic!(
+        "{algorithm} requires the {} feature",
+        algorithm.family_feature().unwrap_or_default()
+    )
+}
+
 /// Calculates the CRC-32/ISCSI (commonly called "crc32c" in many, but not all, implementations)
 /// checksum.
 ///
 /// Because both aarch64 and x86 have native hardware support for CRC-32/ISCSI, we can use
 /// fusion techniques to accelerate the calculation beyond what SIMD can do alone.
+#[cfg(feature = "crc32")]
 #[inline(always)]
-fn crc32_iscsi_calculator(state: u64, data: &[u8], _params: &CrcParams) -> u64 {
+fn crc32_iscsi_calculator(state: u64, data: &[u8], params: &CrcParams) -> u64 {
+    match crc32_iscsi_fusion() {
+        Some(fusion) => fusion(state, data, params),
+        None => ArchCalculator::calculate(state, data, params),
+    }
+}
+
+/// Returns the CRC-32/ISCSI fusion routine if the detected tier supports it.
+#[cfg(feature = "crc32")]
+#[inline(always)]
+fn crc32_iscsi_fusion() -> Option<CalculatorFn> {
+    // fusion selects its own instructions, so it'd bypass a forced tier
+    #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+    if feature_detection::is_tier_forced() {
+        return None;
+    }
+
     #[cfg(all(target_arch = "aarch64", feature = "std"))]
     {
         use crate::feature_detection::PerformanceTier;
@@ -1292,7 +1923,7 @@ fn crc32_iscsi_calculator(state: u64, data: &[u8], _params: &CrcParams) -> u64 {
         let arch_ops = get_arch_ops();
         match arch_ops.get_tier() {
             PerformanceTier::AArch64AesSha3 | PerformanceTier::AArch64Aes => {
-                return fusion::crc32_iscsi(state as u32, data) as u64;
+                return Some(|state, data, _params| fusion::crc32_iscsi(state as u32, data) as u64);
             }
             _ => {}
         }
@@ -1305,19 +1936,22 @@ fn crc32_iscsi_calculator(state: u64, data: &[u8], _params: &CrcParams) -> u64 {
         let arch_ops = get_arch_ops();
         match arch_ops.get_tier() {
             PerformanceTier::X86_64Avx512Vpclmulqdq
+            | PerformanceTier::X86_64Avx2Vpclmulqdq
             | PerformanceTier::X86_64Avx512Pclmulqdq
             | PerformanceTier::X86_64SsePclmulqdq
             | PerformanceTier::X86SsePclmulqdq => {
                 // fusion path requires both pclmulqdq (checked by tier) and sse4.2 (for CRC32 instructions)
                 if is_x86_feature_detected!("sse4.2") {
-                    return fusion::crc32_iscsi(state as u32, data) as u64;
+                    return Some(|state, data, _params| {
+                        fusion::crc32_iscsi(state as u32, data) as u64
+                    });
                 }
             }
             _ => {}
         }
     }
 
-    Calculator::calculate(state, data, _params)
+    None
 }
 
 /// Calculates the CRC-32/ISO-HDLC (commonly called "crc32" in many, but not all, implementations)
@@ -1326,8 +1960,24 @@ fn crc32_iscsi_calculator(state: u64, data: &[u8], _params: &CrcParams) -> u64 {
 /// Because aarch64 has native hardware support for CRC-32/ISO-HDLC, we can use fusion techniques
 /// to accelerate the calculation beyond what SIMD can do alone. x86 does not have native support,
 /// so we use the traditional calculation.
+#[cfg(feature = "crc32")]
+#[inline(always)]
+fn crc32_iso_hdlc_calculator(state: u64, data: &[u8], params: &CrcParams) -> u64 {
+    match crc32_iso_hdlc_fusion() {
+        Some(fusion) => fusion(state, data, params),
+        None => ArchCalculator::calculate(state, data, params),
+    }
+}
+
+/// Returns the CRC-32/ISO-HDLC fusion routine if the detected tier supports it.
+#[cfg(feature = "crc32")]
 #[inline(always)]
-fn crc32_iso_hdlc_calculator(state: u64, data: &[u8], _params: &CrcParams) -> u64 {
+fn crc32_iso_hdlc_fusion() -> Option<CalculatorFn> {
+    #[cfg(all(target_arch = "aarch64", feature = "std"))]
+    if feature_detection::is_tier_forced() {
+        return None;
+    }
+
     #[cfg(all(target_arch = "aarch64", feature = "std"))]
     {
         use crate::feature_detection::{get_arch_ops, PerformanceTier};
@@ -1335,13 +1985,27 @@ fn crc32_iso_hdlc_calculator(state: u64, data: &[u8], _params: &CrcParams) -> u6
 
         match arch_ops.get_tier() {
             PerformanceTier::AArch64AesSha3 | PerformanceTier::AArch64Aes => {
-                return fusion::crc32_iso_hdlc(state as u32, data) as u64;
+                return Some(|state, data, _params| {
+                    fusion::crc32_iso_hdlc(state as u32, data) as u64
+                });
             }
             _ => {}
         }
     }
 
-    Calculator::calculate(state, data, _params)
+    None
+}
+
+/// Returns the CRC-32 fusion routine the active tier uses for this algorithm, if any.
+#[inline(always)]
+fn get_fusion(algorithm: CrcAlgorithm) -> Option<CalculatorFn> {
+    match algorithm {
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32Iscsi => crc32_iscsi_fusion(),
+        #[cfg(feature = "crc32")]
+        CrcAlgorithm::Crc32IsoHdlc => crc32_iso_hdlc_fusion(),
+        _ => None,
+    }
 }
 
 #[cfg(test)]
@@ -1353,7 +2017,8 @@ mod lib {
     use crate::test::enums::AnyCrcTestConfig;
     use cbindgen::Language::C;
     use cbindgen::Style::Both;
-    use rand::{rng, Rng};
+    use rand::rngs::StdRng;
+    use rand::{rng, Rng, SeedableRng};
     use std::fs::{read, write};
 
     #[test]
@@ -1377,8 +2042,9 @@ mod lib {
     }
 
     #[test]
+    #[cfg(all(feature = "crc32", feature = "crc64"))]
     fn test_checksum_with_custom_params() {
-        crate::cache::clear_cache();
+        crate::cache::clear();
 
         // CRC-32 reflected
         assert_eq!(
@@ -1406,8 +2072,9 @@ mod lib {
     }
 
     #[test]
+    #[cfg(all(feature = "crc32", feature = "crc64"))]
     fn test_get_custom_params() {
-        crate::cache::clear_cache();
+        crate::cache::clear();
 
         assert_eq!(
             checksum_with_params(get_custom_crc32_reflected(), TEST_CHECK_STRING),
@@ -1488,6 +2155,72 @@ mod lib {
         );
     }
 
+    #[test]
+    fn test_checksum_with_backend() {
+        let data: Vec<u8> = (0..4099).map(|i| (i * 13 % 256) as u8).collect();
+
+        for backend in available_backends() {
+            for config in TEST_ALL_CONFIGS {
+                for len in [0, 9, 63, 64, 255, 1024, data.len()] {
+                    assert_eq!(
+                        checksum_with_backend(config.get_algorithm(), backend, &data[..len]),
+                        Ok(config.checksum_with_reference(&data[..len])),
+                        "{} mismatch on {backend} at {len} bytes",
+                        config.get_name()
+                    );
+                }
+
+                let mut digest = Digest::new_with_backend(config.get_algorithm(), backend).unwrap();
+                digest.update(TEST_CHECK_STRING);
+                assert_eq!(digest.finalize(), config.get_check());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "crc32")]
+    fn test_unavailable_backend() {
+        #[cfg(target_arch = "aarch64")]
+        let unavailable = PerformanceTier::X86_64SsePclmulqdq;
+        #[cfg(not(target_arch = "aarch64"))]
+        let unavailable = PerformanceTier::AArch64Aes;
+
+        assert!(!available_backends().any(|backend| backend == unavailable));
+        assert_eq!(
+            checksum_with_backend(CrcAlgorithm::Crc32IsoHdlc, unavailable, TEST_CHECK_STRING),
+            Err(BackendUnavailable(unavailable))
+        );
+        assert!(Digest::new_with_backend(CrcAlgorithm::Crc32IsoHdlc, unavailable).is_err());
+    }
+
+    #[test]
+    fn test_is_supported() {
+        assert!(CrcAlgorithm::CrcCustom.is_supported());
+        assert_eq!(
+            CrcAlgorithm::Crc16Arc.is_supported(),
+            cfg!(feature = "crc16")
+        );
+        assert_eq!(
+            CrcAlgorithm::Crc32Iscsi.is_supported(),
+            cfg!(feature = "crc32")
+        );
+        assert_eq!(
+            CrcAlgorithm::Crc64Nvme.is_supported(),
+            cfg!(feature = "crc64")
+        );
+
+        for algorithm in CrcAlgorithm::PREDEFINED {
+            assert!(algorithm.is_supported(), "{algorithm}");
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64"))]
+    #[should_panic(expected = "CRC-64/NVME requires the crc64 feature")]
+    fn test_unsupported_algorithm() {
+        checksum(CrcAlgorithm::Crc64Nvme, TEST_CHECK_STRING);
+    }
+
     #[test]
     fn test_digest_updates_check() {
         for config in TEST_ALL_CONFIGS {
@@ -1496,8 +2229,9 @@ mod lib {
     }
 
     #[test]
+    #[cfg(all(feature = "crc32", feature = "crc64"))]
     fn test_digest_updates_check_with_custom_params() {
-        crate::cache::clear_cache();
+        crate::cache::clear();
 
         // CRC-32 reflected
         check_digest(
@@ -1624,8 +2358,9 @@ mod lib {
     }
 
     #[test]
+    #[cfg(all(feature = "crc32", feature = "crc64"))]
     fn test_combine_with_custom_params() {
-        crate::cache::clear_cache();
+        crate::cache::clear();
 
         // CRC-32 reflected
         let crc32_params = get_custom_crc32_reflected();
@@ -1664,6 +2399,84 @@ mod lib {
         );
     }
 
+    #[test]
+    fn test_masked_range() {
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+            if algorithm == CrcAlgorithm::CrcCustom {
+                continue;
+            }
+
+            // the check string's CRC over data with it embedded between two masked fields
+            let mut data = [0xa5u8; 25];
+            data[8..17].copy_from_slice(TEST_CHECK_STRING);
+            let mut digest = Digest::new(algorithm);
+            digest.update(&[0; 8]);
+            digest.update(TEST_CHECK_STRING);
+            digest.update(&[0; 8]);
+
+            assert_eq!(
+                checksum_with_masked_ranges(algorithm, &data, &[0..8, 17..25]),
+                digest.finalize(),
+                "{}",
+                config.get_name()
+            );
+            assert_eq!(
+                checksum_with_masked_range(algorithm, TEST_CHECK_STRING, 4..4),
+                config.get_check(),
+                "{}",
+                config.get_name()
+            );
+        }
+    }
+
+    /// Skipping for Miri runs due to time constraints, underlying code already covered by other
+    /// tests.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_masked_ranges_match_zeroed_copy() {
+        let mut rng = StdRng::seed_from_u64(0x5eed_0001);
+        let data: Vec<u8> = (0..200_000).map(|_| rng.random()).collect();
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+
+            // short fields are fed through, and long runs use the zero-extension operator
+            let ranges = [
+                3..3,
+                10..14,
+                20..28,
+                1_000..66_536,
+                70_000..135_537,
+                150_000..200_000,
+            ];
+
+            let mut zeroed = data.clone();
+            for range in ranges.clone() {
+                zeroed[range].fill(0);
+            }
+
+            let mut digest = Digest::new_with_params(params);
+            digest.update_with_masked_ranges(&data, &ranges);
+
+            assert_eq!(
+                digest.finalize(),
+                checksum_with_params(params, &zeroed),
+                "{} {:?}",
+                config.get_name(),
+                ranges
+            );
+            assert_eq!(digest.get_amount(), data.len() as u64);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "after any earlier ranges")]
+    #[cfg(feature = "crc32")]
+    fn test_masked_ranges_overlapping() {
+        checksum_with_masked_ranges(CrcAlgorithm::Crc32Iscsi, &[0; 16], &[4..8, 6..10]);
+    }
+
     /// Skipping for Miri runs due to isolation constraints, underlying code other than I/O already
     /// covered by other tests.
     #[test]
@@ -1689,8 +2502,9 @@ mod lib {
     /// covered by other tests.
     #[test]
     #[cfg_attr(miri, ignore)]
+    #[cfg(all(feature = "crc32", feature = "crc64"))]
     fn test_checksum_file_with_custom_params() {
-        crate::cache::clear_cache();
+        crate::cache::clear();
 
         // Create a test file with repeating zeros
         let test_file_path = "test/test_crc32_hash_file_custom.bin";
@@ -1902,6 +2716,7 @@ mod lib {
         Ok(())
     }
 
+    #[cfg(feature = "crc32")]
     fn get_custom_crc32_reflected() -> CrcParams {
         CrcParams::new(
             "Custom CRC-32/ISCSI",
@@ -1914,6 +2729,7 @@ mod lib {
         )
     }
 
+    #[cfg(feature = "crc32")]
     fn get_custom_crc32_forward() -> CrcParams {
         CrcParams::new(
             "Custom CRC-32/BZIP2",
@@ -1926,6 +2742,7 @@ mod lib {
         )
     }
 
+    #[cfg(feature = "crc64")]
     fn get_custom_crc64_reflected() -> CrcParams {
         CrcParams::new(
             "Custom CRC-64/NVME",
@@ -1938,6 +2755,7 @@ mod lib {
         )
     }
 
+    #[cfg(feature = "crc64")]
     fn get_custom_crc64_forward() -> CrcParams {
         CrcParams::new(
             "Custom CRC-64/ECMA-182",
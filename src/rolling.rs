@@ -0,0 +1,445 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Rolling CRCs over a sliding window, for content-defined chunking.
+//!
+//! Feeding a byte into a CRC register is linear over GF(2), so the effect of the byte leaving the
+//! window is the same wherever the window is: its contribution shifted through `window` zero
+//! bytes, calculated once per byte value with the zero operator behind
+//! [checksum_combine](crate::checksum_combine). Each roll is then a table-driven step for the
+//! incoming byte and an XOR for the outgoing one, and the result always matches
+//! [checksum_with_params] over the bytes in the window.
+
+use crate::combine;
+use crate::{checksum_with_params, CrcParams};
+use core::fmt::{Debug, Formatter};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+
+/// The CRC of the last `window` bytes of a stream, updated a byte at a time.
+///
+/// Before `window` bytes have been rolled in, the window is padded with leading zeros.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum, Calculator, CrcAlgorithm::Crc32Iscsi, RollingCrc};
+///
+/// let data = b"the quick brown fox jumps over the lazy dog";
+/// let mut rolling = RollingCrc::new(*Calculator::new(Crc32Iscsi).params(), 8);
+///
+/// rolling.set_window(&data[..8]);
+/// for i in 8..data.len() {
+///     rolling.roll(data[i - 8], data[i]);
+/// }
+///
+/// assert_eq!(rolling.crc(), checksum(Crc32Iscsi, &data[data.len() - 8..]));
+/// ```
+#[derive(Clone)]
+pub struct RollingCrc {
+    params: CrcParams,
+    window: usize,
+    reflected: bool,
+    /// The shift bringing the top byte of a forward register down to the bottom
+    shift: u32,
+    mask: u64,
+    /// The register, which is the CRC before the final XOR
+    state: u64,
+    /// The register over a window of zeros
+    empty: u64,
+    /// The register change from feeding in each byte value
+    incoming: [u64; 256],
+    /// The register change from each byte value leaving the window
+    outgoing: [u64; 256],
+}
+
+impl RollingCrc {
+    /// Creates a rolling CRC over windows of `window` bytes, starting from a window of zeros.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero, or if `refin` and `refout` differ, which no catalogued CRC
+    /// does.
+    pub fn new(params: CrcParams, window: usize) -> Self {
+        assert!(window > 0, "window must be at least one byte");
+
+        let width = params.width as u32;
+
+        // the register change from each bit of a byte, then shifted through the window
+        let zero = checksum_with_params(params, &[0]);
+        let mut columns = [0u64; 8];
+        for (bit, column) in columns.iter_mut().enumerate() {
+            *column = checksum_with_params(params, &[1 << bit]) ^ zero;
+        }
+        let shifted = columns.map(|column| combine::zeros(column, window as u64, &params));
+
+        // the init value's contribution also shifts one byte further along as the window rolls
+        let empty = combine::zeros(params.init_algorithm, window as u64, &params);
+        let drift = combine::zeros(empty, 1, &params) ^ empty;
+
+        let mut outgoing = table(&shifted);
+        for entry in outgoing.iter_mut() {
+            *entry ^= drift;
+        }
+
+        Self {
+            params,
+            window,
+            reflected: params.refin,
+            shift: width - 8,
+            mask: u64::MAX >> (64 - width),
+            state: empty,
+            empty,
+            incoming: table(&columns),
+            outgoing,
+        }
+    }
+
+    /// Rolls `in_byte` into the window, and `out_byte`, which entered the window `window` bytes
+    /// earlier (or zero for the padding), out of it, returning the new CRC.
+    #[inline(always)]
+    pub fn roll(&mut self, out_byte: u8, in_byte: u8) -> u64 {
+        self.state = self.step(self.state, in_byte) ^ self.outgoing[out_byte as usize];
+
+        self.crc()
+    }
+
+    /// Rolls each byte of `incoming` into the window, and the matching byte of `outgoing` out of
+    /// it, returning the final CRC.
+    ///
+    /// For a contiguous buffer, rolling through `data[window..]` uses
+    /// `roll_slice(&data[..data.len() - window], &data[window..])`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices are different lengths.
+    pub fn roll_slice(&mut self, outgoing: &[u8], incoming: &[u8]) -> u64 {
+        assert_eq!(
+            outgoing.len(),
+            incoming.len(),
+            "outgoing and incoming bytes must pair up"
+        );
+
+        self.state = if self.reflected {
+            self.roll_bytes::<true>(outgoing, incoming)
+        } else {
+            self.roll_bytes::<false>(outgoing, incoming)
+        };
+
+        self.crc()
+    }
+
+    /// Replaces the window with `window`, using the accelerated checksum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` isn't [window_len](Self::window_len) bytes.
+    pub fn set_window(&mut self, window: &[u8]) {
+        assert_eq!(
+            window.len(),
+            self.window,
+            "window must be {} bytes",
+            self.window
+        );
+
+        self.state = checksum_with_params(self.params, window) ^ self.params.xorout;
+    }
+
+    /// Goes back to a window of zeros.
+    pub fn reset(&mut self) {
+        self.state = self.empty;
+    }
+
+    /// The CRC of the bytes in the window.
+    #[inline(always)]
+    pub fn crc(&self) -> u64 {
+        self.state ^ self.params.xorout
+    }
+
+    /// The window size, in bytes.
+    pub fn window_len(&self) -> usize {
+        self.window
+    }
+
+    /// Rolls each pair of bytes through the register, with the bit order fixed for the whole
+    /// slice, returning the new register.
+    #[inline(always)]
+    fn roll_bytes<const REFLECTED: bool>(&self, outgoing: &[u8], incoming: &[u8]) -> u64 {
+        let mut state = self.state;
+        for (&out_byte, &in_byte) in outgoing.iter().zip(incoming) {
+            state =
+                self.step_ordered::<REFLECTED>(state, in_byte) ^ self.outgoing[out_byte as usize];
+        }
+
+        state
+    }
+
+    /// Feeds one byte through the register.
+    #[inline(always)]
+    fn step(&self, state: u64, byte: u8) -> u64 {
+        if self.reflected {
+            self.step_ordered::<true>(state, byte)
+        } else {
+            self.step_ordered::<false>(state, byte)
+        }
+    }
+
+    /// Feeds one byte through the register in the given bit order.
+    #[inline(always)]
+    fn step_ordered<const REFLECTED: bool>(&self, state: u64, byte: u8) -> u64 {
+        if REFLECTED {
+            (state >> 8) ^ self.incoming[((state ^ byte as u64) & 0xff) as usize]
+        } else {
+            ((state << 8) & self.mask)
+                ^ self.incoming[(((state >> self.shift) ^ byte as u64) & 0xff) as usize]
+        }
+    }
+}
+
+impl Debug for RollingCrc {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RollingCrc")
+            .field("algorithm", &self.params.name)
+            .field("window", &self.window)
+            .field("crc", &self.crc())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Splits a stream into content-defined chunks, ending one wherever the [RollingCrc] of the last
+/// `window` bytes, masked with `mask`, equals `magic`.
+///
+/// Since boundaries depend only on the nearby content, inserting or removing bytes only changes
+/// the chunks around the edit, so the rest still deduplicate. The average chunk size is about
+/// `2^mask.count_ones()` bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{Calculator, Chunker, CrcAlgorithm::Crc32Iscsi};
+///
+/// let data: Vec<u8> = (0..65536u32).map(|i| (i.wrapping_mul(2654435761) >> 13) as u8).collect();
+/// let mut chunker = Chunker::new(*Calculator::new(Crc32Iscsi).params(), 48, 0x3ff, 0);
+///
+/// let mut chunks = Vec::new();
+/// let mut rest = &data[..];
+/// while let Some(len) = chunker.next_boundary(rest) {
+///     chunks.push(&rest[..len]);
+///     rest = &rest[len..];
+/// }
+/// chunks.push(rest);
+///
+/// assert_eq!(chunks.concat(), data);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct Chunker {
+    rolling: RollingCrc,
+    mask: u64,
+    magic: u64,
+    /// The last `window` bytes seen, as a ring starting at `oldest`
+    history: Vec<u8>,
+    oldest: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl Chunker {
+    /// Creates a chunker over windows of `window` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `magic` has bits outside `mask`, so it could never match, or as
+    /// [RollingCrc::new] does.
+    pub fn new(params: CrcParams, window: usize, mask: u64, magic: u64) -> Self {
+        assert_eq!(magic & !mask, 0, "magic must fit within mask");
+
+        Self {
+            rolling: RollingCrc::new(params, window),
+            mask,
+            magic,
+            history: vec![0; window],
+            oldest: 0,
+        }
+    }
+
+    /// Rolls through `data` up to and including the first byte ending a chunk, returning how many
+    /// bytes that was, or `None` after consuming all of `data` without ending one.
+    ///
+    /// The rest of `data` hasn't been consumed when a chunk ends, so pass it in again.
+    pub fn next_boundary(&mut self, data: &[u8]) -> Option<usize> {
+        let window = self.history.len();
+
+        // bytes leave from the history until the window is entirely within data
+        let from_history = data.len().min(window);
+        for (index, &in_byte) in data[..from_history].iter().enumerate() {
+            let out_byte = self.history[(self.oldest + index) % window];
+            if self.rolling.roll(out_byte, in_byte) & self.mask == self.magic {
+                return Some(self.consume(&data[..index + 1]));
+            }
+        }
+
+        for index in from_history..data.len() {
+            if self.rolling.roll(data[index - window], data[index]) & self.mask == self.magic {
+                return Some(self.consume(&data[..index + 1]));
+            }
+        }
+
+        self.consume(data);
+
+        None
+    }
+
+    /// Goes back to the start of a stream.
+    pub fn reset(&mut self) {
+        self.rolling.reset();
+        self.history.fill(0);
+        self.oldest = 0;
+    }
+
+    /// Keeps the last `window` bytes of those rolled in, returning how many there were.
+    fn consume(&mut self, consumed: &[u8]) -> usize {
+        let window = self.history.len();
+
+        if consumed.len() >= window {
+            self.history
+                .copy_from_slice(&consumed[consumed.len() - window..]);
+            self.oldest = 0;
+        } else {
+            for &byte in consumed {
+                self.history[self.oldest] = byte;
+                self.oldest = (self.oldest + 1) % window;
+            }
+        }
+
+        consumed.len()
+    }
+}
+
+/// Builds a table of each byte value's register change from those of its bits.
+fn table(columns: &[u64; 8]) -> [u64; 256] {
+    let mut table = [0u64; 256];
+    for value in 1..256 {
+        table[value] = table[value & (value - 1)] ^ columns[value.trailing_zeros() as usize];
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn random_data(len: usize) -> Vec<u8> {
+        let mut rng = StdRng::seed_from_u64(0x5eed_0001);
+
+        (0..len).map(|_| rng.random()).collect()
+    }
+
+    #[test]
+    fn test_roll_matches_checksum() {
+        let data = random_data(200);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+
+            for window in [1, 7, 16, 64] {
+                let mut rolling = RollingCrc::new(params, window);
+
+                // the leading zeros stand in for bytes before the start
+                let mut padded = vec![0u8; window];
+                padded.extend_from_slice(&data);
+
+                assert_eq!(
+                    rolling.crc(),
+                    checksum_with_params(params, &padded[..window])
+                );
+
+                for index in 0..data.len() {
+                    let crc = rolling.roll(padded[index], data[index]);
+
+                    assert_eq!(
+                        crc,
+                        checksum_with_params(params, &padded[index + 1..index + 1 + window]),
+                        "{} window {} at {}",
+                        config.get_name(),
+                        window,
+                        index
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_roll_slice() {
+        let data = random_data(1000);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+            let window = 48;
+            let mut rolling = RollingCrc::new(params, window);
+
+            rolling.set_window(&data[..window]);
+            let crc = rolling.roll_slice(&data[..data.len() - window], &data[window..]);
+
+            assert_eq!(
+                crc,
+                checksum_with_params(params, &data[data.len() - window..]),
+                "{}",
+                config.get_name()
+            );
+
+            rolling.reset();
+            assert_eq!(rolling.crc(), checksum_with_params(params, &[0; 48]));
+        }
+    }
+
+    /// Skipping for Miri runs due to time constraints, rolling is already covered by other tests.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_chunker_boundaries() {
+        let data = random_data(100_000);
+        let window = 32;
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+            let mut chunker = Chunker::new(params, window, 0xff, 0x5a);
+
+            let mut boundaries = Vec::new();
+            let mut offset = 0;
+            while let Some(len) = chunker.next_boundary(&data[offset..]) {
+                offset += len;
+                boundaries.push(offset);
+            }
+
+            assert!(!boundaries.is_empty(), "{}", config.get_name());
+            for &boundary in boundaries.iter().filter(|&&boundary| boundary >= window) {
+                let crc = checksum_with_params(params, &data[boundary - window..boundary]);
+                assert_eq!(crc & 0xff, 0x5a, "{}", config.get_name());
+            }
+
+            // feeding the same stream in arbitrary pieces finds the same boundaries
+            let mut rng = StdRng::seed_from_u64(0x5eed_0002);
+            chunker.reset();
+            let mut pieces = Vec::new();
+            let mut consumed = 0;
+            let mut start = 0;
+            while start < data.len() {
+                let end = (start + rng.random_range(1..100)).min(data.len());
+                let mut piece = &data[start..end];
+                while let Some(len) = chunker.next_boundary(piece) {
+                    consumed += len;
+                    pieces.push(consumed);
+                    piece = &piece[len..];
+                }
+                consumed += piece.len();
+                start = end;
+            }
+
+            assert_eq!(pieces, boundaries, "{}", config.get_name());
+        }
+    }
+}
@@ -0,0 +1,319 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides a runtime self-test of every implementation available on this CPU.
+//!
+//! Each supported algorithm's `check` value and a set of published known-answer vectors are
+//! computed with every available [PerformanceTier], the CRC-32 fusion paths, and the default
+//! dispatch used by [checksum]. Longer inputs, which exercise the SIMD folding, are then
+//! cross-checked against the software tables.
+
+use crate::{available_backends, checksum, get_fusion, Calculator, CrcAlgorithm, PerformanceTier};
+use core::fmt::{Display, Formatter};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// The standard input used for every algorithm's `check` value.
+const CHECK_INPUT: &[u8] = b"123456789";
+
+/// Lengths cross-checked against the software tables, covering the small-input path, each SIMD
+/// folding width and the remainder handling around them.
+const CROSS_CHECK_LENGTHS: [usize; 14] =
+    [0, 1, 15, 16, 31, 63, 64, 65, 127, 128, 255, 256, 1023, 4099];
+
+/// Published known-answer vectors, in addition to each algorithm's `check` value.
+const KNOWN_ANSWERS: &[(CrcAlgorithm, KnownInput, u64)] = &[
+    // RFC 3720, section B.4
+    #[cfg(feature = "crc32")]
+    (CrcAlgorithm::Crc32Iscsi, KnownInput::Fill(0x00), 0x8a9136aa),
+    #[cfg(feature = "crc32")]
+    (CrcAlgorithm::Crc32Iscsi, KnownInput::Fill(0xff), 0x62a8ab43),
+    #[cfg(feature = "crc32")]
+    (CrcAlgorithm::Crc32Iscsi, KnownInput::Ascending, 0x46dd794e),
+    #[cfg(feature = "crc32")]
+    (CrcAlgorithm::Crc32Iscsi, KnownInput::Descending, 0x113fdb5c),
+    #[cfg(feature = "crc32")]
+    (
+        CrcAlgorithm::Crc32IsoHdlc,
+        KnownInput::Text(b"The quick brown fox jumps over the lazy dog"),
+        0x414fa339,
+    ),
+];
+
+/// Inputs for the known-answer vectors.
+#[derive(Clone, Copy)]
+#[cfg_attr(not(feature = "crc32"), allow(dead_code))]
+enum KnownInput {
+    /// 32 bytes of the same value
+    Fill(u8),
+    /// The 32 bytes 0x00 to 0x1f
+    Ascending,
+    /// The 32 bytes 0x1f to 0x00
+    Descending,
+    Text(&'static [u8]),
+}
+
+impl KnownInput {
+    fn to_bytes(self) -> Vec<u8> {
+        match self {
+            KnownInput::Fill(byte) => [byte; 32].to_vec(),
+            KnownInput::Ascending => (0..32).collect(),
+            KnownInput::Descending => (0..32).rev().collect(),
+            KnownInput::Text(text) => text.to_vec(),
+        }
+    }
+}
+
+/// The implementation a self-test result was computed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelfTestPath {
+    /// A specific backend, via [Calculator::new_with_backend]
+    Backend(PerformanceTier),
+    /// The CRC-32/ISCSI or CRC-32/ISO-HDLC fusion path, if the active tier uses one
+    Fusion,
+    /// The default dispatch used by [checksum], [Digest::new](crate::Digest::new) and friends
+    Dispatch,
+}
+
+impl Display for SelfTestPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SelfTestPath::Backend(tier) => write!(f, "{tier}"),
+            SelfTestPath::Fusion => write!(f, "crc32-fusion"),
+            SelfTestPath::Dispatch => write!(f, "dispatch"),
+        }
+    }
+}
+
+/// A self-test result which didn't match the expected checksum.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SelfTestFailure {
+    pub algorithm: CrcAlgorithm,
+    pub path: SelfTestPath,
+    /// Length of the input, in bytes
+    pub len: usize,
+    pub expected: u64,
+    pub actual: u64,
+}
+
+impl Display for SelfTestFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} via {} for {} bytes: expected {:#x}, got {:#x}",
+            self.algorithm, self.path, self.len, self.expected, self.actual
+        )
+    }
+}
+
+/// The results of [self_test].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelfTestReport {
+    /// Backends which were tested, in order of preference
+    pub backends: Vec<PerformanceTier>,
+    /// Whether a CRC-32 fusion path was tested
+    pub fusion: bool,
+    /// Number of checksums which were computed and compared
+    pub checks: usize,
+    /// Every result which didn't match
+    pub failures: Vec<SelfTestFailure>,
+}
+
+impl SelfTestReport {
+    /// Returns whether every result matched.
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl Display for SelfTestReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} with {} checks across",
+            if self.passed() { "passed" } else { "FAILED" },
+            self.checks
+        )?;
+
+        for tier in &self.backends {
+            write!(f, " {tier}")?;
+        }
+
+        if self.fusion {
+            write!(f, " crc32-fusion")?;
+        }
+
+        for failure in &self.failures {
+            write!(f, "\n  {failure}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Runs a self-test of every CRC implementation available on this CPU.
+///
+/// Each predefined algorithm's `check` value and a set of published known-answer vectors are
+/// computed with every backend in [available_backends], the CRC-32 fusion paths, and the default
+/// dispatch. Inputs of various lengths, up to 4 KiB, are then cross-checked against the software
+/// tables, which the `check` values and known answers verify first.
+///
+/// Suitable for power-on self-tests. A forced backend (see
+/// [set_backend_override](crate::set_backend_override)) disables the fusion paths, so they're
+/// only tested when the detected tier uses them.
+///
+/// # Examples
+///
+/// ```rust
+/// let report = crc_fast::self_test();
+///
+/// assert!(report.passed(), "{report}");
+/// ```
+pub fn self_test() -> SelfTestReport {
+    let backends: Vec<PerformanceTier> = available_backends().collect();
+    let data: Vec<u8> = (0..4099u32)
+        .map(|i| (i.wrapping_mul(31) ^ (i >> 3)) as u8)
+        .collect();
+
+    let mut report = SelfTestReport {
+        backends: backends.clone(),
+        fusion: false,
+        checks: 0,
+        failures: Vec::new(),
+    };
+
+    for &algorithm in CrcAlgorithm::PREDEFINED {
+        let software = Calculator::new_with_backend(algorithm, PerformanceTier::SoftwareTable)
+            .expect("the software tables are always available");
+        let params = *software.params();
+
+        // (input, expected) pairs, where only the check value and known answers are independent
+        // of this library
+        let mut vectors: Vec<(Vec<u8>, u64)> = Vec::new();
+        vectors.push((CHECK_INPUT.to_vec(), params.check));
+
+        for (_, input, expected) in KNOWN_ANSWERS.iter().filter(|kat| kat.0 == algorithm) {
+            vectors.push((input.to_bytes(), *expected));
+        }
+
+        for len in CROSS_CHECK_LENGTHS {
+            vectors.push((data[..len].to_vec(), software.checksum(&data[..len])));
+        }
+
+        // an unaligned start
+        vectors.push((data[1..].to_vec(), software.checksum(&data[1..])));
+
+        for &backend in &backends {
+            if let Ok(calculator) = Calculator::new_with_backend(algorithm, backend) {
+                verify(
+                    &mut report,
+                    algorithm,
+                    SelfTestPath::Backend(backend),
+                    &vectors,
+                    |d| calculator.checksum(d),
+                );
+            }
+        }
+
+        if let Some(fusion) = get_fusion(algorithm) {
+            report.fusion = true;
+
+            verify(
+                &mut report,
+                algorithm,
+                SelfTestPath::Fusion,
+                &vectors,
+                |d| fusion(params.init_algorithm, d, &params) ^ params.xorout,
+            );
+        }
+
+        verify(
+            &mut report,
+            algorithm,
+            SelfTestPath::Dispatch,
+            &vectors,
+            |d| checksum(algorithm, d),
+        );
+    }
+
+    report
+}
+
+/// Computes every vector with `calculate`, recording any mismatches.
+fn verify(
+    report: &mut SelfTestReport,
+    algorithm: CrcAlgorithm,
+    path: SelfTestPath,
+    vectors: &[(Vec<u8>, u64)],
+    calculate: impl Fn(&[u8]) -> u64,
+) {
+    for (input, expected) in vectors {
+        let actual = calculate(input);
+        report.checks += 1;
+
+        if actual != *expected {
+            report.failures.push(SelfTestFailure {
+                algorithm,
+                path,
+                len: input.len(),
+                expected: *expected,
+                actual,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+
+    #[test]
+    #[cfg(any(feature = "crc16", feature = "crc32", feature = "crc64"))]
+    fn test_self_test_passes() {
+        let report = self_test();
+
+        assert!(report.passed(), "{report}");
+        assert_eq!(report.backends, available_backends().collect::<Vec<_>>());
+        assert!(report.checks > CrcAlgorithm::PREDEFINED.len() * CROSS_CHECK_LENGTHS.len());
+    }
+
+    #[test]
+    fn test_every_algorithm_is_tested() {
+        for config in TEST_ALL_CONFIGS {
+            assert!(
+                CrcAlgorithm::PREDEFINED.contains(&config.get_algorithm()),
+                "{} is missing",
+                config.get_name()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "crc32")]
+    fn test_failures_are_reported() {
+        let mut report = SelfTestReport {
+            backends: Vec::new(),
+            fusion: false,
+            checks: 0,
+            failures: Vec::new(),
+        };
+
+        let vectors = [(CHECK_INPUT.to_vec(), 0xcbf43926)];
+
+        verify(
+            &mut report,
+            CrcAlgorithm::Crc32IsoHdlc,
+            SelfTestPath::Dispatch,
+            &vectors,
+            |_| 0,
+        );
+
+        assert!(!report.passed());
+        assert_eq!(report.checks, 1);
+        assert_eq!(
+            report.failures[0].to_string(),
+            "CRC-32/ISO-HDLC via dispatch for 9 bytes: expected 0xcbf43926, got 0x0"
+        );
+    }
+}
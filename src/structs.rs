@@ -3,7 +3,7 @@
 #![allow(dead_code)]
 
 use crate::traits::{CrcCalculator, CrcWidth};
-use crate::{arch, cache, CrcAlgorithm, CrcParams};
+use crate::{arch, cache, generate, CrcAlgorithm, CrcParams};
 
 /// CRC algorithm parameters matching the CRC catalogue specification.
 ///
@@ -70,9 +70,9 @@ pub struct CrcState<T> {
     pub reflected: bool,
 }
 
-pub(crate) struct Calculator {}
+pub(crate) struct ArchCalculator {}
 
-impl CrcCalculator for Calculator {
+impl CrcCalculator for ArchCalculator {
     #[inline(always)]
     fn calculate(state: u64, data: &[u8], params: &CrcParams) -> u64 {
         unsafe { arch::update(state, data, params) }
@@ -98,12 +98,54 @@ impl CrcParams {
         xorout: u64,
         check: u64,
     ) -> Self {
-        let keys_array = cache::get_or_generate_keys(width, poly, reflected);
-        let keys = crate::CrcKeysStorage::from_keys_fold_256(keys_array);
+        let keys = cache::get_or_generate_keys(width, poly, reflected);
 
+        Self::with_keys(name, width, poly, init, reflected, xorout, check, keys)
+    }
+
+    /// Creates custom CRC parameters in a `const` context, such as for a `static` or `const`.
+    ///
+    /// Same as [new](CrcParams::new), except the folding keys are generated at compile time
+    /// (or on every call, at runtime) instead of going through the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{checksum_with_params, CrcParams};
+    ///
+    /// // CRC-32/ISO-HDLC, as custom parameters
+    /// const PARAMS: CrcParams = CrcParams::const_new("CRC-32/CUSTOM", 32, 0x04c11db7, 0xffffffff, true, 0xffffffff, 0xcbf43926);
+    ///
+    /// assert_eq!(checksum_with_params(PARAMS, b"123456789"), 0xcbf43926);
+    /// ```
+    pub const fn const_new(
+        name: &'static str,
+        width: u8,
+        poly: u64,
+        init: u64,
+        reflected: bool,
+        xorout: u64,
+        check: u64,
+    ) -> Self {
+        let keys = generate::keys(width, poly, reflected);
+
+        Self::with_keys(name, width, poly, init, reflected, xorout, check, keys)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    const fn with_keys(
+        name: &'static str,
+        width: u8,
+        poly: u64,
+        init: u64,
+        reflected: bool,
+        xorout: u64,
+        check: u64,
+        keys: [u64; 23],
+    ) -> Self {
         // Validate width is supported
         if width != 16 && width != 32 && width != 64 {
-            panic!("Unsupported width: {width}");
+            panic!("Unsupported width, must be 16, 32 or 64");
         }
 
         // For reflected CRC-16, bit-reverse the init value for the SIMD algorithm
@@ -124,7 +166,7 @@ impl CrcParams {
             refout: reflected,
             xorout,
             check,
-            keys,
+            keys: crate::CrcKeysStorage::from_keys_fold_256(keys),
         }
     }
 
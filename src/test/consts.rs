@@ -3,6 +3,7 @@
 #![cfg(test)]
 #![allow(dead_code)]
 
+#[cfg(feature = "crc16")]
 use crate::crc16::consts::{
     CRC16_ARC, CRC16_CDMA2000, CRC16_CMS, CRC16_DDS_110, CRC16_DECT_R, CRC16_DECT_X, CRC16_DNP,
     CRC16_EN_13757, CRC16_GENIBUS, CRC16_GSM, CRC16_IBM_3740, CRC16_IBM_SDLC,
@@ -11,14 +12,17 @@ use crate::crc16::consts::{
     CRC16_RIELLO, CRC16_SPI_FUJITSU, CRC16_T10_DIF, CRC16_TELEDISK, CRC16_TMS37157, CRC16_UMTS,
     CRC16_USB, CRC16_XMODEM,
 };
+#[cfg(feature = "crc32")]
 use crate::crc32::consts::{
     CRC32_AIXM, CRC32_AUTOSAR, CRC32_BASE91_D, CRC32_BZIP2, CRC32_CD_ROM_EDC, CRC32_CKSUM,
     CRC32_ISCSI, CRC32_ISO_HDLC, CRC32_JAMCRC, CRC32_MEF, CRC32_MPEG_2, CRC32_XFER,
 };
+#[cfg(feature = "crc64")]
 use crate::crc64::consts::{
     CRC64_ECMA_182, CRC64_GO_ISO, CRC64_MS, CRC64_NVME, CRC64_REDIS, CRC64_WE, CRC64_XZ,
 };
 use crate::test::enums::*;
+#[cfg(any(feature = "crc16", feature = "crc32", feature = "crc64"))]
 use crate::test::structs::*;
 use crc::Table;
 
@@ -189,305 +193,405 @@ pub(crate) const RUST_CRC64_WE: crc::Crc<u64, Table<16>> =
 pub(crate) const RUST_CRC64_XZ: crc::Crc<u64, Table<16>> =
     crc::Crc::<u64, Table<16>>::new(&crc::CRC_64_XZ);
 
+#[cfg(feature = "crc64")]
 pub(crate) const TEST_CRC64_ECMA_182: Crc64TestConfig = Crc64TestConfig {
     params: CRC64_ECMA_182,
     reference_impl: &RUST_CRC64_ECMA_182,
 };
 
+#[cfg(feature = "crc64")]
 pub(crate) const TEST_CRC64_GO_ISO: Crc64TestConfig = Crc64TestConfig {
     params: CRC64_GO_ISO,
     reference_impl: &RUST_CRC64_GO_ISO,
 };
 
+#[cfg(feature = "crc64")]
 pub(crate) const TEST_CRC64_MS: Crc64TestConfig = Crc64TestConfig {
     params: CRC64_MS,
     reference_impl: &RUST_CRC64_MS,
 };
 
+#[cfg(feature = "crc64")]
 pub(crate) const TEST_CRC64_NVME: Crc64TestConfig = Crc64TestConfig {
     params: CRC64_NVME,
     reference_impl: &RUST_CRC64_NVME,
 };
 
+#[cfg(feature = "crc64")]
 pub(crate) const TEST_CRC64_REDIS: Crc64TestConfig = Crc64TestConfig {
     params: CRC64_REDIS,
     reference_impl: &RUST_CRC64_REDIS,
 };
 
+#[cfg(feature = "crc64")]
 pub(crate) const TEST_CRC64_WE: Crc64TestConfig = Crc64TestConfig {
     params: CRC64_WE,
     reference_impl: &RUST_CRC64_WE,
 };
 
+#[cfg(feature = "crc64")]
 pub(crate) const TEST_CRC64_XZ: Crc64TestConfig = Crc64TestConfig {
     params: CRC64_XZ,
     reference_impl: &RUST_CRC64_XZ,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_ARC: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_ARC,
     reference_impl: &RUST_CRC16_ARC,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_CDMA2000: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_CDMA2000,
     reference_impl: &RUST_CRC16_CDMA2000,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_CMS: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_CMS,
     reference_impl: &RUST_CRC16_CMS,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_DDS_110: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_DDS_110,
     reference_impl: &RUST_CRC16_DDS_110,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_DECT_R: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_DECT_R,
     reference_impl: &RUST_CRC16_DECT_R,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_DECT_X: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_DECT_X,
     reference_impl: &RUST_CRC16_DECT_X,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_DNP: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_DNP,
     reference_impl: &RUST_CRC16_DNP,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_EN_13757: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_EN_13757,
     reference_impl: &RUST_CRC16_EN_13757,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_GENIBUS: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_GENIBUS,
     reference_impl: &RUST_CRC16_GENIBUS,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_GSM: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_GSM,
     reference_impl: &RUST_CRC16_GSM,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_IBM_3740: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_IBM_3740,
     reference_impl: &RUST_CRC16_IBM_3740,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_IBM_SDLC: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_IBM_SDLC,
     reference_impl: &RUST_CRC16_IBM_SDLC,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_ISO_IEC_14443_3_A: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_ISO_IEC_14443_3_A,
     reference_impl: &RUST_CRC16_ISO_IEC_14443_3_A,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_KERMIT: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_KERMIT,
     reference_impl: &RUST_CRC16_KERMIT,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_LJ1200: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_LJ1200,
     reference_impl: &RUST_CRC16_LJ1200,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_M17: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_M17,
     reference_impl: &RUST_CRC16_M17,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_MAXIM_DOW: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_MAXIM_DOW,
     reference_impl: &RUST_CRC16_MAXIM_DOW,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_MCRF4XX: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_MCRF4XX,
     reference_impl: &RUST_CRC16_MCRF4XX,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_MODBUS: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_MODBUS,
     reference_impl: &RUST_CRC16_MODBUS,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_NRSC_5: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_NRSC_5,
     reference_impl: &RUST_CRC16_NRSC_5,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_OPENSAFETY_A: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_OPENSAFETY_A,
     reference_impl: &RUST_CRC16_OPENSAFETY_A,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_OPENSAFETY_B: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_OPENSAFETY_B,
     reference_impl: &RUST_CRC16_OPENSAFETY_B,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_PROFIBUS: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_PROFIBUS,
     reference_impl: &RUST_CRC16_PROFIBUS,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_RIELLO: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_RIELLO,
     reference_impl: &RUST_CRC16_RIELLO,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_SPI_FUJITSU: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_SPI_FUJITSU,
     reference_impl: &RUST_CRC16_SPI_FUJITSU,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_T10_DIF: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_T10_DIF,
     reference_impl: &RUST_CRC16_T10_DIF,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_TELEDISK: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_TELEDISK,
     reference_impl: &RUST_CRC16_TELEDISK,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_TMS37157: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_TMS37157,
     reference_impl: &RUST_CRC16_TMS37157,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_UMTS: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_UMTS,
     reference_impl: &RUST_CRC16_UMTS,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_USB: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_USB,
     reference_impl: &RUST_CRC16_USB,
 };
 
+#[cfg(feature = "crc16")]
 pub(crate) const TEST_CRC16_XMODEM: Crc16TestConfig = Crc16TestConfig {
     params: CRC16_XMODEM,
     reference_impl: &RUST_CRC16_XMODEM,
 };
 
+#[cfg(feature = "crc32")]
 pub(crate) const TEST_CRC32_AIXM: Crc32TestConfig = Crc32TestConfig {
     params: CRC32_AIXM,
     reference_impl: &RUST_CRC32_AIXM,
 };
 
+#[cfg(feature = "crc32")]
 pub(crate) const TEST_CRC32_AUTOSAR: Crc32TestConfig = Crc32TestConfig {
     params: CRC32_AUTOSAR,
     reference_impl: &RUST_CRC32_AUTOSAR,
 };
 
+#[cfg(feature = "crc32")]
 pub(crate) const TEST_CRC32_BASE91_D: Crc32TestConfig = Crc32TestConfig {
     params: CRC32_BASE91_D,
     reference_impl: &RUST_CRC32_BASE91_D,
 };
 
+#[cfg(feature = "crc32")]
 pub(crate) const TEST_CRC32_BZIP2: Crc32TestConfig = Crc32TestConfig {
     params: CRC32_BZIP2,
     reference_impl: &RUST_CRC32_BZIP2,
 };
 
+#[cfg(feature = "crc32")]
 pub(crate) const TEST_CRC32_CD_ROM_EDC: Crc32TestConfig = Crc32TestConfig {
     params: CRC32_CD_ROM_EDC,
     reference_impl: &RUST_CRC32_CD_ROM_EDC,
 };
 
+#[cfg(feature = "crc32")]
 pub(crate) const TEST_CRC32_CKSUM: Crc32TestConfig = Crc32TestConfig {
     params: CRC32_CKSUM,
     reference_impl: &RUST_CRC32_CKSUM,
 };
 
+#[cfg(feature = "crc32")]
 pub(crate) const TEST_CRC32_ISCSI: Crc32TestConfig = Crc32TestConfig {
     params: CRC32_ISCSI,
     reference_impl: &RUST_CRC32_ISCSI,
 };
 
+#[cfg(feature = "crc32")]
 pub(crate) const TEST_CRC32_ISO_HDLC: Crc32TestConfig = Crc32TestConfig {
     params: CRC32_ISO_HDLC,
     reference_impl: &RUST_CRC32_ISO_HDLC,
 };
 
+#[cfg(feature = "crc32")]
 pub(crate) const TEST_CRC32_JAMCRC: Crc32TestConfig = Crc32TestConfig {
     params: CRC32_JAMCRC,
     reference_impl: &RUST_CRC32_JAMCRC,
 };
 
+#[cfg(feature = "crc32")]
 pub(crate) const TEST_CRC32_MEF: Crc32TestConfig = Crc32TestConfig {
     params: CRC32_MEF,
     reference_impl: &RUST_CRC32_MEF,
 };
 
+#[cfg(feature = "crc32")]
 pub(crate) const TEST_CRC32_MPEG_2: Crc32TestConfig = Crc32TestConfig {
     params: CRC32_MPEG_2,
     reference_impl: &RUST_CRC32_MPEG_2,
 };
 
+#[cfg(feature = "crc32")]
 pub(crate) const TEST_CRC32_XFER: Crc32TestConfig = Crc32TestConfig {
     params: CRC32_XFER,
     reference_impl: &RUST_CRC32_XFER,
 };
 
 pub(crate) const TEST_ALL_CONFIGS: &[AnyCrcTestConfig] = &[
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_ARC),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_CDMA2000),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_CMS),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_DDS_110),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_DECT_R),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_DECT_X),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_DNP),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_EN_13757),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_GENIBUS),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_GSM),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_IBM_3740),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_IBM_SDLC),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_ISO_IEC_14443_3_A),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_KERMIT),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_LJ1200),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_M17),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_MAXIM_DOW),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_MCRF4XX),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_MODBUS),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_NRSC_5),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_OPENSAFETY_A),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_OPENSAFETY_B),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_PROFIBUS),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_RIELLO),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_SPI_FUJITSU),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_T10_DIF),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_TELEDISK),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_TMS37157),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_UMTS),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_USB),
+    #[cfg(feature = "crc16")]
     AnyCrcTestConfig::CRC16(&TEST_CRC16_XMODEM),
+    #[cfg(feature = "crc32")]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_AIXM),
+    #[cfg(feature = "crc32")]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_AUTOSAR),
+    #[cfg(feature = "crc32")]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_BASE91_D),
+    #[cfg(feature = "crc32")]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_BZIP2),
+    #[cfg(feature = "crc32")]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_CD_ROM_EDC),
+    #[cfg(feature = "crc32")]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_CKSUM),
+    #[cfg(feature = "crc32")]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_ISCSI),
+    #[cfg(feature = "crc32")]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_ISO_HDLC),
+    #[cfg(feature = "crc32")]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_JAMCRC),
+    #[cfg(feature = "crc32")]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_MEF),
+    #[cfg(feature = "crc32")]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_MPEG_2),
+    #[cfg(feature = "crc32")]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_XFER),
+    #[cfg(feature = "crc64")]
     AnyCrcTestConfig::CRC64(&TEST_CRC64_ECMA_182),
+    #[cfg(feature = "crc64")]
     AnyCrcTestConfig::CRC64(&TEST_CRC64_GO_ISO),
+    #[cfg(feature = "crc64")]
     AnyCrcTestConfig::CRC64(&TEST_CRC64_MS),
+    #[cfg(feature = "crc64")]
     AnyCrcTestConfig::CRC64(&TEST_CRC64_NVME),
+    #[cfg(feature = "crc64")]
     AnyCrcTestConfig::CRC64(&TEST_CRC64_REDIS),
+    #[cfg(feature = "crc64")]
     AnyCrcTestConfig::CRC64(&TEST_CRC64_WE),
+    #[cfg(feature = "crc64")]
     AnyCrcTestConfig::CRC64(&TEST_CRC64_XZ),
 ];
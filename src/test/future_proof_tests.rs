@@ -653,6 +653,7 @@ fn test_key_access_performance_matches_direct_array_access() {
 }
 
 #[test]
+#[cfg(all(feature = "crc32", feature = "crc64"))]
 fn test_crc_calculation_performance_before_and_after_changes() {
     // Test that CRC calculation performance remains identical with the new key storage
     use crate::{checksum, CrcAlgorithm};
@@ -1361,7 +1362,12 @@ fn test_future_expansion_backwards_compatibility() {
 
 #[cfg(all(
     feature = "ffi",
-    any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86")
+    any(
+        target_arch = "aarch64",
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "riscv64"
+    )
 ))]
 mod ffi_tests {
     use crate::ffi::CrcFastParams;
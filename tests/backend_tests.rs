@@ -0,0 +1,71 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Tests for forcing a backend process-wide. The override is global state, so this is a separate
+//! test binary with a single test.
+
+#![cfg(feature = "std")]
+
+#[cfg(any(feature = "crc32", feature = "crc64"))]
+use crc_fast::checksum;
+#[cfg(any(feature = "crc16", feature = "crc32", feature = "crc64"))]
+use crc_fast::CrcAlgorithm;
+#[cfg(feature = "crc16")]
+use crc_fast::Digest;
+use crc_fast::{
+    active_backend, available_backends, set_backend_override, BackendUnavailable, PerformanceTier,
+    FORCE_TIER_ENV,
+};
+
+#[cfg(any(feature = "crc16", feature = "crc32", feature = "crc64"))]
+const CHECK_STRING: &[u8] = b"123456789";
+
+#[test]
+fn test_forced_backends() {
+    // must be set before the first dispatch, which caches the active tier
+    std::env::set_var(FORCE_TIER_ENV, "software-fallback-tables");
+
+    assert_eq!(active_backend(), PerformanceTier::SoftwareTable);
+    #[cfg(feature = "crc32")]
+    assert_eq!(checksum(CrcAlgorithm::Crc32Iscsi, CHECK_STRING), 0xe3069283);
+
+    for backend in available_backends() {
+        set_backend_override(Some(backend)).unwrap();
+        assert_eq!(active_backend(), backend);
+
+        #[cfg(feature = "crc32")]
+        assert_eq!(checksum(CrcAlgorithm::Crc32Iscsi, CHECK_STRING), 0xe3069283);
+        #[cfg(feature = "crc32")]
+        assert_eq!(
+            checksum(CrcAlgorithm::Crc32IsoHdlc, CHECK_STRING),
+            0xcbf43926
+        );
+        #[cfg(feature = "crc64")]
+        assert_eq!(
+            checksum(CrcAlgorithm::Crc64Nvme, CHECK_STRING),
+            0xae8b14860a799888
+        );
+
+        #[cfg(feature = "crc16")]
+        {
+            let mut digest = Digest::new(CrcAlgorithm::Crc16Modbus);
+            digest.update(CHECK_STRING);
+            assert_eq!(digest.finalize(), 0x4b37);
+        }
+    }
+
+    // an unavailable backend leaves the current one in place
+    #[cfg(target_arch = "aarch64")]
+    let unavailable = PerformanceTier::X86_64SsePclmulqdq;
+    #[cfg(not(target_arch = "aarch64"))]
+    let unavailable = PerformanceTier::AArch64Aes;
+
+    assert_eq!(
+        set_backend_override(Some(unavailable)),
+        Err(BackendUnavailable(unavailable))
+    );
+    assert_eq!(active_backend(), PerformanceTier::SoftwareTable);
+
+    // clearing the override returns to the tier forced by the environment
+    set_backend_override(None).unwrap();
+    assert_eq!(active_backend(), PerformanceTier::SoftwareTable);
+}
@@ -0,0 +1,95 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Tests for the custom parameter cache management API. The cache and its settings are global
+//! state, so this is a separate test binary with a single test.
+
+#![cfg(feature = "std")]
+
+use crc_fast::{cache, checksum_with_params, set_backend_override, CrcParams, PerformanceTier};
+
+fn custom_params(poly: u64) -> CrcParams {
+    CrcParams::new("CRC-32/TEST", 32, poly, 0xffffffff, true, 0xffffffff, 0)
+}
+
+#[test]
+fn test_cache_management() {
+    // the software tables are only cached while the software backend is active
+    set_backend_override(Some(PerformanceTier::SoftwareTable)).unwrap();
+
+    assert!(cache::stats().enabled);
+    assert_eq!(cache::capacity(), cache::DEFAULT_CAPACITY);
+
+    cache::clear();
+    let stats = cache::stats();
+    assert_eq!(
+        (stats.key_entries, stats.table_entries, stats.plan_entries),
+        (0, 0, 0)
+    );
+
+    // prewarming caches both the keys and the table, so the first use is all hits
+    let params = custom_params(0x1edc6f41);
+    cache::prewarm(&params);
+
+    let before = cache::stats();
+    assert_eq!((before.key_entries, before.table_entries), (1, 1));
+
+    let crc = checksum_with_params(params, b"123456789");
+    assert_eq!(crc, 0xe3069283);
+
+    let after = cache::stats();
+    assert_eq!(after.misses, before.misses);
+    assert!(after.hits > before.hits);
+
+    // a smaller capacity evicts the least recently used entries
+    cache::set_capacity(2);
+    for poly in [0x04c11db7, 0x741b8cd7, 0x814141ab] {
+        let params = custom_params(poly);
+        checksum_with_params(params, b"123456789");
+    }
+
+    let stats = cache::stats();
+    assert_eq!(stats.capacity, 2);
+    assert_eq!((stats.key_entries, stats.table_entries), (2, 2));
+    assert!(stats.evictions >= 4);
+
+    // evicted parameters still work, they're just regenerated
+    assert_eq!(checksum_with_params(params, b"123456789"), crc);
+
+    // without room for a table, the software path works bit by bit instead of generating one
+    let data: Vec<u8> = (0..4096u32).map(|i| (i * 31 + (i >> 3)) as u8).collect();
+    let long_crc = checksum_with_params(params, &data);
+
+    // long inputs search once for a fold plan for a polynomial without a predefined one
+    let searched = custom_params(0x32583499);
+    let searched_crc = checksum_with_params(searched, &data);
+    assert_eq!(cache::stats().plan_entries, 1);
+    assert_eq!(checksum_with_params(searched, &data), searched_crc);
+    assert_eq!(cache::stats().plan_entries, 1);
+
+    cache::set_capacity(0);
+    assert_eq!(checksum_with_params(params, b"123456789"), crc);
+    assert_eq!(checksum_with_params(params, &data), long_crc);
+    assert_eq!(checksum_with_params(searched, &data), searched_crc);
+
+    let stats = cache::stats();
+    assert_eq!((stats.table_entries, stats.plan_entries), (0, 0));
+    cache::set_capacity(2);
+
+    // disabling clears the caches and stops filling them
+    cache::set_enabled(false);
+    assert_eq!(checksum_with_params(params, b"123456789"), crc);
+    assert_eq!(checksum_with_params(params, &data), long_crc);
+
+    assert_eq!(checksum_with_params(searched, &data), searched_crc);
+
+    let stats = cache::stats();
+    assert!(!stats.enabled);
+    assert_eq!(
+        (stats.key_entries, stats.table_entries, stats.plan_entries),
+        (0, 0, 0)
+    );
+
+    cache::set_enabled(true);
+    cache::set_capacity(cache::DEFAULT_CAPACITY);
+    set_backend_override(None).unwrap();
+}
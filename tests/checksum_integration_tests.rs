@@ -3,7 +3,8 @@
 #![cfg(feature = "cli")]
 
 use std::fs;
-use std::process::Command;
+use std::io::Write;
+use std::process::{Command, Stdio};
 
 #[test]
 #[cfg_attr(miri, ignore)] // Miri doesn't allow this due to isolation restrictions
@@ -275,3 +276,392 @@ fn test_benchmark_nonexistent_file() {
     let stderr = String::from_utf8_lossy(&output.stderr);
     assert!(stderr.contains("File not found"));
 }
+
+/// Creates an empty scratch directory for a test
+fn scratch_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("crc_fast_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("Failed to create scratch directory");
+
+    dir
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri doesn't allow this due to isolation restrictions
+fn test_multiple_files_and_stdin() {
+    let dir = scratch_dir("multiple_files");
+    let a = dir.join("a.txt");
+    let b = dir.join("b.txt");
+    fs::write(&a, "123456789").unwrap();
+    fs::write(&b, "").unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_checksum"))
+        .args(["-a", "CRC-32/ISO-HDLC"])
+        .arg(&a)
+        .arg(&b)
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to execute command");
+    child.stdin.take().unwrap().write_all(b"123456789").unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    let _ = fs::remove_dir_all(&dir);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        stdout,
+        format!(
+            "cbf43926  {}\n00000000  {}\ncbf43926  -\n",
+            a.display(),
+            b.display()
+        )
+    );
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri doesn't allow this due to isolation restrictions
+fn test_missing_file_continues_with_error_status() {
+    let dir = scratch_dir("missing_file");
+    let a = dir.join("a.txt");
+    let missing = dir.join("missing.txt");
+    fs::write(&a, "123456789").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_checksum"))
+        .args(["-a", "CRC-64/NVME"])
+        .arg(&missing)
+        .arg(&a)
+        .output()
+        .expect("Failed to execute command");
+
+    let _ = fs::remove_dir_all(&dir);
+
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout, format!("ae8b14860a799888  {}\n", a.display()));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains(&format!("checksum: {}:", missing.display())));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri doesn't allow this due to isolation restrictions
+fn test_recursive_and_check() {
+    let dir = scratch_dir("recursive_check");
+    fs::create_dir_all(dir.join("sub")).unwrap();
+    fs::write(dir.join("b.txt"), "b").unwrap();
+    fs::write(dir.join("sub").join("a.txt"), "a").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_checksum"))
+        .args(["-a", "CRC-32/ISCSI", "-r"])
+        .arg(&dir)
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let manifest = String::from_utf8_lossy(&output.stdout).into_owned();
+    let lines: Vec<&str> = manifest.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].ends_with("b.txt"));
+    assert!(lines[1].ends_with("a.txt"));
+
+    let manifest_file = dir.join("manifest.txt");
+    fs::write(&manifest_file, &manifest).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_checksum"))
+        .args(["-a", "CRC-32/ISCSI", "-c"])
+        .arg(&manifest_file)
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.matches(": OK").count(), 2);
+
+    // A changed file fails the check, and --status keeps it silent
+    fs::write(dir.join("b.txt"), "changed").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_checksum"))
+        .args(["-a", "CRC-32/ISCSI", "-c", "--quiet"])
+        .arg(&manifest_file)
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout, format!("{}: FAILED\n", dir.join("b.txt").display()));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("WARNING: 1 computed checksum did NOT match"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_checksum"))
+        .args(["-a", "CRC-32/ISCSI", "-c", "--status"])
+        .arg(&manifest_file)
+        .output()
+        .expect("Failed to execute command");
+
+    let _ = fs::remove_dir_all(&dir);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri doesn't allow this due to isolation restrictions
+fn test_posix_cksum_mode() {
+    let dir = scratch_dir("posix_cksum");
+    let zeros = dir.join("zeros.bin");
+    fs::write(&zeros, [0u8; 300]).unwrap();
+
+    // GNU coreutils: `head -c 300 /dev/zero | cksum` prints "351385237 300"
+    let output = Command::new(env!("CARGO_BIN_EXE_checksum"))
+        .arg("--posix-cksum")
+        .arg(&zeros)
+        .output()
+        .expect("Failed to execute command");
+
+    let _ = fs::remove_dir_all(&dir);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout, format!("351385237 300 {}\n", zeros.display()));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_checksum"))
+        .args(["--posix-cksum", "-s", "123456789"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "930766865 9\n");
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri doesn't allow this due to isolation restrictions
+fn test_output_formats() {
+    let run = |format: &str| {
+        let output = Command::new(env!("CARGO_BIN_EXE_checksum"))
+            .args(["-a", "CRC-64/NVME", "-s", "123456789", "--format", format])
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(output.status.success(), "--format {format}");
+        output.stdout
+    };
+
+    assert_eq!(run("hex"), b"0xae8b14860a799888\n");
+    assert_eq!(run("decimal"), b"12577168950296156296\n");
+    assert_eq!(run("base64"), b"rosUhgp5mIg=\n");
+    assert_eq!(run("bytes"), 0xae8b14860a799888u64.to_be_bytes());
+    assert_eq!(
+        String::from_utf8_lossy(&run("json")),
+        "{\"algorithm\":\"CRC-64/NVME\",\"width\":64,\"value\":\"ae8b14860a799888\",\"input\":null}\n"
+    );
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri doesn't allow this due to isolation restrictions
+fn test_check_base64_manifest() {
+    let dir = scratch_dir("base64_manifest");
+    let file = dir.join("check.txt");
+    let manifest = dir.join("manifest.txt");
+    fs::write(&file, "123456789").unwrap();
+    fs::write(&manifest, format!("4waSgw==  {}\n", file.display())).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_checksum"))
+        .args(["-a", "CRC-32/ISCSI", "--format", "base64", "-c"])
+        .arg(&manifest)
+        .output()
+        .expect("Failed to execute command");
+
+    let _ = fs::remove_dir_all(&dir);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout, format!("{}: OK\n", file.display()));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri doesn't allow this due to isolation restrictions
+fn test_arch_check_self_test() {
+    let output = Command::new(env!("CARGO_BIN_EXE_arch-check"))
+        .arg("--self-test")
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("[Self-test]"));
+    assert!(stdout.contains("passed with"));
+    assert!(stdout.contains("software-fallback-tables"));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri doesn't allow this due to isolation restrictions
+fn test_bench_matrix_csv() {
+    let output = Command::new(env!("CARGO_BIN_EXE_checksum"))
+        .args([
+            "--bench-matrix",
+            "-a",
+            "CRC-32/ISCSI",
+            "--sizes",
+            "64,1024",
+            "--tiers",
+            "auto,software-fallback-tables",
+            "--duration",
+            "0.05",
+            "--warmup",
+            "0.01",
+            "--samples",
+            "2",
+            "--bench-format",
+            "csv",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    assert_eq!(lines.len(), 5);
+    assert!(lines[0].starts_with("algorithm,tier,size_bytes,samples,iterations,"));
+    assert!(lines
+        .iter()
+        .all(|line| line.split(',').count() == lines[0].split(',').count()));
+    assert!(lines[1].starts_with("CRC-32/ISCSI,auto,64,2,"));
+    assert!(lines[2].starts_with("CRC-32/ISCSI,software-fallback-tables,64,2,"));
+    assert!(lines[4].starts_with("CRC-32/ISCSI,software-fallback-tables,1024,2,"));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri doesn't allow this due to isolation restrictions
+fn test_bench_matrix_flags_require_bench_matrix() {
+    let output = Command::new(env!("CARGO_BIN_EXE_checksum"))
+        .args(["-a", "CRC-32/ISCSI", "-s", "hello", "--sizes", "64"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("can only be used with --bench-matrix"));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri doesn't allow this due to isolation restrictions
+fn test_forge() {
+    // forging the check value back over part of the check string recovers that part
+    for (algorithm, offset, target, expected) in [
+        ("CRC-32/ISO-HDLC", "2", "0xcbf43926", "33343536"),
+        ("CRC-16/XMODEM", "7", "31c3", "3839"),
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_checksum"))
+            .args([
+                "-a",
+                algorithm,
+                "-s",
+                "123456789",
+                "--forge",
+                offset,
+                "--target",
+                target,
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), expected);
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_checksum"))
+        .args([
+            "-a",
+            "CRC-64/NVME",
+            "-s",
+            "12345",
+            "--forge",
+            "0",
+            "--target",
+            "1",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("don't fit"));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri doesn't allow this due to isolation restrictions
+fn test_get_custom_params_emit_reveng() {
+    let output = Command::new(env!("CARGO_BIN_EXE_get-custom-params"))
+        .args([
+            "-n",
+            "CRC-64/NVME",
+            "-w",
+            "64",
+            "-p",
+            "0xad93d23594c93659",
+            "-i",
+            "0xffffffffffffffff",
+            "-r",
+            "true",
+            "-x",
+            "0xffffffffffffffff",
+            "-c",
+            "0xae8b14860a799888",
+            "--emit",
+            "reveng",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "width=64  poly=0xad93d23594c93659  init=0xffffffffffffffff  refin=true  refout=true  xorout=0xffffffffffffffff  check=0xae8b14860a799888  residue=0xf310303b2b6f6e42  name=\"CRC-64/NVME\"\n"
+    );
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri doesn't allow this due to isolation restrictions
+fn test_arch_check_json() {
+    let output = Command::new(env!("CARGO_BIN_EXE_arch-check"))
+        .arg("--json")
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("{\"arch\":"));
+    assert!(stdout.contains("\"capabilities\":{\"aes\":"));
+    assert!(stdout.contains("\"name\":\"software-fallback-tables\",\"available\":true"));
+    assert!(stdout.contains("{\"algorithm\":\"CRC-32/ISCSI\",\"backend\":"));
+    assert!(!stdout.contains("[CPU Info]"));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri doesn't allow this due to isolation restrictions
+fn test_arch_check_rejected_force_tier() {
+    let output = Command::new(env!("CARGO_BIN_EXE_arch-check"))
+        .arg("--json")
+        .env("CRC_FAST_FORCE_TIER", "avx512")
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("\"forced\":false,\"force_rejected\":\"unknown backend \\\"avx512\\\"\"")
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_arch-check"))
+        .env("CRC_FAST_FORCE_TIER", "avx512")
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout)
+        .contains("CRC_FAST_FORCE_TIER ignored: unknown backend \"avx512\""));
+}
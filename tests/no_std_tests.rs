@@ -5,10 +5,15 @@
 //!
 //! Run tests: cargo test --test no_std_tests
 
-use crc_fast::{checksum, CrcAlgorithm, Digest};
+#[cfg(feature = "crc32")]
+use crc_fast::checksum;
+#[cfg(any(feature = "crc32", feature = "crc64"))]
+use crc_fast::CrcAlgorithm;
+use crc_fast::{checksum_with_tables, CrcParams, CustomTables, Digest};
 
 /// Test basic checksum calculation (works without std)
 #[test]
+#[cfg(all(feature = "crc32", feature = "crc64"))]
 fn test_no_std_basic_checksum() {
     let data = b"123456789";
 
@@ -40,6 +45,7 @@ fn test_no_std_basic_checksum() {
 
 /// Test all 21 standard CRC algorithms
 #[test]
+#[cfg(all(feature = "crc32", feature = "crc64"))]
 fn test_no_std_all_algorithms() {
     let data = b"123456789";
 
@@ -66,6 +72,7 @@ fn test_no_std_all_algorithms() {
 
 /// Test Digest API (core functionality without std)
 #[test]
+#[cfg(feature = "crc32")]
 fn test_no_std_digest_api() {
     let mut digest = Digest::new(CrcAlgorithm::Crc32IsoHdlc);
     digest.update(b"1234");
@@ -77,6 +84,7 @@ fn test_no_std_digest_api() {
 
 /// Test empty input
 #[test]
+#[cfg(feature = "crc32")]
 fn test_no_std_empty_input() {
     let empty: &[u8] = &[];
     let result = checksum(CrcAlgorithm::Crc32IsoHdlc, empty);
@@ -86,6 +94,7 @@ fn test_no_std_empty_input() {
 
 /// Test various input sizes to ensure all code paths work
 #[test]
+#[cfg(feature = "crc32")]
 fn test_no_std_various_sizes() {
     // Small (< 64 bytes)
     let small = b"hello";
@@ -104,6 +113,7 @@ fn test_no_std_various_sizes() {
 
 /// Test that reflected and non-reflected algorithms both work
 #[test]
+#[cfg(feature = "crc32")]
 fn test_no_std_reflection_modes() {
     let data = b"123456789";
 
@@ -136,6 +146,7 @@ fn test_no_std_custom_params() {
 
 /// Test that Digest can be reused
 #[test]
+#[cfg(feature = "crc32")]
 fn test_no_std_digest_reuse() {
     let mut digest = Digest::new(CrcAlgorithm::Crc32IsoHdlc);
 
@@ -151,6 +162,7 @@ fn test_no_std_digest_reuse() {
 
 /// Test incremental digest updates
 #[test]
+#[cfg(feature = "crc64")]
 fn test_no_std_incremental_digest() {
     let mut digest1 = Digest::new(CrcAlgorithm::Crc64Nvme);
     digest1.update(b"123456789");
@@ -168,6 +180,7 @@ fn test_no_std_incremental_digest() {
 /// Test checksum_combine (requires alloc)
 #[cfg(feature = "alloc")]
 #[test]
+#[cfg(feature = "crc32")]
 fn test_no_std_checksum_combine() {
     use crc_fast::checksum_combine;
 
@@ -181,6 +194,7 @@ fn test_no_std_checksum_combine() {
 
 /// Test that the library works with stack-only data
 #[test]
+#[cfg(feature = "crc32")]
 fn test_no_std_stack_only() {
     // This should work without any heap allocation
     let data = b"123456789";
@@ -190,6 +204,7 @@ fn test_no_std_stack_only() {
 
 /// Test both CRC-32 and CRC-64 widths
 #[test]
+#[cfg(all(feature = "crc32", feature = "crc64"))]
 fn test_no_std_both_widths() {
     let data = b"test data";
 
@@ -202,6 +217,7 @@ fn test_no_std_both_widths() {
 
 /// Test with byte patterns that might expose issues
 #[test]
+#[cfg(feature = "crc32")]
 fn test_no_std_edge_case_patterns() {
     // All zeros
     let zeros = [0u8; 128];
@@ -215,3 +231,63 @@ fn test_no_std_edge_case_patterns() {
     let alt = [0xAAu8; 128];
     let _ = checksum(CrcAlgorithm::Crc32IsoHdlc, &alt);
 }
+
+/// Test custom parameters with caller-owned tables, which don't need alloc
+#[test]
+fn test_no_std_custom_tables() {
+    // CRC-16/ARC, as custom parameters
+    static TABLES: CustomTables = CustomTables::new(CrcParams::const_new(
+        "CRC-16/CUSTOM",
+        16,
+        0x8005,
+        0x0,
+        true,
+        0x0,
+        0xbb3d,
+    ));
+
+    assert_eq!(checksum_with_tables(&TABLES, b"123456789"), 0xbb3d);
+
+    let mut digest = Digest::new_with_tables(&TABLES);
+    digest.update(b"1234");
+    digest.update(b"56789");
+    assert_eq!(digest.finalize(), 0xbb3d);
+}
+
+/// Test custom parameters and tables built entirely at compile time match runtime ones
+#[test]
+fn test_no_std_const_custom_params() {
+    // CRC-64/XZ, as custom parameters
+    const PARAMS: CrcParams = CrcParams::const_new(
+        "CRC-64/CUSTOM",
+        64,
+        0x42f0e1eba9ea3693,
+        0xffffffffffffffff,
+        true,
+        0xffffffffffffffff,
+        0x995dc9bbdf1939fa,
+    );
+    static TABLES: CustomTables = CustomTables::new(PARAMS);
+
+    let runtime = CrcParams::new(
+        "CRC-64/CUSTOM",
+        64,
+        0x42f0e1eba9ea3693,
+        0xffffffffffffffff,
+        true,
+        0xffffffffffffffff,
+        0x995dc9bbdf1939fa,
+    );
+    for i in 0..runtime.key_count() {
+        assert_eq!(PARAMS.get_key(i), runtime.get_key(i), "key {i}");
+    }
+
+    let data = [0x5au8; 1000];
+    let mut digest = Digest::new_with_tables(&TABLES);
+    digest.update(b"123456789");
+    assert_eq!(digest.finalize(), 0x995dc9bbdf1939fa);
+    assert_eq!(
+        checksum_with_tables(&TABLES, &data),
+        crc_fast::checksum_with_params(runtime, &data)
+    );
+}
@@ -5,13 +5,17 @@
 //!
 //! Run tests: cargo test --test wasm_tests
 
+#[cfg(any(feature = "crc32", feature = "crc64"))]
 use crc_fast::{checksum, CrcAlgorithm, Digest};
 
+#[cfg(all(feature = "alloc", feature = "crc32"))]
+use crc_fast::checksum_combine;
 #[cfg(feature = "alloc")]
-use crc_fast::{checksum_combine, checksum_with_params, CrcParams};
+use crc_fast::{checksum_with_params, CrcParams};
 
 /// Test basic CRC calculation
 #[test]
+#[cfg(feature = "crc32")]
 fn test_wasm_basic_crc32() {
     let data = b"123456789";
     assert_eq!(checksum(CrcAlgorithm::Crc32IsoHdlc, data), 0xcbf43926);
@@ -20,6 +24,7 @@ fn test_wasm_basic_crc32() {
 
 /// Test CRC-64 variants
 #[test]
+#[cfg(feature = "crc64")]
 fn test_wasm_crc64() {
     let data = b"123456789";
     assert_eq!(checksum(CrcAlgorithm::Crc64Nvme, data), 0xae8b14860a799888);
@@ -28,6 +33,7 @@ fn test_wasm_crc64() {
 
 /// Test Digest API (incremental hashing)
 #[test]
+#[cfg(feature = "crc32")]
 fn test_wasm_digest() {
     let mut digest = Digest::new(CrcAlgorithm::Crc32IsoHdlc);
     digest.update(b"1234");
@@ -37,6 +43,7 @@ fn test_wasm_digest() {
 
 /// Test all CRC-32 algorithms
 #[test]
+#[cfg(feature = "crc32")]
 fn test_wasm_all_crc32() {
     let data = b"123456789";
     assert_eq!(checksum(CrcAlgorithm::Crc32IsoHdlc, data), 0xcbf43926);
@@ -47,6 +54,7 @@ fn test_wasm_all_crc32() {
 
 /// Test all CRC-64 algorithms
 #[test]
+#[cfg(feature = "crc64")]
 fn test_wasm_all_crc64() {
     let data = b"123456789";
     assert_eq!(
@@ -59,7 +67,7 @@ fn test_wasm_all_crc64() {
 
 /// Test various buffer sizes
 #[test]
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", feature = "crc32", feature = "crc64"))]
 fn test_wasm_various_sizes() {
     extern crate alloc;
     use alloc::vec::Vec;
@@ -79,6 +87,7 @@ fn test_wasm_various_sizes() {
 
 /// Test empty input
 #[test]
+#[cfg(feature = "crc32")]
 fn test_wasm_empty() {
     let empty: &[u8] = &[];
     assert_eq!(checksum(CrcAlgorithm::Crc32IsoHdlc, empty), 0);
@@ -86,6 +95,7 @@ fn test_wasm_empty() {
 
 /// Test incremental hashing
 #[test]
+#[cfg(feature = "crc64")]
 fn test_wasm_incremental() {
     let mut digest = Digest::new(CrcAlgorithm::Crc64Nvme);
     for chunk in [b"123", b"456", b"789"].iter() {
@@ -96,6 +106,7 @@ fn test_wasm_incremental() {
 
 /// Test digest reset
 #[test]
+#[cfg(feature = "crc32")]
 fn test_wasm_reset() {
     let mut digest = Digest::new(CrcAlgorithm::Crc32IsoHdlc);
     digest.update(b"123456789");
@@ -124,7 +135,7 @@ fn test_wasm_custom_params() {
 
 /// Test checksum combining
 #[test]
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", feature = "crc32"))]
 fn test_wasm_combine() {
     let crc1 = checksum(CrcAlgorithm::Crc32IsoHdlc, b"1234");
     let crc2 = checksum(CrcAlgorithm::Crc32IsoHdlc, b"56789");
@@ -135,6 +146,7 @@ fn test_wasm_combine() {
 
 /// Test reflected vs non-reflected
 #[test]
+#[cfg(feature = "crc32")]
 fn test_wasm_reflection() {
     let data = b"123456789";
     assert_eq!(checksum(CrcAlgorithm::Crc32IsoHdlc, data), 0xcbf43926); // reflected
@@ -143,6 +155,7 @@ fn test_wasm_reflection() {
 
 /// Test standard test vectors
 #[test]
+#[cfg(feature = "crc32")]
 fn test_wasm_vectors() {
     let vectors = [
         (b"" as &[u8], CrcAlgorithm::Crc32IsoHdlc, 0_u64),